@@ -3,13 +3,17 @@ complete -c hurl -l cacert -d 'CA certificate to verify peer against (PEM format
 complete -c hurl -l cert -d 'Client certificate file and password'
 complete -c hurl -l key -d 'Private key file name'
 complete -c hurl -l color -d 'Colorize output'
-complete -c hurl -l compressed -d 'Request compressed response (using deflate or gzip)'
+complete -c hurl -l compressed -d 'Request compressed response (using br, gzip or deflate)'
 complete -c hurl -l connect-timeout -d 'Maximum time allowed for connection'
 complete -c hurl -l connect-to -d 'For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead'
 complete -c hurl -l continue-on-error -d 'Continue executing requests even if an error occurs'
 complete -c hurl -l cookie -d 'Read cookies from FILE'
 complete -c hurl -l cookie-jar -d 'Write cookies to FILE after running the session (only for one session)'
+complete -c hurl -l curl -d 'Export each request to a list of curl commands'
+complete -c hurl -l dataset -d 'Run each file once per row of a CSV or JSON data set'
 complete -c hurl -l delay -d 'Sets delay before each request'
+complete -c hurl -l digest -d 'Use HTTP Digest authentication'
+complete -c hurl -l doh-url -d 'Resolve host names over DNS-over-HTTPS using this URL'
 complete -c hurl -l error-format -d 'Control the format of error messages'
 complete -c hurl -l fail-at-end -d 'Fail at end'
 complete -c hurl -l file-root -d 'Set root directory to import files [default: input file directory]'
@@ -29,6 +33,7 @@ complete -c hurl -l ipv4 -d 'Tell Hurl to use IPv4 addresses only when resolving
 complete -c hurl -l ipv6 -d 'Tell Hurl to use IPv6 addresses only when resolving host names, and not for example try IPv4'
 complete -c hurl -l jobs -d 'Maximum number of parallel jobs'
 complete -c hurl -l json -d 'Output each Hurl file result to JSON'
+complete -c hurl -l max-decompressed-size -d 'Specify the maximum size (in bytes) of a decompressed response body'
 complete -c hurl -l max-filesize -d 'Specify the maximum size (in bytes) of a file to download'
 complete -c hurl -l max-redirs -d 'Maximum number of redirects allowed, -1 for unlimited redirects'
 complete -c hurl -l max-time -d 'Maximum time allowed for the transfer'
@@ -38,11 +43,15 @@ complete -c hurl -l netrc-optional -d 'Use either .netrc or the URL'
 complete -c hurl -l no-color -d 'Do not colorize output'
 complete -c hurl -l no-output -d 'Suppress output. By default, Hurl outputs the body of the last response'
 complete -c hurl -l noproxy -d 'List of hosts which do not use proxy'
+complete -c hurl -l ntlm -d 'Use NTLM authentication'
+complete -c hurl -l oauth2-client -d 'OAuth2 client credentials used with --oauth2-token-url'
+complete -c hurl -l oauth2-token-url -d 'Fetch an OAuth2 access token from URL with client credentials'
 complete -c hurl -l output -d 'Write to FILE instead of stdout'
 complete -c hurl -l parallel -d 'Run files in parallel (default in test mode)'
 complete -c hurl -l path-as-is -d 'Tell Hurl to not handle sequences of /../ or /./ in the given URL path'
 complete -c hurl -l proxy -d 'Use proxy on given PROTOCOL/HOST/PORT'
 complete -c hurl -l repeat -d 'Repeat the input files sequence NUM times, -1 for infinite loop'
+complete -c hurl -l report-har -d 'Write a HAR report to FILE'
 complete -c hurl -l report-html -d 'Generate HTML report to DIR'
 complete -c hurl -l report-json -d 'Generate JSON report to DIR'
 complete -c hurl -l report-junit -d 'Write a JUnit XML report to FILE'
@@ -50,6 +59,7 @@ complete -c hurl -l report-tap -d 'Write a TAP report to FILE'
 complete -c hurl -l resolve -d 'Provide a custom address for a specific HOST and PORT pair'
 complete -c hurl -l retry -d 'Maximum number of retries, 0 for no retries, -1 for unlimited retries'
 complete -c hurl -l retry-interval -d 'Interval in milliseconds before a retry'
+complete -c hurl -l secret -d 'Define a variable which value is secret'
 complete -c hurl -l ssl-no-revoke -d '(Windows) Tell Hurl to disable certificate revocation checks'
 complete -c hurl -l test -d 'Activate test mode (use parallel execution)'
 complete -c hurl -l to-entry -d 'Execute Hurl file to ENTRY_NUMBER (starting at 1)'
@@ -60,6 +70,7 @@ complete -c hurl -l variable -d 'Define a variable'
 complete -c hurl -l variables-file -d 'Define a properties file in which you define your variables'
 complete -c hurl -l verbose -d 'Turn on verbose'
 complete -c hurl -l very-verbose -d 'Turn on verbose output, including HTTP response and libcurl logs'
+complete -c hurl -l warn-asserts -d 'Report assert errors as warnings, without failing the run'
 complete -c hurl -l help -d 'Print help'
 complete -c hurl -l version -d 'Print version'
 
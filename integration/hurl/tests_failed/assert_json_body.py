@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-assert-json-body")
+def error_assert_json_body():
+    return Response(
+        '{"address":{"zip":"75002","city":"Paris"},"tags":["admin","user"],"name":"Alice","id":1}',
+        mimetype="application/json",
+    )
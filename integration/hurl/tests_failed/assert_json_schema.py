@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-assert-json-schema")
+def error_assert_json_schema():
+    return Response(
+        '{"id": "1", "roles": []}',
+        mimetype="application/json",
+    )
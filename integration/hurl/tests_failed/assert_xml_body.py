@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-assert-xml-body")
+def error_assert_xml_body():
+    return Response(
+        '<?xml version="1.0" encoding="UTF-8"?><catalog xmlns="urn:catalog"><book id="bk101" lang="fr"><author>Gambardella, Matthew</author><title>XML Developer\'s Guide</title></book></catalog>',
+        mimetype="application/xml",
+    )
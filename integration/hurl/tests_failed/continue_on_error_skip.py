@@ -0,0 +1,16 @@
+from app import app
+
+
+@app.route("/continue-on-error-skip/login")
+def continue_on_error_skip_login():
+    return ""
+
+
+@app.route("/continue-on-error-skip/users")
+def continue_on_error_skip_users():
+    return "[]"
+
+
+@app.route("/continue-on-error-skip/health")
+def continue_on_error_skip_health():
+    return "OK"
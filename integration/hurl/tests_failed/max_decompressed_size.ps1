@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --max-decompressed-size 1023 tests_failed/max_decompressed_size.hurl
@@ -0,0 +1,12 @@
+import gzip
+
+from app import app
+from flask import Response
+
+
+@app.route("/max-decompressed-size-fail")
+def max_decompressed_size_fail():
+    data = gzip.compress(b"x" * 1024)
+    resp = Response(data, mimetype="application/octet-stream")
+    resp.headers["Content-Encoding"] = "gzip"
+    return resp
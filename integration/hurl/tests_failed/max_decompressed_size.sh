@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --max-decompressed-size 1023 tests_failed/max_decompressed_size.hurl
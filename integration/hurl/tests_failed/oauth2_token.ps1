@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --oauth2-token-url http://localhost:8000/oauth2/token-invalid-client --oauth2-client client:secret tests_failed/oauth2_token.hurl
@@ -0,0 +1,7 @@
+from app import app
+from flask import jsonify
+
+
+@app.route("/oauth2/token-invalid-client", methods=["POST"])
+def oauth2_token_invalid_client():
+    return jsonify(error="invalid_client"), 401
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --oauth2-token-url http://localhost:8000/oauth2/token-invalid-client --oauth2-client client:secret tests_failed/oauth2_token.hurl
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --location --max-redirs -1 tests_failed/redirect_loop.hurl
@@ -0,0 +1,12 @@
+from app import app
+from flask import redirect
+
+
+@app.route("/redirect-loop/a", methods=["GET"])
+def redirect_loop_a():
+    return redirect("http://localhost:8000/redirect-loop/b")
+
+
+@app.route("/redirect-loop/b", methods=["GET"])
+def redirect_loop_b():
+    return redirect("http://localhost:8000/redirect-loop/a")
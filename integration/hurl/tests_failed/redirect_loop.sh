@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --location --max-redirs -1 tests_failed/redirect_loop.hurl
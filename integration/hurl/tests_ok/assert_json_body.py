@@ -0,0 +1,21 @@
+import time
+import uuid
+
+from app import app
+from flask import Response
+
+
+@app.route("/assert-json-body")
+def assert_json_body():
+    return Response(
+        '{"address":{"zip":"75001","city":"Paris"},"tags":["admin","user"],"name":"Alice","id":1}',
+        mimetype="application/json",
+    )
+
+
+@app.route("/assert-json-body/wildcard")
+def assert_json_body_wildcard():
+    return Response(
+        f'{{"id":"{uuid.uuid4()}","created":{int(time.time())},"tags":["admin",{{"level":3}}]}}',
+        mimetype="application/json",
+    )
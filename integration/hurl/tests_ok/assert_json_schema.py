@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-json-schema")
+def assert_json_schema():
+    return Response(
+        '{"id": 1, "name": "Alice", "roles": ["admin", "user"]}',
+        mimetype="application/json",
+    )
@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-xml-body")
+def assert_xml_body():
+    return Response(
+        '<?xml version="1.0" encoding="UTF-8"?><c:catalog xmlns:c="urn:catalog"><c:book lang="en" id="bk101"><c:author>Gambardella, Matthew</c:author><c:title>XML Developer\'s Guide</c:title></c:book></c:catalog>',
+        mimetype="application/xml",
+    )
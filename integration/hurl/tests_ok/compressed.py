@@ -67,6 +67,33 @@ def compressed_brotli_identity():
     return resp
 
 
+@app.route("/compressed/deflate_gzip")
+def compressed_deflate_gzip():
+    assert "gzip" in request.headers["Accept-Encoding"]
+    assert "deflate" in request.headers["Accept-Encoding"]
+    result = BytesIO()
+    result.write(
+        b"\x1f\x8b\x08\x00\x00\x00\x00\x00\x02\x03\xab\x98\xf3\xd9\xe3\xec\xc9\x93\xe1\x1c\xe7\xf5\x4f\x79\x06\xb2\x30\xc8\x78\xb2\xd8\x01\x00\xa8\xf3\xad\xdc\x14\x00\x00\x00"
+    )
+    data = result.getvalue()
+    resp = make_response(data)
+    resp.headers["Content-Encoding"] = "deflate, gzip"
+    return resp
+
+
+@app.route("/compressed/zstd")
+def compressed_zstd():
+    assert "zstd" in request.headers["Accept-Encoding"]
+    result = BytesIO()
+    result.write(
+        b"\x28\xb5\x2f\xfd\x00\x58\x61\x00\x00\x48\x65\x6c\x6c\x6f\x20\x57\x6f\x72\x6c\x64\x21"
+    )
+    data = result.getvalue()
+    resp = make_response(data)
+    resp.headers["Content-Encoding"] = "zstd"
+    return resp
+
+
 @app.route("/compressed/none")
 def compressed_none():
     return "Hello World!"
@@ -0,0 +1,8 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/curl_option.txt) {
+    Remove-Item build/curl_option.txt
+}
+
+hurl --variable name=Bob --variable lang=fr --curl build/curl_option.txt --no-output tests_ok/curl_option.hurl
+Write-Host (Get-Content build/curl_option.txt -Raw) -NoNewLine
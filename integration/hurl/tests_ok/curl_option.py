@@ -0,0 +1,9 @@
+from app import app
+from flask import request
+
+
+@app.route("/curl-option", methods=["POST"])
+def curl_option():
+    assert request.args["lang"] == "fr"
+    name = request.json["name"]
+    return f"Hello {name}!"
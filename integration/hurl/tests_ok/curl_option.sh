@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/curl_option.txt
+
+hurl --variable name=Bob --variable lang=fr --curl build/curl_option.txt --no-output tests_ok/curl_option.hurl
+cat build/curl_option.txt
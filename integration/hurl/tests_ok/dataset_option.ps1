@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --dataset tests_ok/dataset_option.csv tests_ok/dataset_option.hurl
+hurl --dataset tests_ok/dataset_option.json tests_ok/dataset_option.hurl
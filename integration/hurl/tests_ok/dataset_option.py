@@ -0,0 +1,9 @@
+from app import app
+from flask import request
+
+
+@app.route("/dataset")
+def dataset():
+    user = request.args.get("user")
+    age = request.args.get("age")
+    return f"{user} is {age}\n"
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --dataset tests_ok/dataset_option.csv tests_ok/dataset_option.hurl
+hurl --dataset tests_ok/dataset_option.json tests_ok/dataset_option.hurl
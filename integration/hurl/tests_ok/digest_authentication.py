@@ -0,0 +1,29 @@
+from hashlib import md5
+
+from app import app
+from flask import Response, request
+
+REALM = "hurl"
+NONCE = "dcd98b7102dd2f0e8b11d0f600bfb0c093"
+
+
+def hash(s):
+    return md5(s.encode()).hexdigest()
+
+
+@app.route("/digest-authentication")
+def digest_authentication():
+    auth = request.authorization
+    if auth is None or auth.type != "digest":
+        return Response(
+            status=401,
+            headers={
+                "WWW-Authenticate": f'Digest realm="{REALM}", qop="auth", nonce="{NONCE}"'
+            },
+        )
+    assert auth.username == "bob"
+    ha1 = hash(f"bob:{REALM}:secret")
+    ha2 = hash(f"{request.method}:{auth.uri}")
+    response = hash(f"{ha1}:{auth.nonce}:{auth.nc}:{auth.cnonce}:{auth.qop}:{ha2}")
+    assert auth.response == response
+    return "You are authenticated"
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --digest --user bob:secret tests_ok/digest_authentication.hurl
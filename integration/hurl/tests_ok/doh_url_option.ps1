@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --doh-url https://localhost:1/dns-query tests_ok/doh_url_option.hurl
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --doh-url https://localhost:1/dns-query tests_ok/doh_url_option.hurl
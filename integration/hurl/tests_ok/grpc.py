@@ -0,0 +1,71 @@
+import struct
+
+from app import app
+from flask import Response, request
+
+
+def read_varint(data, pos):
+    value = 0
+    shift = 0
+    while True:
+        byte = data[pos]
+        pos += 1
+        value |= (byte & 0x7F) << shift
+        shift += 7
+        if byte < 0x80:
+            return value, pos
+
+
+def write_varint(value):
+    out = b""
+    while value > 0x7F:
+        out += bytes([(value & 0x7F) | 0x80])
+        value >>= 7
+    return out + bytes([value])
+
+
+def decode_hello_request(data):
+    fields = {}
+    pos = 0
+    while pos < len(data):
+        key, pos = read_varint(data, pos)
+        number, wire_type = key >> 3, key & 0x07
+        if wire_type == 0:
+            fields[number], pos = read_varint(data, pos)
+        elif wire_type == 2:
+            length, pos = read_varint(data, pos)
+            fields[number] = data[pos : pos + length].decode()
+            pos += length
+    return fields
+
+
+@app.route("/helloworld.Greeter/SayHello", methods=["POST"])
+def grpc_say_hello():
+    assert request.headers["Content-Type"] == "application/grpc"
+    assert request.headers["TE"] == "trailers"
+    body = request.data
+    assert body[0] == 0
+    (length,) = struct.unpack(">I", body[1:5])
+    fields = decode_hello_request(body[5 : 5 + length])
+
+    name = fields.get(1, "")
+    if name == "":
+        return Response(
+            b"",
+            headers={
+                "Content-Type": "application/grpc",
+                "grpc-status": "3",
+                "grpc-message": "name is required",
+            },
+        )
+
+    greeting = "Bonjour" if fields.get(3, 0) == 1 else "Hello"
+    reply = b""
+    for _ in range(fields.get(2, 1)):
+        message = f"{greeting} {name}".encode()
+        reply += b"\x0a" + write_varint(len(message)) + message
+    reply += b"\x10" + write_varint(12345678901)
+    return Response(
+        b"\x00" + struct.pack(">I", len(reply)) + reply,
+        headers={"Content-Type": "application/grpc", "grpc-status": "0"},
+    )
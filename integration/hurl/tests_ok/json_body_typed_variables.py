@@ -0,0 +1,23 @@
+from flask import request
+from app import app
+
+
+@app.route("/json-body-typed-variables", methods=["GET", "POST"])
+def json_body_typed_variables():
+    if request.method == "GET":
+        return app.response_class(
+            response='{"id": 42, "price": 9.5, "tags": ["a", "b"], "user": {"name": "Bob", "admin": false}}',
+            mimetype="application/json",
+        )
+    s = request.data.decode("utf-8")
+    assert (
+        s
+        == """{
+    "id": 42,
+    "price": 9.5,
+    "tags": ["a","b"],
+    "user": {"admin":false,"name":"Bob"},
+    "retry": 3
+}"""
+    )
+    return ""
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --variable retry=3 tests_ok/json_body_typed_variables.hurl
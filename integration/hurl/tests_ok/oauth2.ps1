@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --oauth2-token-url http://localhost:8000/oauth2/token --oauth2-client client:secret tests_ok/oauth2.hurl
@@ -0,0 +1,21 @@
+from app import app
+from flask import jsonify, request
+
+oauth2_token_count = 0
+
+
+@app.route("/oauth2/token", methods=["POST"])
+def oauth2_token():
+    global oauth2_token_count
+    # Client credentials are sent with Basic authentication: `echo -n 'client:secret' | base64`
+    assert request.headers["Authorization"] == "Basic Y2xpZW50OnNlY3JldA=="
+    assert request.form["grant_type"] == "client_credentials"
+    oauth2_token_count += 1
+    return jsonify(
+        access_token=f"token-{oauth2_token_count}", token_type="Bearer", expires_in=1
+    )
+
+
+@app.route("/oauth2/resource")
+def oauth2_resource():
+    return request.headers["Authorization"]
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --oauth2-token-url http://localhost:8000/oauth2/token --oauth2-client client:secret tests_ok/oauth2.hurl
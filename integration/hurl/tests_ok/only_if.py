@@ -0,0 +1,22 @@
+from app import app
+
+count = 0
+
+
+@app.route("/only-if/init")
+def only_if_init():
+    global count
+    count = 0
+    return str(count)
+
+
+@app.route("/only-if/increment")
+def only_if_increment():
+    global count
+    count += 1
+    return str(count)
+
+
+@app.route("/only-if/get")
+def only_if_get():
+    return str(count)
@@ -0,0 +1,8 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/report.har) {
+    Remove-Item build/report.har
+}
+
+hurl --report-har build/report.har tests_ok/report_har.hurl --no-output
+Write-Host (Get-Content build/report.har -Raw) -NoNewLine
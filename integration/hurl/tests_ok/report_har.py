@@ -0,0 +1,13 @@
+from app import app
+from flask import redirect, request
+
+
+@app.route("/report-har", methods=["POST"])
+def report_har():
+    name = request.json["name"]
+    return f"Hello {name}!"
+
+
+@app.route("/report-har/redirect")
+def report_har_redirect():
+    return redirect("/hello")
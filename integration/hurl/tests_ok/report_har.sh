@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/report.har
+
+hurl --report-har build/report.har tests_ok/report_har.hurl --no-output
+cat build/report.har
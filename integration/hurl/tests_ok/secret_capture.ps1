@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/secret_capture.hurl --secret 'password=p&ss w0rd' --verbose
@@ -0,0 +1,15 @@
+from app import app
+from flask import Response, request
+
+
+@app.route("/secret/login", methods=["POST"])
+def secret_login():
+    assert request.form["password"] == "p&ss w0rd"
+    return Response("", headers={"X-Session": "s3ss10n"})
+
+
+@app.route("/secret/profile")
+def secret_profile():
+    assert request.headers["X-Session"] == "s3ss10n"
+    assert request.authorization.password == "p&ss w0rd"
+    return "Hello Bob"
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/secret_capture.hurl --secret "password=p&ss w0rd" --verbose
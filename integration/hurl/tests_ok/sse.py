@@ -0,0 +1,40 @@
+import itertools
+import json
+import time
+
+from app import app
+from flask import Response
+
+
+@app.route("/sse/messages")
+def sse_messages():
+    def events():
+        yield ": a comment\n\n"
+        yield "data: Hello\n\n"
+        yield "event: greetings\n"
+        yield "id: 1\n"
+        yield "data: Bonjour\n"
+        yield "data: Hola\n\n"
+
+    return Response(events(), mimetype="text/event-stream")
+
+
+@app.route("/sse/ticker")
+def sse_ticker():
+    def events():
+        for i in itertools.count():
+            yield f"event: tick\nid: {i}\ndata: {json.dumps({'count': i})}\n\n"
+            time.sleep(0.1)
+
+    return Response(events(), mimetype="text/event-stream")
+
+
+@app.route("/sse/idle")
+def sse_idle():
+    def events():
+        yield "data: started\n\n"
+        while True:
+            time.sleep(0.2)
+            yield ": keep-alive\n\n"
+
+    return Response(events(), mimetype="text/event-stream")
@@ -0,0 +1,20 @@
+import re
+from datetime import datetime
+
+from flask import request
+from app import app
+
+
+@app.route("/template-functions", methods=["GET", "POST"])
+def template_functions():
+    if request.method == "GET":
+        assert request.args["id"] == "my-uuid"
+        return ""
+    body = request.get_json()
+    assert re.fullmatch(
+        r"[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}", body["id"]
+    )
+    assert re.fullmatch(r"user-[A-Za-z0-9]{16}", body["name"])
+    assert datetime.fromisoformat(body["created"].replace("Z", "+00:00"))
+    assert isinstance(body["seed"], int) and body["seed"] >= 0
+    return ""
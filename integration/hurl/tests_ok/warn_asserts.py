@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/warn-asserts")
+def warn_asserts():
+    return "Hello"
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/warn_asserts.hurl --warn-asserts --continue-on-error
@@ -0,0 +1,105 @@
+import base64
+import hashlib
+import json
+import struct
+
+from app import app
+from flask import abort, request
+
+GUID = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11"
+
+
+def accept(sock):
+    key = request.headers.get("Sec-WebSocket-Key")
+    if request.headers.get("Upgrade", "").lower() != "websocket" or not key:
+        abort(400)
+    digest = hashlib.sha1((key + GUID).encode()).digest()
+    accept_key = base64.b64encode(digest).decode()
+    sock.sendall(
+        (
+            "HTTP/1.1 101 Switching Protocols\r\n"
+            "Upgrade: websocket\r\n"
+            "Connection: Upgrade\r\n"
+            f"Sec-WebSocket-Accept: {accept_key}\r\n"
+            "\r\n"
+        ).encode()
+    )
+
+
+def recv_exact(sock, n):
+    data = b""
+    while len(data) < n:
+        chunk = sock.recv(n - len(data))
+        if not chunk:
+            raise ConnectionError
+        data += chunk
+    return data
+
+
+def recv_frame(sock):
+    b0, b1 = recv_exact(sock, 2)
+    opcode = b0 & 0x0F
+    length = b1 & 0x7F
+    if length == 126:
+        (length,) = struct.unpack(">H", recv_exact(sock, 2))
+    elif length == 127:
+        (length,) = struct.unpack(">Q", recv_exact(sock, 8))
+    mask = recv_exact(sock, 4)
+    payload = recv_exact(sock, length)
+    return opcode, bytes(b ^ mask[i % 4] for i, b in enumerate(payload))
+
+
+def send_frame(sock, opcode, payload):
+    header = bytes([0x80 | opcode])
+    if len(payload) < 126:
+        header += bytes([len(payload)])
+    else:
+        header += bytes([126]) + struct.pack(">H", len(payload))
+    sock.sendall(header + payload)
+
+
+def close(sock):
+    send_frame(sock, 0x8, struct.pack(">H", 1000))
+
+
+@app.route("/websocket/echo", websocket=True)
+def websocket_echo():
+    sock = request.environ["werkzeug.socket"]
+    accept(sock)
+    # Asks the client for a pong, before echoing its frames.
+    send_frame(sock, 0x9, b"ping")
+    while True:
+        opcode, payload = recv_frame(sock)
+        if opcode == 0x1:
+            echo = json.dumps(
+                {
+                    "echo": payload.decode(),
+                    "user_agent": request.user_agent.string,
+                    "hosts": request.headers.getlist("Host"),
+                }
+            )
+            send_frame(sock, 0x1, echo.encode())
+        elif opcode == 0x2:
+            send_frame(sock, 0x2, payload[::-1])
+        elif opcode == 0x8:
+            close(sock)
+            break
+    return ""
+
+
+@app.route("/websocket/greetings", websocket=True)
+def websocket_greetings():
+    sock = request.environ["werkzeug.socket"]
+    accept(sock)
+    send_frame(sock, 0x1, b"Hello")
+    # A fragmented message.
+    sock.sendall(bytes([0x01, 3]) + b"Bon")
+    sock.sendall(bytes([0x80, 4]) + b"jour")
+    close(sock)
+    recv_frame(sock)
+    return ""
+
+
+@app.route("/websocket/status")
+def websocket_status():
+    return "ok"
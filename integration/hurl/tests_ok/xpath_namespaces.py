@@ -0,0 +1,24 @@
+from app import app
+from flask import Response, request
+
+
+@app.route("/xpath-namespaces/soap", methods=["POST"])
+def xpath_namespaces_soap():
+    assert request.headers["Content-Type"] == "application/soap+xml"
+    body = """<?xml version="1.0"?>
+<Envelope xmlns="http://www.w3.org/2003/05/soap-envelope">
+  <Body>
+    <GetStockPriceResponse xmlns="https://www.example.org/stock">
+      <Price>34.5</Price>
+      <Token>abcd1234</Token>
+    </GetStockPriceResponse>
+  </Body>
+</Envelope>
+"""
+    return Response(body, mimetype="application/soap+xml")
+
+
+@app.route("/xpath-namespaces/token/<token>")
+def xpath_namespaces_token(token):
+    assert token == "abcd1234"
+    return ""
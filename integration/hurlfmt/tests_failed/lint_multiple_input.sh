@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurlfmt --lint tests_failed/lint_multiple_input_1.hurl tests_failed/lint_multiple_input_2.hurl
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurlfmt --in har tests_ok/import_har.in
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurlfmt --in openapi tests_ok/import_openapi.in
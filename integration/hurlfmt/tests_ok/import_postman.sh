@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurlfmt --in postman tests_ok/import_postman.in
@@ -70,7 +70,7 @@ pub fn color() -> clap::Arg {
 pub fn compressed() -> clap::Arg {
     clap::Arg::new("compressed")
         .long("compressed")
-        .help("Request compressed response (using deflate or gzip)")
+        .help("Request compressed response (using br, gzip or deflate)")
         .action(clap::ArgAction::SetTrue)
 }
 
@@ -117,6 +117,22 @@ pub fn cookies_output_file() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn curl() -> clap::Arg {
+    clap::Arg::new("curl")
+        .long("curl")
+        .value_name("FILE")
+        .help("Export each request to a list of curl commands")
+        .num_args(1)
+}
+
+pub fn dataset() -> clap::Arg {
+    clap::Arg::new("dataset")
+        .long("dataset")
+        .value_name("FILE")
+        .help("Run each file once per row of a CSV or JSON data set")
+        .num_args(1)
+}
+
 pub fn delay() -> clap::Arg {
     clap::Arg::new("delay")
         .long("delay")
@@ -126,6 +142,22 @@ pub fn delay() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn digest() -> clap::Arg {
+    clap::Arg::new("digest")
+        .long("digest")
+        .help("Use HTTP Digest authentication")
+        .conflicts_with("ntlm")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn doh_url() -> clap::Arg {
+    clap::Arg::new("doh_url")
+        .long("doh-url")
+        .value_name("URL")
+        .help("Resolve host names over DNS-over-HTTPS using this URL")
+        .num_args(1)
+}
+
 pub fn error_format() -> clap::Arg {
     clap::Arg::new("error_format")
         .long("error-format")
@@ -279,6 +311,15 @@ pub fn json() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn max_decompressed_size() -> clap::Arg {
+    clap::Arg::new("max_decompressed_size")
+        .long("max-decompressed-size")
+        .value_name("BYTES")
+        .value_parser(clap::value_parser!(u64))
+        .help("Specify the maximum size (in bytes) of a decompressed response body")
+        .num_args(1)
+}
+
 pub fn max_filesize() -> clap::Arg {
     clap::Arg::new("max_filesize")
         .long("max-filesize")
@@ -360,6 +401,30 @@ pub fn noproxy() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn ntlm() -> clap::Arg {
+    clap::Arg::new("ntlm")
+        .long("ntlm")
+        .help("Use NTLM authentication")
+        .conflicts_with("digest")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn oauth2_client() -> clap::Arg {
+    clap::Arg::new("oauth2_client")
+        .long("oauth2-client")
+        .value_name("CLIENT_ID:CLIENT_SECRET")
+        .help("OAuth2 client credentials used with --oauth2-token-url")
+        .num_args(1)
+}
+
+pub fn oauth2_token_url() -> clap::Arg {
+    clap::Arg::new("oauth2_token_url")
+        .long("oauth2-token-url")
+        .value_name("URL")
+        .help("Fetch an OAuth2 access token from URL with client credentials")
+        .num_args(1)
+}
+
 pub fn output() -> clap::Arg {
     clap::Arg::new("output")
         .long("output")
@@ -402,6 +467,14 @@ pub fn repeat() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn report_har() -> clap::Arg {
+    clap::Arg::new("report_har")
+        .long("report-har")
+        .value_name("FILE")
+        .help("Write a HAR report to FILE")
+        .num_args(1)
+}
+
 pub fn report_html() -> clap::Arg {
     clap::Arg::new("report_html")
         .long("report-html")
@@ -462,6 +535,15 @@ pub fn retry_interval() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn secret() -> clap::Arg {
+    clap::Arg::new("secret")
+        .long("secret")
+        .value_name("NAME=VALUE")
+        .help("Define a variable which value is secret")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
 pub fn ssl_no_revoke() -> clap::Arg {
     clap::Arg::new("ssl_no_revoke")
         .long("ssl-no-revoke")
@@ -544,3 +626,10 @@ pub fn very_verbose() -> clap::Arg {
         .help("Turn on verbose output, including HTTP response and libcurl logs")
         .action(clap::ArgAction::SetTrue)
 }
+
+pub fn warn_asserts() -> clap::Arg {
+    clap::Arg::new("warn_asserts")
+        .long("warn-asserts")
+        .help("Report assert errors as warnings, without failing the run")
+        .action(clap::ArgAction::SetTrue)
+}
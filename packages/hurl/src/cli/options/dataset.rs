@@ -0,0 +1,262 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::variables::parse_value;
+use super::CliOptionsError;
+use crate::runner::Value;
+
+/// Parses the data set file at `path` and returns the variables of each of its rows.
+///
+/// A file with a `.json` extension is a JSON array of objects, one object per row. Any other file
+/// is a CSV file, whose first line defines the variable names. A data set without any row is an
+/// error.
+pub fn parse(path: &Path) -> Result<Vec<HashMap<String, Value>>, CliOptionsError> {
+    if !path.exists() {
+        return Err(CliOptionsError::Error(format!(
+            "Data set file {} does not exist",
+            path.display()
+        )));
+    }
+    let Ok(content) = fs::read_to_string(path) else {
+        return Err(CliOptionsError::Error(format!(
+            "Data set file {} can not be read",
+            path.display()
+        )));
+    };
+    let rows = if path.extension().is_some_and(|e| e == "json") {
+        parse_json(&content)
+    } else {
+        parse_csv(&content)
+    };
+    let rows = rows.and_then(|rows| {
+        if rows.is_empty() {
+            Err("no rows".to_string())
+        } else {
+            Ok(rows)
+        }
+    });
+    rows.map_err(|message| {
+        CliOptionsError::Error(format!(
+            "Can not parse data set file {}: {message}",
+            path.display()
+        ))
+    })
+}
+
+/// Parses a JSON data set, an array of objects.
+fn parse_json(s: &str) -> Result<Vec<HashMap<String, Value>>, String> {
+    let value = serde_json::from_str::<serde_json::Value>(s).map_err(|e| e.to_string())?;
+    let Some(rows) = value.as_array() else {
+        return Err("expecting an array of objects".to_string());
+    };
+    rows.iter()
+        .map(|row| match row.as_object() {
+            Some(object) => Ok(object
+                .iter()
+                .map(|(name, value)| (name.clone(), Value::from_json(value)))
+                .collect()),
+            None => Err("expecting an array of objects".to_string()),
+        })
+        .collect()
+}
+
+/// Parses a CSV data set, with a header line.
+///
+/// Unquoted fields are typed like `--variable` values (`true`, `30`, `null`...), quoted fields
+/// are always strings.
+fn parse_csv(s: &str) -> Result<Vec<HashMap<String, Value>>, String> {
+    let mut records = csv_records(s)?.into_iter();
+    let Some(header) = records.next() else {
+        return Err("missing header line".to_string());
+    };
+    let names = header.into_iter().map(|(name, _)| name).collect::<Vec<_>>();
+    records
+        .enumerate()
+        .map(|(index, record)| {
+            if record.len() != names.len() {
+                return Err(format!(
+                    "row {} has {} fields, expecting {}",
+                    index + 1,
+                    record.len(),
+                    names.len()
+                ));
+            }
+            names
+                .iter()
+                .zip(record)
+                .map(|(name, (field, quoted))| {
+                    let value = if quoted {
+                        Value::String(field)
+                    } else {
+                        parse_value(&field).map_err(|_| format!("invalid value <{field}>"))?
+                    };
+                    Ok((name.clone(), value))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Splits a CSV text `s` into records of fields (see <https://datatracker.ietf.org/doc/html/rfc4180>).
+///
+/// Each field is returned with a flag indicating if it was quoted. Empty lines are ignored.
+fn csv_records(s: &str) -> Result<Vec<Vec<(String, bool)>>, String> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if field.is_empty() && !quoted => {
+                quoted = true;
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => field.push(c),
+                        None => return Err("missing closing double quote".to_string()),
+                    }
+                }
+                // A quoted field ends the field.
+                match chars.peek() {
+                    None | Some(',') | Some('\r') | Some('\n') => {}
+                    Some(c) => {
+                        return Err(format!(
+                            "unexpected character <{c}> after closing double quote"
+                        ))
+                    }
+                }
+            }
+            ',' => {
+                record.push((std::mem::take(&mut field), quoted));
+                quoted = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                if !field.is_empty() || quoted || !record.is_empty() {
+                    record.push((std::mem::take(&mut field), quoted));
+                    records.push(std::mem::take(&mut record));
+                }
+                quoted = false;
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || quoted || !record.is_empty() {
+        record.push((field, quoted));
+        records.push(record);
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Number;
+
+    #[test]
+    fn test_parse_csv() {
+        let csv = "user,age,admin,note\nbob,30,true,\"a, \"\"quoted\"\" note\"\r\n\nalice,\"25\",false,\n";
+        assert_eq!(
+            parse_csv(csv).unwrap(),
+            vec![
+                HashMap::from([
+                    ("user".to_string(), Value::String("bob".to_string())),
+                    ("age".to_string(), Value::Number(Number::Integer(30))),
+                    ("admin".to_string(), Value::Bool(true)),
+                    (
+                        "note".to_string(),
+                        Value::String("a, \"quoted\" note".to_string())
+                    ),
+                ]),
+                HashMap::from([
+                    ("user".to_string(), Value::String("alice".to_string())),
+                    ("age".to_string(), Value::String("25".to_string())),
+                    ("admin".to_string(), Value::Bool(false)),
+                    ("note".to_string(), Value::String(String::new())),
+                ]),
+            ]
+        );
+        assert_eq!(
+            parse_csv("user,age\nbob\n").unwrap_err(),
+            "row 1 has 1 fields, expecting 2"
+        );
+        assert_eq!(
+            parse_csv("user\n\"bob\n").unwrap_err(),
+            "missing closing double quote"
+        );
+        assert_eq!(
+            parse_csv("user\n\"bob\"x\n").unwrap_err(),
+            "unexpected character <x> after closing double quote"
+        );
+        assert_eq!(parse_csv("user\n").unwrap(), vec![]);
+        assert_eq!(parse_csv("").unwrap_err(), "missing header line");
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let dir = std::env::temp_dir();
+        let csv = dir.join("hurl_empty_dataset.csv");
+        fs::write(&csv, "user,age\n").unwrap();
+        let json = dir.join("hurl_empty_dataset.json");
+        fs::write(&json, "[]").unwrap();
+        assert_eq!(
+            parse(&csv).unwrap_err(),
+            CliOptionsError::Error(format!(
+                "Can not parse data set file {}: no rows",
+                csv.display()
+            ))
+        );
+        assert_eq!(
+            parse(&json).unwrap_err(),
+            CliOptionsError::Error(format!(
+                "Can not parse data set file {}: no rows",
+                json.display()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let json = r#"[{"user": "bob", "age": 30}, {"user": "alice", "age": null}]"#;
+        assert_eq!(
+            parse_json(json).unwrap(),
+            vec![
+                HashMap::from([
+                    ("user".to_string(), Value::String("bob".to_string())),
+                    ("age".to_string(), Value::Number(Number::Integer(30))),
+                ]),
+                HashMap::from([
+                    ("user".to_string(), Value::String("alice".to_string())),
+                    ("age".to_string(), Value::Null),
+                ]),
+            ]
+        );
+        assert_eq!(
+            parse_json(r#"{"user": "bob"}"#).unwrap_err(),
+            "expecting an array of objects"
+        );
+    }
+}
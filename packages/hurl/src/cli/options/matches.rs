@@ -28,7 +28,7 @@ use hurl_core::input::Input;
 use hurl_core::typing::{Count, DurationUnit};
 
 use crate::cli::options::variables::{parse as parse_variable, parse_value};
-use crate::cli::options::{duration, CliOptionsError};
+use crate::cli::options::{dataset, duration, CliOptionsError};
 use crate::cli::options::{ErrorFormat, HttpVersion, IpResolve, Output};
 use crate::cli::OutputType;
 
@@ -126,11 +126,33 @@ pub fn cookie_output_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
     get::<String>(arg_matches, "cookies_output_file").map(PathBuf::from)
 }
 
+pub fn curl_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get::<String>(arg_matches, "curl").map(PathBuf::from)
+}
+
+/// Returns the variables of each row of the data set file, if any.
+pub fn dataset(
+    arg_matches: &ArgMatches,
+) -> Result<Option<Vec<HashMap<String, Value>>>, CliOptionsError> {
+    match get::<String>(arg_matches, "dataset") {
+        Some(filename) => Ok(Some(dataset::parse(Path::new(&filename))?)),
+        None => Ok(None),
+    }
+}
+
 pub fn delay(arg_matches: &ArgMatches) -> Result<Duration, CliOptionsError> {
     let s = get::<String>(arg_matches, "delay").unwrap_or_default();
     get_duration(&s, DurationUnit::MilliSecond)
 }
 
+pub fn digest(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "digest")
+}
+
+pub fn doh_url(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "doh_url")
+}
+
 pub fn error_format(arg_matches: &ArgMatches) -> ErrorFormat {
     let error_format = get::<String>(arg_matches, "error_format");
     match error_format.as_deref() {
@@ -155,6 +177,10 @@ pub fn from_entry(arg_matches: &ArgMatches) -> Option<usize> {
     get::<u32>(arg_matches, "from_entry").map(|x| x as usize)
 }
 
+pub fn har_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get::<String>(arg_matches, "report_har").map(PathBuf::from)
+}
+
 pub fn html_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, CliOptionsError> {
     if let Some(dir) = get::<String>(arg_matches, "report_html") {
         let path = Path::new(&dir);
@@ -333,10 +359,34 @@ pub fn netrc_optional(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "netrc_optional")
 }
 
+pub fn ntlm(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "ntlm")
+}
+
 pub fn no_proxy(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "noproxy")
 }
 
+pub fn oauth2_client(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
+    let client = get::<String>(arg_matches, "oauth2_client");
+    if client.is_some() && get::<String>(arg_matches, "oauth2_token_url").is_none() {
+        return Err(CliOptionsError::Error(
+            "--oauth2-client requires --oauth2-token-url".to_string(),
+        ));
+    }
+    Ok(client)
+}
+
+pub fn oauth2_token_url(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
+    let token_url = get::<String>(arg_matches, "oauth2_token_url");
+    if token_url.is_some() && get::<String>(arg_matches, "oauth2_client").is_none() {
+        return Err(CliOptionsError::Error(
+            "--oauth2-token-url requires --oauth2-client".to_string(),
+        ));
+    }
+    Ok(token_url)
+}
+
 pub fn output(arg_matches: &ArgMatches) -> Option<Output> {
     get::<String>(arg_matches, "output").map(|filename| Output::new(&filename))
 }
@@ -392,6 +442,21 @@ pub fn retry_interval(arg_matches: &ArgMatches) -> Result<Duration, CliOptionsEr
     get_duration(&s, DurationUnit::MilliSecond)
 }
 
+pub fn secrets(arg_matches: &ArgMatches) -> Result<HashMap<String, String>, CliOptionsError> {
+    let mut secrets = HashMap::new();
+    if let Some(input) = get_strings(arg_matches, "secret") {
+        for s in input {
+            let Some((name, value)) = s.split_once('=') else {
+                return Err(CliOptionsError::Error(format!(
+                    "Missing value for secret {s}!"
+                )));
+            };
+            secrets.insert(name.to_string(), value.to_string());
+        }
+    }
+    Ok(secrets)
+}
+
 pub fn ssl_no_revoke(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "ssl_no_revoke")
 }
@@ -488,6 +553,14 @@ pub fn very_verbose(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "very_verbose")
 }
 
+pub fn warn_asserts(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "warn_asserts")
+}
+
+pub fn max_decompressed_size(arg_matches: &ArgMatches) -> Option<u64> {
+    get::<u64>(arg_matches, "max_decompressed_size")
+}
+
 pub fn max_filesize(arg_matches: &ArgMatches) -> Option<u64> {
     get::<u64>(arg_matches, "max_filesize")
 }
@@ -16,6 +16,7 @@
  *
  */
 mod commands;
+mod dataset;
 mod duration;
 mod error;
 mod matches;
@@ -54,12 +55,17 @@ pub struct CliOptions {
     pub continue_on_error: bool,
     pub cookie_input_file: Option<String>,
     pub cookie_output_file: Option<PathBuf>,
+    pub curl_file: Option<PathBuf>,
+    pub dataset: Option<Vec<HashMap<String, Value>>>,
     pub delay: Duration,
+    pub digest: bool,
+    pub doh_url: Option<String>,
     pub error_format: ErrorFormat,
     pub file_root: Option<String>,
     pub follow_location: bool,
     pub follow_location_trusted: bool,
     pub from_entry: Option<usize>,
+    pub har_file: Option<PathBuf>,
     pub html_dir: Option<PathBuf>,
     pub http_version: Option<HttpVersion>,
     pub ignore_asserts: bool,
@@ -71,12 +77,16 @@ pub struct CliOptions {
     pub jobs: Option<usize>,
     pub json_report_dir: Option<PathBuf>,
     pub junit_file: Option<PathBuf>,
+    pub max_decompressed_size: Option<u64>,
     pub max_filesize: Option<u64>,
     pub max_redirect: Count,
     pub netrc: bool,
     pub netrc_file: Option<String>,
     pub netrc_optional: bool,
     pub no_proxy: Option<String>,
+    pub ntlm: bool,
+    pub oauth2_client: Option<String>,
+    pub oauth2_token_url: Option<String>,
     pub output: Option<Output>,
     pub output_type: OutputType,
     pub parallel: bool,
@@ -87,6 +97,7 @@ pub struct CliOptions {
     pub resolves: Vec<String>,
     pub retry: Option<Count>,
     pub retry_interval: Duration,
+    pub secrets: HashMap<String, String>,
     pub ssl_no_revoke: bool,
     pub tap_file: Option<PathBuf>,
     pub test: bool,
@@ -98,6 +109,7 @@ pub struct CliOptions {
     pub variables: HashMap<String, Value>,
     pub verbose: bool,
     pub very_verbose: bool,
+    pub warn_asserts: bool,
 }
 
 /// Error format: long or rich.
@@ -179,7 +191,11 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         .arg(commands::continue_on_error())
         .arg(commands::cookies_input_file())
         .arg(commands::cookies_output_file())
+        .arg(commands::curl())
+        .arg(commands::dataset())
         .arg(commands::delay())
+        .arg(commands::digest())
+        .arg(commands::doh_url())
         .arg(commands::error_format())
         .arg(commands::fail_at_end())
         .arg(commands::file_root())
@@ -200,6 +216,7 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         .arg(commands::ipv6())
         .arg(commands::jobs())
         .arg(commands::json())
+        .arg(commands::max_decompressed_size())
         .arg(commands::max_filesize())
         .arg(commands::max_redirects())
         .arg(commands::max_time())
@@ -209,11 +226,15 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         .arg(commands::no_color())
         .arg(commands::no_output())
         .arg(commands::noproxy())
+        .arg(commands::ntlm())
+        .arg(commands::oauth2_client())
+        .arg(commands::oauth2_token_url())
         .arg(commands::output())
         .arg(commands::parallel())
         .arg(commands::path_as_is())
         .arg(commands::proxy())
         .arg(commands::repeat())
+        .arg(commands::report_har())
         .arg(commands::report_html())
         .arg(commands::report_json())
         .arg(commands::report_junit())
@@ -221,6 +242,7 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         .arg(commands::resolve())
         .arg(commands::retry())
         .arg(commands::retry_interval())
+        .arg(commands::secret())
         .arg(commands::ssl_no_revoke())
         .arg(commands::test())
         .arg(commands::to_entry())
@@ -230,7 +252,8 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         .arg(commands::variable())
         .arg(commands::variables_file())
         .arg(commands::verbose())
-        .arg(commands::very_verbose());
+        .arg(commands::very_verbose())
+        .arg(commands::warn_asserts());
     let arg_matches = command.try_get_matches_from_mut(env::args_os())?;
 
     // If we've no file input (either from the standard input or from the command line arguments),
@@ -267,11 +290,16 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let continue_on_error = matches::continue_on_error(arg_matches);
     let cookie_input_file = matches::cookie_input_file(arg_matches);
     let cookie_output_file = matches::cookie_output_file(arg_matches);
+    let curl_file = matches::curl_file(arg_matches);
+    let dataset = matches::dataset(arg_matches)?;
     let delay = matches::delay(arg_matches)?;
+    let digest = matches::digest(arg_matches);
+    let doh_url = matches::doh_url(arg_matches);
     let error_format = matches::error_format(arg_matches);
     let file_root = matches::file_root(arg_matches);
     let (follow_location, follow_location_trusted) = matches::follow_location(arg_matches);
     let from_entry = matches::from_entry(arg_matches);
+    let har_file = matches::har_file(arg_matches);
     let html_dir = matches::html_dir(arg_matches)?;
     let http_version = matches::http_version(arg_matches);
     let ignore_asserts = matches::ignore_asserts(arg_matches);
@@ -282,12 +310,16 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let ip_resolve = matches::ip_resolve(arg_matches);
     let json_report_dir = matches::json_report_dir(arg_matches)?;
     let junit_file = matches::junit_file(arg_matches);
+    let max_decompressed_size = matches::max_decompressed_size(arg_matches);
     let max_filesize = matches::max_filesize(arg_matches);
     let max_redirect = matches::max_redirect(arg_matches);
     let netrc = matches::netrc(arg_matches);
     let netrc_file = matches::netrc_file(arg_matches)?;
     let netrc_optional = matches::netrc_optional(arg_matches);
     let no_proxy = matches::no_proxy(arg_matches);
+    let ntlm = matches::ntlm(arg_matches);
+    let oauth2_client = matches::oauth2_client(arg_matches)?;
+    let oauth2_token_url = matches::oauth2_token_url(arg_matches)?;
     let parallel = matches::parallel(arg_matches);
     let path_as_is = matches::path_as_is(arg_matches);
     let progress_bar = matches::progress_bar(arg_matches);
@@ -298,6 +330,7 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let resolves = matches::resolves(arg_matches);
     let retry = matches::retry(arg_matches);
     let retry_interval = matches::retry_interval(arg_matches)?;
+    let secrets = matches::secrets(arg_matches)?;
     let ssl_no_revoke = matches::ssl_no_revoke(arg_matches);
     let tap_file = matches::tap_file(arg_matches);
     let test = matches::test(arg_matches);
@@ -309,6 +342,7 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let variables = matches::variables(arg_matches)?;
     let verbose = matches::verbose(arg_matches);
     let very_verbose = matches::very_verbose(arg_matches);
+    let warn_asserts = matches::warn_asserts(arg_matches);
     let jobs = matches::jobs(arg_matches);
     Ok(CliOptions {
         aws_sigv4,
@@ -322,12 +356,17 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         continue_on_error,
         cookie_input_file,
         cookie_output_file,
+        curl_file,
+        dataset,
         delay,
+        digest,
+        doh_url,
         error_format,
         file_root,
         follow_location,
         follow_location_trusted,
         from_entry,
+        har_file,
         html_dir,
         http_version,
         ignore_asserts,
@@ -338,12 +377,16 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         ip_resolve,
         json_report_dir,
         junit_file,
+        max_decompressed_size,
         max_filesize,
         max_redirect,
         netrc,
         netrc_file,
         netrc_optional,
         no_proxy,
+        ntlm,
+        oauth2_client,
+        oauth2_token_url,
         path_as_is,
         parallel,
         progress_bar,
@@ -354,6 +397,7 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         resolves,
         retry,
         retry_interval,
+        secrets,
         ssl_no_revoke,
         tap_file,
         test,
@@ -365,6 +409,7 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         variables,
         verbose,
         very_verbose,
+        warn_asserts,
         jobs,
     })
 }
@@ -399,6 +444,8 @@ impl CliOptions {
         let continue_on_error = self.continue_on_error;
         let cookie_input_file = self.cookie_input_file.clone();
         let delay = self.delay;
+        let digest = self.digest;
+        let doh_url = self.doh_url.clone();
         let follow_location = self.follow_location;
         let follow_location_trusted = self.follow_location_trusted;
         let from_entry = self.from_entry;
@@ -412,12 +459,16 @@ impl CliOptions {
             Some(ip) => ip.into(),
             None => http::IpResolve::default(),
         };
+        let max_decompressed_size = self.max_decompressed_size;
         let max_filesize = self.max_filesize;
         let max_redirect = self.max_redirect;
         let netrc = self.netrc;
         let netrc_file = self.netrc_file.clone();
         let netrc_optional = self.netrc_optional;
         let no_proxy = self.no_proxy.clone();
+        let ntlm = self.ntlm;
+        let oauth2_client = self.oauth2_client.clone();
+        let oauth2_token_url = self.oauth2_token_url.clone();
         let output = self.output.clone();
         let path_as_is = self.path_as_is;
         let post_entry = if self.interactive {
@@ -447,6 +498,8 @@ impl CliOptions {
             .client_cert_file(client_cert_file)
             .client_key_file(client_key_file)
             .delay(delay)
+            .digest(digest)
+            .doh_url(doh_url)
             .compressed(compressed)
             .connect_timeout(connect_timeout)
             .connects_to(&connects_to)
@@ -460,12 +513,16 @@ impl CliOptions {
             .ignore_asserts(ignore_asserts)
             .insecure(insecure)
             .ip_resolve(ip_resolve)
+            .max_decompressed_size(max_decompressed_size)
             .max_filesize(max_filesize)
             .max_redirect(max_redirect)
             .netrc(netrc)
             .netrc_file(netrc_file)
             .netrc_optional(netrc_optional)
             .no_proxy(no_proxy)
+            .ntlm(ntlm)
+            .oauth2_client(oauth2_client)
+            .oauth2_token_url(oauth2_token_url)
             .output(output)
             .path_as_is(path_as_is)
             .post_entry(post_entry)
@@ -488,7 +545,38 @@ impl CliOptions {
         LoggerOptionsBuilder::new()
             .color(self.color)
             .error_format(self.error_format.into())
+            .secrets(&self.secret_values())
             .verbosity(verbosity)
+            .warn_asserts(self.warn_asserts)
             .build()
     }
+
+    /// Returns the variables of each run of an input file.
+    ///
+    /// Without data set, an input file is run once with the command line variables. With a data
+    /// set, an input file is run once per row, the variables of the row overriding the command
+    /// line variables.
+    pub fn variables_sets(&self) -> Vec<HashMap<String, Value>> {
+        // Secrets are variables too, always of string type.
+        let mut variables = self.variables.clone();
+        for (name, value) in &self.secrets {
+            variables.insert(name.clone(), Value::String(value.clone()));
+        }
+        match &self.dataset {
+            None => vec![variables],
+            Some(rows) => rows
+                .iter()
+                .map(|row| {
+                    let mut variables = variables.clone();
+                    variables.extend(row.clone());
+                    variables
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the values of the secrets, to be redacted from logs and reports.
+    pub fn secret_values(&self) -> Vec<String> {
+        self.secrets.values().cloned().collect()
+    }
 }
@@ -77,18 +77,22 @@ pub mod tests {
                 captures: vec![],
                 asserts: vec![],
                 errors: vec![],
+                curl_cmd: String::new(),
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
+                skipped: false,
             };
             HurlRun {
                 content: String::new(),
                 filename: Input::new(""),
+                row: None,
                 hurl_result: HurlResult {
                     entries: vec![dummy_entry; entries_count],
                     duration: Duration::from_millis(0),
                     success,
                     cookies: vec![],
                     timestamp: 1,
+                    secrets: vec![],
                 },
             }
         }
@@ -0,0 +1,596 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Conversion between JSON and Protocol Buffers messages.
+//!
+//! JSON values follow the Protocol Buffers JSON mapping
+//! (see <https://protobuf.dev/programming-guides/proto3/#json>): 64-bit integers are strings,
+//! bytes are Base64 strings and enums are names.
+use base64::engine::general_purpose;
+use base64::Engine;
+use serde_json::{Map, Number, Value};
+
+use crate::grpc::proto::{Field, FieldKind, Proto};
+
+const WIRE_VARINT: u32 = 0;
+const WIRE_I64: u32 = 1;
+const WIRE_LEN: u32 = 2;
+const WIRE_I32: u32 = 5;
+
+impl Proto {
+    /// Encodes a JSON `value` to a message of type `message`.
+    pub fn encode(&self, message: &str, value: &Value) -> Result<Vec<u8>, String> {
+        let fields = &self.messages[message].fields;
+        let Value::Object(object) = value else {
+            return Err(format!("expecting an object for message <{message}>"));
+        };
+        let mut data = vec![];
+        for (name, value) in object {
+            let Some(field) = fields
+                .iter()
+                .find(|f| &f.json_name == name || &f.name == name)
+            else {
+                return Err(format!("unknown field <{name}> in message <{message}>"));
+            };
+            if value.is_null() {
+                continue;
+            }
+            self.encode_field(field, value, &mut data)
+                .map_err(|e| format!("field <{name}>: {e}"))?;
+        }
+        Ok(data)
+    }
+
+    fn encode_field(&self, field: &Field, value: &Value, data: &mut Vec<u8>) -> Result<(), String> {
+        if let FieldKind::Map(key_kind, value_kind) = &field.kind {
+            let Value::Object(entries) = value else {
+                return Err("expecting an object".to_string());
+            };
+            for (key, value) in entries {
+                let mut entry = vec![];
+                self.encode_value(1, key_kind, &map_key(key_kind, key)?, &mut entry)?;
+                self.encode_value(2, value_kind, value, &mut entry)?;
+                write_tag(field.number, WIRE_LEN, data);
+                write_len(&entry, data);
+            }
+            return Ok(());
+        }
+        if !field.repeated {
+            return self.encode_value(field.number, &field.kind, value, data);
+        }
+        let Value::Array(values) = value else {
+            return Err("expecting an array".to_string());
+        };
+        if wire_type(&field.kind) == WIRE_LEN {
+            for value in values {
+                self.encode_value(field.number, &field.kind, value, data)?;
+            }
+        } else {
+            // Repeated scalar numeric fields are packed.
+            let mut packed = vec![];
+            for value in values {
+                self.encode_scalar(&field.kind, value, &mut packed)?;
+            }
+            write_tag(field.number, WIRE_LEN, data);
+            write_len(&packed, data);
+        }
+        Ok(())
+    }
+
+    /// Encodes a `value` with its tag.
+    fn encode_value(
+        &self,
+        number: u32,
+        kind: &FieldKind,
+        value: &Value,
+        data: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        write_tag(number, wire_type(kind), data);
+        match kind {
+            FieldKind::String => {
+                let Value::String(value) = value else {
+                    return Err("expecting a string".to_string());
+                };
+                write_len(value.as_bytes(), data);
+            }
+            FieldKind::Bytes => {
+                let Value::String(value) = value else {
+                    return Err("expecting a Base64 string".to_string());
+                };
+                write_len(&decode_base64(value)?, data);
+            }
+            FieldKind::Message(message) => write_len(&self.encode(message, value)?, data),
+            kind => self.encode_scalar(kind, value, data)?,
+        }
+        Ok(())
+    }
+
+    /// Encodes a scalar `value`, without tag.
+    fn encode_scalar(
+        &self,
+        kind: &FieldKind,
+        value: &Value,
+        data: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        match kind {
+            FieldKind::Double => data.extend(to_f64(value)?.to_le_bytes()),
+            FieldKind::Float => data.extend((to_f64(value)? as f32).to_le_bytes()),
+            FieldKind::Int32 => write_varint(to_i32(value)? as i64 as u64, data),
+            FieldKind::Int64 => write_varint(to_i64(value)? as u64, data),
+            FieldKind::UInt32 => write_varint(to_u32(value)? as u64, data),
+            FieldKind::UInt64 => write_varint(to_u64(value)?, data),
+            FieldKind::SInt32 => write_varint(zigzag(to_i32(value)? as i64), data),
+            FieldKind::SInt64 => write_varint(zigzag(to_i64(value)?), data),
+            FieldKind::Fixed32 => data.extend(to_u32(value)?.to_le_bytes()),
+            FieldKind::Fixed64 => data.extend(to_u64(value)?.to_le_bytes()),
+            FieldKind::SFixed32 => data.extend(to_i32(value)?.to_le_bytes()),
+            FieldKind::SFixed64 => data.extend(to_i64(value)?.to_le_bytes()),
+            FieldKind::Bool => match value {
+                Value::Bool(value) => write_varint(*value as u64, data),
+                _ => return Err("expecting a boolean".to_string()),
+            },
+            FieldKind::Enum(name) => {
+                let number = match value {
+                    Value::String(value) => self.enums[name]
+                        .values
+                        .iter()
+                        .find(|(n, _)| n == value)
+                        .map(|(_, number)| *number)
+                        .ok_or(format!("unknown value <{value}> of enum <{name}>"))?,
+                    value => to_i32(value)?,
+                };
+                write_varint(number as i64 as u64, data);
+            }
+            _ => unreachable!("not a scalar"),
+        }
+        Ok(())
+    }
+
+    /// Decodes the bytes `data` of a message of type `message` to JSON.
+    pub fn decode(&self, message: &str, data: &[u8]) -> Result<Value, String> {
+        let fields = &self.messages[message].fields;
+        let mut object = Map::new();
+        let mut reader = Reader { data, pos: 0 };
+        while !reader.is_empty() {
+            let tag = reader.varint()?;
+            let number = (tag >> 3) as u32;
+            let wire = (tag & 7) as u32;
+            let value = reader.value(wire)?;
+            let Some(field) = fields.iter().find(|f| f.number == number) else {
+                // Unknown fields are ignored.
+                continue;
+            };
+            let name = field.json_name.clone();
+            if let FieldKind::Map(key_kind, value_kind) = &field.kind {
+                let WireValue::Len(entry) = value else {
+                    return Err(format!("invalid wire type for field <{name}>"));
+                };
+                let (key, value) = self.decode_map_entry(key_kind, value_kind, entry)?;
+                let map = object
+                    .entry(name)
+                    .or_insert_with(|| Value::Object(Map::new()));
+                if let Value::Object(map) = map {
+                    map.insert(key, value);
+                }
+            } else if field.repeated {
+                let values = match (&value, wire_type(&field.kind)) {
+                    (WireValue::Len(packed), wire) if wire != WIRE_LEN => {
+                        let mut values = vec![];
+                        let mut reader = Reader {
+                            data: packed,
+                            pos: 0,
+                        };
+                        while !reader.is_empty() {
+                            let value = reader.value(wire)?;
+                            values.push(self.decode_value(&field.kind, &value)?);
+                        }
+                        values
+                    }
+                    _ => vec![self.decode_value(&field.kind, &value)?],
+                };
+                let list = object.entry(name).or_insert_with(|| Value::Array(vec![]));
+                if let Value::Array(list) = list {
+                    list.extend(values);
+                }
+            } else {
+                object.insert(name, self.decode_value(&field.kind, &value)?);
+            }
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn decode_map_entry(
+        &self,
+        key_kind: &FieldKind,
+        value_kind: &FieldKind,
+        data: &[u8],
+    ) -> Result<(String, Value), String> {
+        let mut key = None;
+        let mut value = None;
+        let mut reader = Reader { data, pos: 0 };
+        while !reader.is_empty() {
+            let tag = reader.varint()?;
+            let wire_value = reader.value((tag & 7) as u32)?;
+            match tag >> 3 {
+                1 => key = Some(self.decode_value(key_kind, &wire_value)?),
+                2 => value = Some(self.decode_value(value_kind, &wire_value)?),
+                _ => {}
+            }
+        }
+        let key = match key {
+            Some(Value::String(key)) => key,
+            Some(key) => key.to_string(),
+            None => default_key(key_kind),
+        };
+        let value = match value {
+            Some(value) => value,
+            None => self.default_value(value_kind),
+        };
+        Ok((key, value))
+    }
+
+    fn decode_value(&self, kind: &FieldKind, value: &WireValue) -> Result<Value, String> {
+        let value = match (kind, value) {
+            (FieldKind::Int32, WireValue::Varint(v)) => Value::from(*v as i32),
+            (FieldKind::Int64, WireValue::Varint(v)) => Value::String((*v as i64).to_string()),
+            (FieldKind::UInt32, WireValue::Varint(v)) => Value::from(*v as u32),
+            (FieldKind::UInt64, WireValue::Varint(v)) => Value::String(v.to_string()),
+            (FieldKind::SInt32, WireValue::Varint(v)) => Value::from(unzigzag(*v) as i32),
+            (FieldKind::SInt64, WireValue::Varint(v)) => Value::String(unzigzag(*v).to_string()),
+            (FieldKind::Bool, WireValue::Varint(v)) => Value::Bool(*v != 0),
+            (FieldKind::Enum(name), WireValue::Varint(v)) => {
+                let number = *v as i32;
+                match self.enums[name].values.iter().find(|(_, n)| *n == number) {
+                    Some((name, _)) => Value::String(name.clone()),
+                    None => Value::from(number),
+                }
+            }
+            (FieldKind::Fixed32, WireValue::I32(v)) => Value::from(*v),
+            (FieldKind::SFixed32, WireValue::I32(v)) => Value::from(*v as i32),
+            (FieldKind::Float, WireValue::I32(v)) => from_f64(f32::from_bits(*v) as f64),
+            (FieldKind::Fixed64, WireValue::I64(v)) => Value::String(v.to_string()),
+            (FieldKind::SFixed64, WireValue::I64(v)) => Value::String((*v as i64).to_string()),
+            (FieldKind::Double, WireValue::I64(v)) => from_f64(f64::from_bits(*v)),
+            (FieldKind::String, WireValue::Len(v)) => match std::str::from_utf8(v) {
+                Ok(s) => Value::String(s.to_string()),
+                Err(_) => return Err("invalid UTF-8 string".to_string()),
+            },
+            (FieldKind::Bytes, WireValue::Len(v)) => {
+                Value::String(general_purpose::STANDARD.encode(v))
+            }
+            (FieldKind::Message(message), WireValue::Len(v)) => self.decode(message, v)?,
+            _ => return Err("invalid wire type".to_string()),
+        };
+        Ok(value)
+    }
+
+    /// Returns the default JSON value of a `kind`, for map entries without value.
+    fn default_value(&self, kind: &FieldKind) -> Value {
+        match kind {
+            FieldKind::Double | FieldKind::Float => Value::from(0.0),
+            FieldKind::Int64
+            | FieldKind::UInt64
+            | FieldKind::SInt64
+            | FieldKind::Fixed64
+            | FieldKind::SFixed64 => Value::String("0".to_string()),
+            FieldKind::Bool => Value::Bool(false),
+            FieldKind::String | FieldKind::Bytes => Value::String(String::new()),
+            FieldKind::Message(_) | FieldKind::Map(_, _) | FieldKind::Named { .. } => {
+                Value::Object(Map::new())
+            }
+            FieldKind::Enum(name) => match self.enums[name].values.first() {
+                Some((name, _)) => Value::String(name.clone()),
+                None => Value::from(0),
+            },
+            _ => Value::from(0),
+        }
+    }
+}
+
+/// A value read on the wire.
+enum WireValue<'a> {
+    Varint(u64),
+    I64(u64),
+    Len(&'a [u8]),
+    I32(u32),
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len());
+        let Some(end) = end else {
+            return Err("truncated message".to_string());
+        };
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.bytes(1)?[0];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("invalid varint".to_string())
+    }
+
+    fn value(&mut self, wire: u32) -> Result<WireValue<'a>, String> {
+        match wire {
+            WIRE_VARINT => Ok(WireValue::Varint(self.varint()?)),
+            WIRE_I64 => Ok(WireValue::I64(u64::from_le_bytes(
+                self.bytes(8)?.try_into().unwrap(),
+            ))),
+            WIRE_LEN => {
+                let len = self.varint()? as usize;
+                Ok(WireValue::Len(self.bytes(len)?))
+            }
+            WIRE_I32 => Ok(WireValue::I32(u32::from_le_bytes(
+                self.bytes(4)?.try_into().unwrap(),
+            ))),
+            wire => Err(format!("unsupported wire type {wire}")),
+        }
+    }
+}
+
+fn wire_type(kind: &FieldKind) -> u32 {
+    match kind {
+        FieldKind::Double | FieldKind::Fixed64 | FieldKind::SFixed64 => WIRE_I64,
+        FieldKind::Float | FieldKind::Fixed32 | FieldKind::SFixed32 => WIRE_I32,
+        FieldKind::String
+        | FieldKind::Bytes
+        | FieldKind::Message(_)
+        | FieldKind::Map(_, _)
+        | FieldKind::Named { .. } => WIRE_LEN,
+        _ => WIRE_VARINT,
+    }
+}
+
+fn write_varint(mut value: u64, data: &mut Vec<u8>) {
+    while value >= 0x80 {
+        data.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    data.push(value as u8);
+}
+
+fn write_tag(number: u32, wire: u32, data: &mut Vec<u8>) {
+    write_varint(((number << 3) | wire) as u64, data);
+}
+
+fn write_len(bytes: &[u8], data: &mut Vec<u8>) {
+    write_varint(bytes.len() as u64, data);
+    data.extend(bytes);
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn to_i64(value: &Value) -> Result<i64, String> {
+    let parsed = match value {
+        Value::Number(n) => n.as_i64().or_else(|| {
+            n.as_f64()
+                .filter(|f| f.fract() == 0.0 && f.abs() < i64::MAX as f64)
+                .map(|f| f as i64)
+        }),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    };
+    parsed.ok_or(format!("expecting an integer, actual is {value}"))
+}
+
+fn to_u64(value: &Value) -> Result<u64, String> {
+    let parsed = match value {
+        Value::Number(n) => n.as_u64().or_else(|| {
+            n.as_f64()
+                .filter(|f| f.fract() == 0.0 && *f >= 0.0 && *f < u64::MAX as f64)
+                .map(|f| f as u64)
+        }),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    };
+    parsed.ok_or(format!("expecting an unsigned integer, actual is {value}"))
+}
+
+fn to_i32(value: &Value) -> Result<i32, String> {
+    i32::try_from(to_i64(value)?).map_err(|_| format!("integer {value} out of range"))
+}
+
+fn to_u32(value: &Value) -> Result<u32, String> {
+    u32::try_from(to_u64(value)?).map_err(|_| format!("integer {value} out of range"))
+}
+
+fn to_f64(value: &Value) -> Result<f64, String> {
+    let parsed = match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => match s.as_str() {
+            "NaN" => Some(f64::NAN),
+            "Infinity" => Some(f64::INFINITY),
+            "-Infinity" => Some(f64::NEG_INFINITY),
+            s => s.parse().ok(),
+        },
+        _ => None,
+    };
+    parsed.ok_or(format!("expecting a number, actual is {value}"))
+}
+
+fn from_f64(value: f64) -> Value {
+    match Number::from_f64(value) {
+        Some(n) => Value::Number(n),
+        None if value.is_nan() => Value::String("NaN".to_string()),
+        None if value > 0.0 => Value::String("Infinity".to_string()),
+        None => Value::String("-Infinity".to_string()),
+    }
+}
+
+/// Decodes a Base64 string, with the standard or the URL-safe alphabet, padded or not.
+fn decode_base64(value: &str) -> Result<Vec<u8>, String> {
+    general_purpose::STANDARD
+        .decode(value)
+        .or_else(|_| general_purpose::STANDARD_NO_PAD.decode(value))
+        .or_else(|_| general_purpose::URL_SAFE.decode(value))
+        .or_else(|_| general_purpose::URL_SAFE_NO_PAD.decode(value))
+        .map_err(|_| "invalid Base64 string".to_string())
+}
+
+/// Converts a JSON object `key` of a map to a value of the map key kind.
+fn map_key(kind: &FieldKind, key: &str) -> Result<Value, String> {
+    match kind {
+        FieldKind::String => Ok(Value::String(key.to_string())),
+        FieldKind::Bool => match key {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            _ => Err(format!("invalid boolean key <{key}>")),
+        },
+        _ => Ok(Value::String(key.to_string())),
+    }
+}
+
+fn default_key(kind: &FieldKind) -> String {
+    match kind {
+        FieldKind::String => String::new(),
+        FieldKind::Bool => "false".to_string(),
+        _ => "0".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn proto() -> Proto {
+        let mut proto = Proto::default();
+        proto
+            .add_file(
+                r#"
+syntax = "proto3";
+package test;
+message Item {
+  enum Color { RED = 0; GREEN = 1; }
+  int32 id = 1;
+  string name = 2;
+  repeated int32 scores = 3;
+  double price = 4;
+  bool available = 5;
+  Color color = 6;
+  int64 big = 7;
+  sint32 delta = 8;
+  bytes data = 9;
+  Item child = 10;
+  map<string, int32> stock = 11;
+  repeated string tags = 12;
+}
+"#,
+            )
+            .unwrap();
+        proto.resolve().unwrap();
+        proto
+    }
+
+    #[test]
+    fn test_encode() {
+        let proto = proto();
+        assert_eq!(
+            proto
+                .encode("test.Item", &json!({"id": 150, "name": "testing"}))
+                .unwrap(),
+            vec![0x08, 0x96, 0x01, 0x12, 0x07, b't', b'e', b's', b't', b'i', b'n', b'g']
+        );
+        assert_eq!(
+            proto
+                .encode("test.Item", &json!({"scores": [3, 270]}))
+                .unwrap(),
+            vec![0x1a, 0x03, 0x03, 0x8e, 0x02]
+        );
+        assert_eq!(
+            proto.encode("test.Item", &json!({"delta": -2})).unwrap(),
+            vec![0x40, 0x03]
+        );
+        assert_eq!(
+            proto.encode("test.Item", &json!({"id": -1})).unwrap(),
+            vec![0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]
+        );
+        assert_eq!(
+            proto.encode("test.Item", &json!({"foo": 1})).unwrap_err(),
+            "unknown field <foo> in message <test.Item>"
+        );
+        assert_eq!(
+            proto
+                .encode("test.Item", &json!({"color": "BLUE"}))
+                .unwrap_err(),
+            "field <color>: unknown value <BLUE> of enum <test.Item.Color>"
+        );
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let proto = proto();
+        let value = json!({
+            "id": 1,
+            "name": "café",
+            "scores": [1, 2, 3],
+            "price": 9.5,
+            "available": true,
+            "color": "GREEN",
+            "big": "12345678901",
+            "delta": -5,
+            "data": "AAEC",
+            "child": {"id": 2, "tags": ["a", "b"]},
+            "stock": {"paris": 3, "lyon": 0},
+            "tags": ["x"],
+        });
+        let data = proto.encode("test.Item", &value).unwrap();
+        assert_eq!(proto.decode("test.Item", &data).unwrap(), value);
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let proto = proto();
+        assert_eq!(
+            proto.decode("test.Item", &[0x12, 0x07, b't']).unwrap_err(),
+            "truncated message"
+        );
+        assert_eq!(
+            proto
+                .decode("test.Item", &[0x0d, 0x00, 0x00, 0x00, 0x00])
+                .unwrap_err(),
+            "invalid wire type"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Unary gRPC calls (see <https://github.com/grpc/grpc/blob/master/doc/PROTOCOL-HTTP2.md>).
+//!
+//! The messages of a call are described by `.proto` files, and are converted from and to JSON.
+pub use self::proto::{Method, Proto};
+
+mod codec;
+mod proto;
+
+/// Returns the gRPC message of `data`: a compressed flag, the length of the message and the
+/// message itself.
+pub fn frame(data: &[u8]) -> Vec<u8> {
+    let mut message = vec![0];
+    message.extend((data.len() as u32).to_be_bytes());
+    message.extend(data);
+    message
+}
+
+/// Returns the message of a gRPC response `body`, with a single uncompressed message.
+pub fn unframe(body: &[u8]) -> Result<&[u8], String> {
+    if body.len() < 5 {
+        return Err("truncated gRPC message".to_string());
+    }
+    if body[0] != 0 {
+        return Err("compressed gRPC messages are not supported".to_string());
+    }
+    let len = u32::from_be_bytes([body[1], body[2], body[3], body[4]]) as usize;
+    match body.get(5..5 + len) {
+        Some(message) if body.len() == 5 + len => Ok(message),
+        Some(_) => Err("streamed gRPC messages are not supported".to_string()),
+        None => Err("truncated gRPC message".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame() {
+        assert_eq!(frame(&[0x08, 0x01]), vec![0, 0, 0, 0, 2, 0x08, 0x01]);
+        assert_eq!(
+            unframe(&[0, 0, 0, 0, 2, 0x08, 0x01]).unwrap(),
+            &[0x08, 0x01]
+        );
+        assert_eq!(
+            unframe(&[0, 0, 0, 0, 3, 0x08, 0x01]).unwrap_err(),
+            "truncated gRPC message"
+        );
+        assert_eq!(
+            unframe(&[1, 0, 0, 0, 0]).unwrap_err(),
+            "compressed gRPC messages are not supported"
+        );
+    }
+}
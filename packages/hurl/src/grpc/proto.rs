@@ -0,0 +1,769 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Parser of Protocol Buffers `.proto` files.
+//!
+//! Only the definitions needed to encode and decode messages are kept: messages, enums and
+//! services. Options, extensions and reserved statements are ignored.
+use std::collections::HashMap;
+
+/// The messages, enums and methods defined by a set of `.proto` files.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Proto {
+    /// Messages, by fully qualified name (`package.Message`).
+    pub(crate) messages: HashMap<String, Message>,
+    /// Enums, by fully qualified name (`package.Enum`).
+    pub(crate) enums: HashMap<String, Enum>,
+    pub(crate) methods: Vec<Method>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Message {
+    pub fields: Vec<Field>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub json_name: String,
+    pub number: u32,
+    pub repeated: bool,
+    pub kind: FieldKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldKind {
+    Double,
+    Float,
+    Int32,
+    Int64,
+    UInt32,
+    UInt64,
+    SInt32,
+    SInt64,
+    Fixed32,
+    Fixed64,
+    SFixed32,
+    SFixed64,
+    Bool,
+    String,
+    Bytes,
+    /// A message, by fully qualified name.
+    Message(String),
+    /// An enum, by fully qualified name.
+    Enum(String),
+    /// A map, encoded as a list of entries with a `key` (1) and a `value` (2) field.
+    Map(Box<FieldKind>, Box<FieldKind>),
+    /// A type name, not yet resolved, and the scope where it has been used.
+    Named {
+        name: String,
+        scope: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Enum {
+    pub values: Vec<(String, i32)>,
+}
+
+/// A RPC method of a service.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Method {
+    /// The HTTP path of the method: `/package.Service/Method`.
+    pub path: String,
+    /// The input message, by fully qualified name.
+    pub input: String,
+    /// The output message, by fully qualified name.
+    pub output: String,
+    /// True if the client or the server sends a stream of messages.
+    pub streaming: bool,
+}
+
+impl Proto {
+    /// Parses the content `s` of a `.proto` file and adds its definitions.
+    ///
+    /// Returns the files imported by this file, that must also be added before the types are
+    /// resolved.
+    pub fn add_file(&mut self, s: &str) -> Result<Vec<String>, String> {
+        let tokens = tokenize(s)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            proto: self,
+            imports: vec![],
+        };
+        parser.file()?;
+        Ok(parser.imports)
+    }
+
+    /// Resolves the type names of every field and method.
+    pub fn resolve(&mut self) -> Result<(), String> {
+        let mut messages = self.messages.clone();
+        for message in messages.values_mut() {
+            for field in message.fields.iter_mut() {
+                field.kind = self.resolve_kind(&field.kind)?;
+            }
+        }
+        self.messages = messages;
+        let mut methods = self.methods.clone();
+        for method in methods.iter_mut() {
+            let (scope, _) = method.path[1..].rsplit_once('/').unwrap_or_default();
+            let scope = scope.rsplit_once('.').map(|(s, _)| s).unwrap_or_default();
+            method.input = self.resolve_message(&method.input, scope)?;
+            method.output = self.resolve_message(&method.output, scope)?;
+        }
+        self.methods = methods;
+        Ok(())
+    }
+
+    /// Returns the method with the HTTP `path` (`/package.Service/Method`).
+    pub fn method(&self, path: &str) -> Option<&Method> {
+        self.methods.iter().find(|m| m.path == path)
+    }
+
+    fn resolve_kind(&self, kind: &FieldKind) -> Result<FieldKind, String> {
+        match kind {
+            FieldKind::Named { name, scope } => match self.lookup(name, scope) {
+                Some(kind) => Ok(kind),
+                None => Err(format!("unknown type <{name}>")),
+            },
+            FieldKind::Map(key, value) => Ok(FieldKind::Map(
+                Box::new(self.resolve_kind(key)?),
+                Box::new(self.resolve_kind(value)?),
+            )),
+            kind => Ok(kind.clone()),
+        }
+    }
+
+    fn resolve_message(&self, name: &str, scope: &str) -> Result<String, String> {
+        match self.lookup(name, scope) {
+            Some(FieldKind::Message(name)) => Ok(name),
+            _ => Err(format!("unknown message <{name}>")),
+        }
+    }
+
+    /// Looks up a type `name` used in `scope`, from the innermost scope to the outermost one.
+    fn lookup(&self, name: &str, scope: &str) -> Option<FieldKind> {
+        let find = |name: &str| {
+            if self.messages.contains_key(name) {
+                Some(FieldKind::Message(name.to_string()))
+            } else if self.enums.contains_key(name) {
+                Some(FieldKind::Enum(name.to_string()))
+            } else {
+                None
+            }
+        };
+        if let Some(name) = name.strip_prefix('.') {
+            return find(name);
+        }
+        let mut scope = scope;
+        loop {
+            let full_name = if scope.is_empty() {
+                name.to_string()
+            } else {
+                format!("{scope}.{name}")
+            };
+            if let Some(kind) = find(&full_name) {
+                return Some(kind);
+            }
+            if scope.is_empty() {
+                return None;
+            }
+            scope = scope.rsplit_once('.').map(|(s, _)| s).unwrap_or_default();
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    Number(String),
+    Str(String),
+    Symbol(char),
+}
+
+/// Splits a `.proto` file content `s` in tokens, without comments.
+fn tokenize(s: &str) -> Result<Vec<Token>, String> {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            i += 2;
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_ascii_alphanumeric()
+                    || chars[i] == '.'
+                    || (matches!(chars[i], '+' | '-') && matches!(chars[i - 1], 'e' | 'E')))
+            {
+                i += 1;
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c == '"' || c == '\'' {
+            i += 1;
+            let mut value = String::new();
+            loop {
+                match chars.get(i) {
+                    None | Some('\n') => return Err("unterminated string".to_string()),
+                    Some(d) if *d == c => break,
+                    Some('\\') => {
+                        i += 1;
+                        match chars.get(i) {
+                            Some('n') => value.push('\n'),
+                            Some('t') => value.push('\t'),
+                            Some('r') => value.push('\r'),
+                            Some(d) => value.push(*d),
+                            None => return Err("unterminated string".to_string()),
+                        }
+                    }
+                    Some(d) => value.push(*d),
+                }
+                i += 1;
+            }
+            i += 1;
+            tokens.push(Token::Str(value));
+        } else {
+            tokens.push(Token::Symbol(c));
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    proto: &'a mut Proto,
+    imports: Vec<String>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<Token, String> {
+        let token = self.peek().cloned().ok_or("unexpected end of file")?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn is_symbol(&self, c: char) -> bool {
+        self.peek() == Some(&Token::Symbol(c))
+    }
+
+    fn expect_symbol(&mut self, c: char) -> Result<(), String> {
+        match self.next()? {
+            Token::Symbol(d) if d == c => Ok(()),
+            token => Err(format!("expecting <{c}>, found {}", describe(&token))),
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, String> {
+        match self.next()? {
+            Token::Ident(ident) => Ok(ident),
+            token => Err(format!("expecting identifier, found {}", describe(&token))),
+        }
+    }
+
+    /// Parses a dotted identifier, with an optional leading dot (`.package.Message`).
+    fn full_ident(&mut self) -> Result<String, String> {
+        let mut ident = String::new();
+        if self.is_symbol('.') {
+            self.pos += 1;
+            ident.push('.');
+        }
+        ident.push_str(&self.ident()?);
+        while self.is_symbol('.') {
+            self.pos += 1;
+            ident.push('.');
+            ident.push_str(&self.ident()?);
+        }
+        Ok(ident)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        match self.next()? {
+            Token::Str(value) => Ok(value),
+            token => Err(format!("expecting string, found {}", describe(&token))),
+        }
+    }
+
+    fn integer(&mut self) -> Result<i64, String> {
+        let negative = self.is_symbol('-');
+        if negative {
+            self.pos += 1;
+        }
+        let value = match self.next()? {
+            Token::Number(value) => value,
+            token => return Err(format!("expecting integer, found {}", describe(&token))),
+        };
+        let parsed = if let Some(hex) = value.strip_prefix("0x").or(value.strip_prefix("0X")) {
+            i64::from_str_radix(hex, 16)
+        } else if value.len() > 1 && value.starts_with('0') {
+            i64::from_str_radix(&value[1..], 8)
+        } else {
+            value.parse()
+        };
+        let value = parsed.map_err(|_| format!("invalid integer <{value}>"))?;
+        Ok(if negative { -value } else { value })
+    }
+
+    /// Skips a statement, up to its ending `;`.
+    fn skip_statement(&mut self) -> Result<(), String> {
+        while self.next()? != Token::Symbol(';') {}
+        Ok(())
+    }
+
+    /// Skips a statement or a block, up to its ending `;` or `}`.
+    fn skip_block(&mut self) -> Result<(), String> {
+        let mut depth = 0;
+        loop {
+            match self.next()? {
+                Token::Symbol(';') if depth == 0 => return Ok(()),
+                Token::Symbol('{') => depth += 1,
+                Token::Symbol('}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn file(&mut self) -> Result<(), String> {
+        let mut package = String::new();
+        while let Some(token) = self.peek().cloned() {
+            match token {
+                Token::Symbol(';') => self.pos += 1,
+                Token::Ident(ident) => match ident.as_str() {
+                    "package" => {
+                        self.pos += 1;
+                        package = self.full_ident()?;
+                        self.expect_symbol(';')?;
+                    }
+                    "import" => {
+                        self.pos += 1;
+                        if let Some(Token::Ident(_)) = self.peek() {
+                            // `weak` or `public` import
+                            self.pos += 1;
+                        }
+                        let import = self.string()?;
+                        self.imports.push(import);
+                        self.expect_symbol(';')?;
+                    }
+                    "message" => {
+                        self.pos += 1;
+                        self.message(&package)?;
+                    }
+                    "enum" => {
+                        self.pos += 1;
+                        self.enumeration(&package)?;
+                    }
+                    "service" => {
+                        self.pos += 1;
+                        self.service(&package)?;
+                    }
+                    "syntax" | "edition" | "option" | "extend" => self.skip_block()?,
+                    _ => return Err(format!("unexpected <{ident}>")),
+                },
+                token => return Err(format!("unexpected {}", describe(&token))),
+            }
+        }
+        Ok(())
+    }
+
+    fn message(&mut self, scope: &str) -> Result<(), String> {
+        let name = scoped(scope, &self.ident()?);
+        self.expect_symbol('{')?;
+        let mut message = Message::default();
+        loop {
+            match self.next()? {
+                Token::Symbol('}') => break,
+                Token::Symbol(';') => {}
+                Token::Ident(ident) => match ident.as_str() {
+                    "message" => self.message(&name)?,
+                    "enum" => self.enumeration(&name)?,
+                    "oneof" => {
+                        self.ident()?;
+                        self.expect_symbol('{')?;
+                        while !self.is_symbol('}') {
+                            if self.peek() == Some(&Token::Ident("option".to_string())) {
+                                self.skip_statement()?;
+                            } else {
+                                let kind = self.full_ident()?;
+                                let field = self.field(&name, &kind, false)?;
+                                message.fields.push(field);
+                            }
+                        }
+                        self.pos += 1;
+                    }
+                    "map" => {
+                        self.expect_symbol('<')?;
+                        let key = self.full_ident()?;
+                        self.expect_symbol(',')?;
+                        let value = self.full_ident()?;
+                        self.expect_symbol('>')?;
+                        let kind = FieldKind::Map(
+                            Box::new(field_kind(&key, &name)),
+                            Box::new(field_kind(&value, &name)),
+                        );
+                        let field = self.field_definition(kind, false)?;
+                        message.fields.push(field);
+                    }
+                    "reserved" | "extensions" | "option" => self.skip_statement()?,
+                    "extend" => self.skip_block()?,
+                    "repeated" => {
+                        let kind = self.full_ident()?;
+                        let field = self.field(&name, &kind, true)?;
+                        message.fields.push(field);
+                    }
+                    "optional" | "required" => {
+                        let kind = self.full_ident()?;
+                        let field = self.field(&name, &kind, false)?;
+                        message.fields.push(field);
+                    }
+                    "group" => return Err("groups are not supported".to_string()),
+                    _ => {
+                        // A field type, possibly a dotted name.
+                        self.pos -= 1;
+                        let kind = self.full_ident()?;
+                        let field = self.field(&name, &kind, false)?;
+                        message.fields.push(field);
+                    }
+                },
+                token => return Err(format!("unexpected {}", describe(&token))),
+            }
+        }
+        self.proto.messages.insert(name, message);
+        Ok(())
+    }
+
+    /// Parses a field of type `kind`, in the message `scope`.
+    fn field(&mut self, scope: &str, kind: &str, repeated: bool) -> Result<Field, String> {
+        let kind = field_kind(kind, scope);
+        self.field_definition(kind, repeated)
+    }
+
+    /// Parses the name, the number and the options of a field.
+    fn field_definition(&mut self, kind: FieldKind, repeated: bool) -> Result<Field, String> {
+        let name = self.ident()?;
+        self.expect_symbol('=')?;
+        let number = self.integer()?;
+        let number = u32::try_from(number).map_err(|_| format!("invalid field number {number}"))?;
+        let mut json_name = json_name(&name);
+        if self.is_symbol('[') {
+            self.pos += 1;
+            loop {
+                match self.next()? {
+                    Token::Symbol(']') => break,
+                    Token::Ident(ident) if ident == "json_name" => {
+                        self.expect_symbol('=')?;
+                        json_name = self.string()?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        self.expect_symbol(';')?;
+        Ok(Field {
+            name,
+            json_name,
+            number,
+            repeated,
+            kind,
+        })
+    }
+
+    fn enumeration(&mut self, scope: &str) -> Result<(), String> {
+        let name = scoped(scope, &self.ident()?);
+        self.expect_symbol('{')?;
+        let mut enumeration = Enum::default();
+        loop {
+            match self.next()? {
+                Token::Symbol('}') => break,
+                Token::Symbol(';') => {}
+                Token::Ident(ident) if ident == "option" || ident == "reserved" => {
+                    self.skip_statement()?;
+                }
+                Token::Ident(ident) => {
+                    self.expect_symbol('=')?;
+                    let value = self.integer()?;
+                    let value =
+                        i32::try_from(value).map_err(|_| format!("invalid enum value {value}"))?;
+                    self.skip_statement()?;
+                    enumeration.values.push((ident, value));
+                }
+                token => return Err(format!("unexpected {}", describe(&token))),
+            }
+        }
+        self.proto.enums.insert(name, enumeration);
+        Ok(())
+    }
+
+    fn service(&mut self, package: &str) -> Result<(), String> {
+        let service = scoped(package, &self.ident()?);
+        self.expect_symbol('{')?;
+        loop {
+            match self.next()? {
+                Token::Symbol('}') => break,
+                Token::Symbol(';') => {}
+                Token::Ident(ident) if ident == "rpc" => {
+                    let name = self.ident()?;
+                    let (input, input_streaming) = self.rpc_type()?;
+                    if self.ident()? != "returns" {
+                        return Err("expecting <returns>".to_string());
+                    }
+                    let (output, output_streaming) = self.rpc_type()?;
+                    self.skip_block()?;
+                    self.proto.methods.push(Method {
+                        path: format!("/{service}/{name}"),
+                        input,
+                        output,
+                        streaming: input_streaming || output_streaming,
+                    });
+                }
+                Token::Ident(ident) if ident == "option" => self.skip_statement()?,
+                token => return Err(format!("unexpected {}", describe(&token))),
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses the input or output type of a RPC, and returns its name and if it's a stream.
+    fn rpc_type(&mut self) -> Result<(String, bool), String> {
+        self.expect_symbol('(')?;
+        let mut name = self.full_ident()?;
+        let mut streaming = false;
+        if name == "stream" && !self.is_symbol(')') {
+            streaming = true;
+            name = self.full_ident()?;
+        }
+        self.expect_symbol(')')?;
+        Ok((name, streaming))
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Ident(value) | Token::Number(value) => format!("<{value}>"),
+        Token::Str(value) => format!("<\"{value}\">"),
+        Token::Symbol(c) => format!("<{c}>"),
+    }
+}
+
+fn scoped(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_string()
+    } else {
+        format!("{scope}.{name}")
+    }
+}
+
+/// Returns the kind of a field of type `name`, used in `scope`.
+fn field_kind(name: &str, scope: &str) -> FieldKind {
+    match name {
+        "double" => FieldKind::Double,
+        "float" => FieldKind::Float,
+        "int32" => FieldKind::Int32,
+        "int64" => FieldKind::Int64,
+        "uint32" => FieldKind::UInt32,
+        "uint64" => FieldKind::UInt64,
+        "sint32" => FieldKind::SInt32,
+        "sint64" => FieldKind::SInt64,
+        "fixed32" => FieldKind::Fixed32,
+        "fixed64" => FieldKind::Fixed64,
+        "sfixed32" => FieldKind::SFixed32,
+        "sfixed64" => FieldKind::SFixed64,
+        "bool" => FieldKind::Bool,
+        "string" => FieldKind::String,
+        "bytes" => FieldKind::Bytes,
+        _ => FieldKind::Named {
+            name: name.to_string(),
+            scope: scope.to_string(),
+        },
+    }
+}
+
+/// Returns the JSON name of a field `name`, in lower camel case.
+fn json_name(name: &str) -> String {
+    let mut json_name = String::new();
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            json_name.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            json_name.push(c);
+        }
+    }
+    json_name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut proto = Proto::default();
+        let imports = proto
+            .add_file(
+                r#"
+syntax = "proto3";
+package shop.v1;
+import "google/protobuf/any.proto";
+option go_package = "example.org/shop";
+
+// The shop service.
+service Shop {
+  rpc GetProduct (GetProductRequest) returns (Product);
+  rpc ListProducts (ListRequest) returns (stream Product) {}
+}
+
+message GetProductRequest { int64 id = 1; }
+
+message ListRequest {}
+
+/* A product. */
+message Product {
+  enum Status {
+    STATUS_UNKNOWN = 0;
+    STATUS_AVAILABLE = 1 [deprecated = true];
+  }
+  message Price { double amount = 1; string currency_code = 2; }
+  int64 id = 1;
+  string display_name = 2 [json_name = "label"];
+  repeated string tags = 3;
+  Price price = 4;
+  Status status = 5;
+  map<string, int32> stock = 6;
+  oneof discount {
+    sint32 percent = 7;
+    .shop.v1.Product.Price amount = 8;
+  }
+  reserved 9, 10;
+}
+"#,
+            )
+            .unwrap();
+        assert_eq!(imports, vec!["google/protobuf/any.proto".to_string()]);
+        proto.resolve().unwrap();
+
+        assert_eq!(
+            proto.method("/shop.v1.Shop/GetProduct"),
+            Some(&Method {
+                path: "/shop.v1.Shop/GetProduct".to_string(),
+                input: "shop.v1.GetProductRequest".to_string(),
+                output: "shop.v1.Product".to_string(),
+                streaming: false,
+            })
+        );
+        assert!(
+            proto
+                .method("/shop.v1.Shop/ListProducts")
+                .unwrap()
+                .streaming
+        );
+
+        let product = &proto.messages["shop.v1.Product"];
+        let fields = product
+            .fields
+            .iter()
+            .map(|f| (f.json_name.as_str(), f.number, f.repeated, f.kind.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                ("id", 1, false, FieldKind::Int64),
+                ("label", 2, false, FieldKind::String),
+                ("tags", 3, true, FieldKind::String),
+                (
+                    "price",
+                    4,
+                    false,
+                    FieldKind::Message("shop.v1.Product.Price".to_string())
+                ),
+                (
+                    "status",
+                    5,
+                    false,
+                    FieldKind::Enum("shop.v1.Product.Status".to_string())
+                ),
+                (
+                    "stock",
+                    6,
+                    false,
+                    FieldKind::Map(Box::new(FieldKind::String), Box::new(FieldKind::Int32))
+                ),
+                ("percent", 7, false, FieldKind::SInt32),
+                (
+                    "amount",
+                    8,
+                    false,
+                    FieldKind::Message("shop.v1.Product.Price".to_string())
+                ),
+            ]
+        );
+        assert_eq!(
+            proto.messages["shop.v1.Product.Price"].fields[1].json_name,
+            "currencyCode"
+        );
+        assert_eq!(
+            proto.enums["shop.v1.Product.Status"].values,
+            vec![
+                ("STATUS_UNKNOWN".to_string(), 0),
+                ("STATUS_AVAILABLE".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let mut proto = Proto::default();
+        assert_eq!(
+            proto.add_file("message Foo { int32 id 1; }"),
+            Err("expecting <=>, found <1>".to_string())
+        );
+
+        let mut proto = Proto::default();
+        proto.add_file("message Foo { Bar bar = 1; }").unwrap();
+        assert_eq!(proto.resolve(), Err("unknown type <Bar>".to_string()));
+    }
+}
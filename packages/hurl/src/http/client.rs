@@ -15,15 +15,17 @@
  * limitations under the License.
  *
  */
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::str;
 use std::str::FromStr;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use base64::engine::general_purpose;
 use base64::Engine;
 use chrono::Utc;
-use curl::easy::{List, NetRc, SslOpt};
+use curl::easy::{Auth, List, NetRc, SslOpt};
+use curl::multi::{Multi, WaitFd};
 use curl::{easy, Version};
 use encoding::all::ISO_8859_1;
 use encoding::{DecoderTrap, Encoding};
@@ -33,15 +35,20 @@ use crate::http::certificate::Certificate;
 use crate::http::core::*;
 use crate::http::debug::log_body;
 use crate::http::header::{
-    HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, EXPECT, LOCATION, USER_AGENT,
+    HeaderVec, ACCEPT, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, LOCATION,
+    USER_AGENT,
 };
-use crate::http::options::ClientOptions;
+use crate::http::options::{ClientOptions, DEFAULT_STREAM_TIMEOUT};
 use crate::http::request::*;
 use crate::http::request_spec::*;
 use crate::http::response::*;
+use crate::http::sse::EventParser;
 use crate::http::timings::Timings;
 use crate::http::url::Url;
-use crate::http::{easy_ext, Call, Header, HttpError, Verbosity};
+use crate::http::websocket::{
+    Frame, OPCODE_BINARY, OPCODE_CLOSE, OPCODE_CONTINUATION, OPCODE_PING, OPCODE_PONG, OPCODE_TEXT,
+};
+use crate::http::{easy_ext, websocket, Call, Header, HttpError, Verbosity};
 use crate::runner::Output;
 use crate::util::logger::Logger;
 use crate::util::path::ContextDir;
@@ -101,9 +108,26 @@ impl Client {
             certificates: HashMap::new(),
         }
     }
+}
+
+/// An HTTP client, used by the runner to execute the HTTP requests of a Hurl file.
+///
+/// [`Client`] is the libcurl implementation used by Hurl. Library users can provide their own
+/// implementation (for instance, to mock HTTP exchanges and run Hurl files offline): requests
+/// are executed with [`HttpClient::execute`], redirections are followed on top of it, and the
+/// client owns its cookie storage.
+pub trait HttpClient {
+    /// Executes an HTTP request `request_spec`, without following redirection and returns a
+    /// [`Call`].
+    fn execute(
+        &mut self,
+        request_spec: &RequestSpec,
+        options: &ClientOptions,
+        logger: &mut Logger,
+    ) -> Result<Call, HttpError>;
 
     /// Executes an HTTP request `request_spec`, optionally follows redirection and returns a list of [`Call`].
-    pub fn execute_with_redirect(
+    fn execute_with_redirect(
         &mut self,
         request_spec: &RequestSpec,
         options: &ClientOptions,
@@ -118,11 +142,14 @@ impl Client {
         // single list of headers for the 2 responses and Hurl needs to keep every header of every
         // response.
         let mut redirect_count = 0;
+        // Requests already sent and redirected, with their redirection URL, used to detect loops.
+        let mut redirected = vec![];
         loop {
             let call = self.execute(&request_spec, &options, logger)?;
             let request_url = call.request.url.clone();
-            let redirect_url = self.follow_location(&request_url, &call.response)?;
+            let redirect_url = follow_location(&request_url, &call.response)?;
             let status = call.response.status;
+            let request = call.request.clone();
             calls.push(call);
             if !options.follow_location || redirect_url.is_none() {
                 break;
@@ -131,6 +158,14 @@ impl Client {
             logger.debug("");
             logger.debug(&format!("=> Redirect to {redirect_url}"));
             logger.debug("");
+            // The very same request (method, URL, headers including cookies) has already been
+            // redirected to the same location: following it again will never end.
+            if redirected.contains(&(request.clone(), redirect_url.clone())) {
+                return Err(HttpError::RedirectLoop {
+                    url: request.url.to_string(),
+                });
+            }
+            redirected.push((request, redirect_url.clone()));
             redirect_count += 1;
             if let Count::Finite(max_redirect) = options.max_redirect {
                 if redirect_count > max_redirect {
@@ -147,6 +182,7 @@ impl Client {
             if host_changed && !options.follow_location_trusted {
                 headers.retain(|h| !h.name_eq(AUTHORIZATION));
                 options.user = None;
+                options.oauth2_bearer = None;
             }
             request_spec = RequestSpec {
                 method: redirect_method,
@@ -158,9 +194,83 @@ impl Client {
         Ok(calls)
     }
 
+    /// Opens a WebSocket with the opening handshake `request_spec`, sends `frames` and returns the
+    /// [`Call`] of the handshake. The frames received from the server are kept in its response.
+    ///
+    /// WebSockets are not supported by default.
+    fn execute_websocket(
+        &mut self,
+        _request_spec: &RequestSpec,
+        _frames: &[Frame],
+        _options: &ClientOptions,
+        _logger: &mut Logger,
+    ) -> Result<Call, HttpError> {
+        Err(HttpError::WebSocket {
+            description: "WebSocket is not supported by this HTTP client".to_string(),
+        })
+    }
+
+    /// Returns cookie storage.
+    fn cookie_storage(&mut self) -> Vec<Cookie>;
+
+    /// Adds a cookie to the cookie jar.
+    fn add_cookie(&mut self, cookie: &Cookie, options: &ClientOptions);
+
+    /// Clears cookie storage.
+    fn clear_cookie_storage(&mut self, options: &ClientOptions);
+}
+
+/// Returns curl command-line for the HTTP `request_spec` run by `http_client`.
+pub(crate) fn curl_command_line(
+    http_client: &mut dyn HttpClient,
+    request_spec: &RequestSpec,
+    context_dir: &ContextDir,
+    output: Option<&Output>,
+    options: &ClientOptions,
+) -> String {
+    let mut arguments = vec!["curl".to_string()];
+    arguments.append(&mut request_spec.curl_args(context_dir));
+
+    // We extract the last part of the arguments (the url) to insert it
+    // after all the options
+    let url = arguments.pop().unwrap();
+
+    let cookies = all_cookies(&http_client.cookie_storage(), request_spec);
+    if !cookies.is_empty() {
+        arguments.push("--cookie".to_string());
+        arguments.push(format!(
+            "'{}'",
+            cookies
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+                .join("; ")
+        ));
+    }
+    arguments.append(&mut options.curl_args());
+
+    // --output is not an option of the HTTP client, we deal with it here:
+    match output {
+        Some(Output::File(filename)) => {
+            let filename = context_dir.resolved_path(filename);
+            arguments.push("--output".to_string());
+            arguments.push(filename.to_string_lossy().to_string());
+        }
+        Some(Output::Stdout) => {
+            arguments.push("--output".to_string());
+            arguments.push("-".to_string());
+        }
+        None => {}
+    }
+
+    arguments.push(url);
+    arguments.join(" ")
+}
+
+impl HttpClient for Client {
     /// Executes an HTTP request `request_spec`, without following redirection and returns a
     /// pair of [`Call`].
-    pub fn execute(
+    fn execute(
         &mut self,
         request_spec: &RequestSpec,
         options: &ClientOptions,
@@ -190,6 +300,21 @@ impl Client {
         let mut request_body = Vec::<u8>::new();
         let mut response_body = Vec::<u8>::new();
 
+        // A `text/event-stream` response doesn't end until the server closes it: its events are
+        // parsed as they are received, and the transfer is stopped once enough events have been
+        // received, or after the stream timeout.
+        let mut events = vec![];
+        let mut event_parser = EventParser::new();
+        let stream_start = Cell::new(None);
+        let stream_stopped = Cell::new(false);
+        let stream_timeout = options.stream_timeout.unwrap_or(DEFAULT_STREAM_TIMEOUT);
+        // The timeout is checked when data is received, and by the progress callback to also stop
+        // a silent stream. Progress is only enabled when a stream timeout is set, or when the
+        // request accepts an event stream.
+        let progress =
+            options.stream_timeout.is_some() || accepts_event_stream(&request_spec.headers);
+        self.handle.progress(progress)?;
+
         {
             let mut transfer = self.handle.transfer();
 
@@ -248,8 +373,12 @@ impl Client {
             transfer.header_function(|h| {
                 if let Some(s) = decode_header(h) {
                     if s.starts_with("HTTP/") {
+                        stream_start.set(None);
                         status_lines.push(s);
                     } else {
+                        if is_event_stream(&s) {
+                            stream_start.set(Some(Instant::now()));
+                        }
                         response_headers.push(s);
                     }
                 }
@@ -258,16 +387,36 @@ impl Client {
 
             transfer.write_function(|data| {
                 response_body.extend(data);
+                if let Some(start) = stream_start.get() {
+                    events.extend(event_parser.parse(data));
+                    if start.elapsed() >= stream_timeout {
+                        stream_stopped.set(true);
+                        return Ok(0);
+                    }
+                    if let Count::Finite(count) = options.stream_count {
+                        if events.len() >= count {
+                            events.truncate(count);
+                            stream_stopped.set(true);
+                            // Writing less bytes than received aborts the transfer.
+                            return Ok(0);
+                        }
+                    }
+                }
                 Ok(data.len())
             })?;
 
+            transfer.progress_function(|_, _, _, _| match stream_start.get() {
+                Some(start) if start.elapsed() >= stream_timeout => {
+                    stream_stopped.set(true);
+                    false
+                }
+                _ => true,
+            })?;
+
             if let Err(e) = transfer.perform() {
-                let code = e.code() as i32; // due to windows build
-                let description = match e.extra_description() {
-                    None => e.description().to_string(),
-                    Some(s) => s.to_string(),
-                };
-                return Err(HttpError::Libcurl { code, description });
+                if !stream_stopped.get() {
+                    return Err(transfer_error(&e));
+                }
             }
         }
 
@@ -292,7 +441,7 @@ impl Client {
             request_headers,
             request_body,
         );
-        let response = Response::new(
+        let mut response = Response::new(
             version,
             status,
             headers,
@@ -301,6 +450,8 @@ impl Client {
             url,
             certificate,
         );
+        let is_stream = stream_start.get().is_some();
+        response.events = events;
 
         if verbose {
             // FIXME: the cast to u64 seems not necessary.
@@ -308,9 +459,16 @@ impl Client {
             //  we have a segfault on Alpine Docker images and Rust 1.68.0, whereas it was
             //  ok with Rust >= 1.67.0.
             let duration = duration.as_millis() as u64;
-            logger.debug_important(&format!(
-                "Response: (received {length} bytes in {duration} ms)"
-            ));
+            if is_stream {
+                let count = response.events.len();
+                logger.debug_important(&format!(
+                    "Response: (received {count} events, {length} bytes in {duration} ms)"
+                ));
+            } else {
+                logger.debug_important(&format!(
+                    "Response: (received {length} bytes in {duration} ms)"
+                ));
+            }
             logger.debug("");
 
             // FIXME: Explain why there may be multiple status line
@@ -341,6 +499,227 @@ impl Client {
         })
     }
 
+    /// Opens a WebSocket with the opening handshake `request_spec`, sends `frames` and returns the
+    /// [`Call`] of the handshake. The frames received from the server are kept in its response.
+    fn execute_websocket(
+        &mut self,
+        request_spec: &RequestSpec,
+        frames: &[Frame],
+        options: &ClientOptions,
+        logger: &mut Logger,
+    ) -> Result<Call, HttpError> {
+        self.handle.reset();
+
+        // libcurl only opens the connection (TCP, and TLS for `wss`) to the equivalent HTTP URL:
+        // the handshake and the frames are written and read on this connection.
+        let http_url = match request_spec.url.strip_prefix("ws") {
+            Some(url) => format!("http{url}"),
+            None => request_spec.url.clone(),
+        };
+        let http_request_spec = RequestSpec {
+            url: http_url,
+            ..request_spec.clone()
+        };
+        let (url, _) = self.configure(&http_request_spec, options, logger)?;
+        self.handle.verbose(false)?;
+        self.handle.http_version(easy::HttpVersion::V11)?;
+        self.handle.connect_only(true)?;
+
+        let start = Instant::now();
+        let start_dt = Utc::now();
+        let verbose = options.verbosity.is_some();
+        self.handle.perform().map_err(|e| transfer_error(&e))?;
+
+        let inner_url =
+            url::Url::parse(&url).map_err(|e| HttpError::InvalidUrl(url.clone(), e.to_string()))?;
+        let mut target = inner_url.path().to_string();
+        if let Some(query) = inner_url.query() {
+            target.push('?');
+            target.push_str(query);
+        }
+        let host = inner_url.host_str().unwrap_or_default();
+        let host = match inner_url.port() {
+            Some(port) => format!("{host}:{port}"),
+            None => host.to_string(),
+        };
+
+        let key = websocket::new_key();
+        let mut request_headers = HeaderVec::new();
+        if !request_spec.headers.contains_key("Host") {
+            request_headers.push(Header::new("Host", &host));
+        }
+        request_headers.push(Header::new("Upgrade", "websocket"));
+        request_headers.push(Header::new("Connection", "Upgrade"));
+        request_headers.push(Header::new("Sec-WebSocket-Key", &key));
+        request_headers.push(Header::new("Sec-WebSocket-Version", "13"));
+        if !request_spec.headers.contains_key(USER_AGENT) {
+            let user_agent = match options.user_agent {
+                Some(ref u) => u.clone(),
+                None => format!("hurl/{}", clap::crate_version!()),
+            };
+            request_headers.push(Header::new(USER_AGENT, &user_agent));
+        }
+        if !request_spec.headers.contains_key(AUTHORIZATION) {
+            if let Some(oauth2_bearer) = &options.oauth2_bearer {
+                let value = format!("Bearer {oauth2_bearer}");
+                request_headers.push(Header::new(AUTHORIZATION, &value));
+            } else if let Some(user) = &options.user {
+                let authorization = general_purpose::STANDARD.encode(user.as_bytes());
+                let value = format!("Basic {authorization}");
+                request_headers.push(Header::new(AUTHORIZATION, &value));
+            }
+        }
+        let cookies = all_cookies(&self.cookie_storage(), request_spec);
+        if !cookies.is_empty() {
+            let value = cookies
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+                .join("; ");
+            request_headers.push(Header::new(COOKIE, &value));
+        }
+        for header in &request_spec.headers {
+            request_headers.push(header.clone());
+        }
+
+        let request_line = format!("GET {target} HTTP/1.1");
+        let mut handshake = format!("{request_line}\r\n");
+        for header in &request_headers {
+            handshake.push_str(&format!("{}: {}\r\n", header.name, header.value));
+        }
+        handshake.push_str("\r\n");
+        if verbose {
+            logger.debug_method_version_out(&request_line);
+            let headers = request_headers
+                .iter()
+                .map(|h| (h.name.as_str(), h.value.as_str()))
+                .collect::<Vec<_>>();
+            logger.debug_headers_out(&headers);
+        }
+        let deadline = start + options.timeout;
+        self.ws_send(handshake.as_bytes(), deadline)?;
+
+        // Frames sent by the server may directly follow the headers of the handshake response.
+        let mut data = vec![];
+        let headers_len = loop {
+            if let Some(pos) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+                break pos + 4;
+            }
+            if self.ws_recv(&mut data, deadline)? == 0 {
+                if Instant::now() >= deadline {
+                    return Err(HttpError::Timeout {
+                        code: curl_sys::CURLE_OPERATION_TIMEDOUT as i32,
+                        description: "WebSocket handshake timed out".to_string(),
+                    });
+                }
+                return Err(HttpError::CouldNotParseResponse);
+            }
+        };
+        let lines = split_lines(&data[..headers_len]);
+        let status_line = lines.first().ok_or(HttpError::CouldNotParseResponse)?;
+        let version = self.parse_response_version(status_line)?;
+        let status = status_line
+            .split(' ')
+            .nth(1)
+            .and_then(|s| s.parse::<u32>().ok())
+            .ok_or(HttpError::CouldNotParseResponse)?;
+        let headers = self.parse_response_headers(&lines[1..]);
+        if verbose {
+            logger.debug_status_version_in(status_line);
+            let headers = headers
+                .iter()
+                .map(|h| (h.name.as_str(), h.value.as_str()))
+                .collect::<Vec<_>>();
+            logger.debug_headers_in(&headers);
+        }
+        data.drain(..headers_len);
+
+        // If the server refuses to switch protocols, its response is returned as is and can be
+        // asserted.
+        let mut received = vec![];
+        if status == 101 {
+            let accept = headers.get("Sec-WebSocket-Accept").map(|h| h.value.clone());
+            if accept != Some(websocket::accept_key(&key)) {
+                return Err(HttpError::WebSocket {
+                    description: "invalid Sec-WebSocket-Accept header in handshake response"
+                        .to_string(),
+                });
+            }
+            for frame in frames {
+                let (opcode, payload) = match frame {
+                    Frame::Text(text) => (OPCODE_TEXT, text.as_bytes()),
+                    Frame::Binary(bytes) => (OPCODE_BINARY, bytes.as_slice()),
+                };
+                if verbose {
+                    logger.debug(&format!("Send {}", frame_debug(frame)));
+                }
+                self.ws_send(&websocket::encode(opcode, payload), deadline)?;
+            }
+            received = self.ws_receive_frames(data, options, logger)?;
+        }
+
+        let certificate = self.cert_info(logger)?;
+        let duration = start.elapsed();
+        let stop_dt = start_dt + duration;
+        let timings = Timings::new(&mut self.handle, start_dt, stop_dt);
+
+        let url = Url::from_str(&request_spec.url)?;
+        let request = Request::new("GET", url.clone(), request_headers, vec![]);
+        let mut response =
+            Response::new(version, status, headers, vec![], duration, url, certificate);
+        response.frames = received;
+
+        if verbose {
+            let count = response.frames.len();
+            let duration = duration.as_millis() as u64;
+            logger.debug_important(&format!(
+                "Response: (received {count} frames in {duration} ms)"
+            ));
+            logger.debug("");
+        }
+
+        Ok(Call {
+            request,
+            response,
+            timings,
+        })
+    }
+
+    /// Returns cookie storage.
+    fn cookie_storage(&mut self) -> Vec<Cookie> {
+        let list = self.handle.cookies().unwrap();
+        let mut cookies = vec![];
+        for cookie in list.iter() {
+            let line = str::from_utf8(cookie).unwrap();
+            if let Ok(cookie) = Cookie::from_str(line) {
+                cookies.push(cookie);
+            } else {
+                eprintln!("warning: line <{line}> can not be parsed as cookie");
+            }
+        }
+        cookies
+    }
+
+    /// Adds a cookie to the cookie jar.
+    fn add_cookie(&mut self, cookie: &Cookie, options: &ClientOptions) {
+        if options.verbosity.is_some() {
+            eprintln!("* add to cookie store: {cookie}");
+        }
+        self.handle
+            .cookie_list(cookie.to_string().as_str())
+            .unwrap();
+    }
+
+    /// Clears cookie storage.
+    fn clear_cookie_storage(&mut self, options: &ClientOptions) {
+        if options.verbosity.is_some() {
+            eprintln!("* clear cookie storage");
+        }
+        self.handle.cookie_list("ALL").unwrap();
+    }
+}
+
+impl Client {
     /// Configure libcurl handle to send a `request_spec`, using `options`.
     /// If configuration is successful, returns a tuple of the concrete requested URL and method.
     fn configure(
@@ -387,7 +766,18 @@ impl Client {
             logger.debug("Force refreshing connections because requested HTTP version change");
             self.handle.fresh_connect(true)?;
         }
-        self.handle.http_version(options.http_version.into())?;
+        // gRPC servers don't upgrade HTTP/1.1 connections: gRPC calls over plain HTTP/2 are sent
+        // with prior knowledge.
+        let grpc = request_spec.implicit_content_type.as_deref() == Some("application/grpc");
+        if http_version == RequestedHttpVersion::Http2
+            && grpc
+            && request_spec.url.starts_with("http://")
+        {
+            self.handle
+                .http_version(easy::HttpVersion::V2PriorKnowledge)?;
+        } else {
+            self.handle.http_version(options.http_version.into())?;
+        }
 
         self.handle.ip_resolve(options.ip_resolve.into())?;
 
@@ -409,20 +799,16 @@ impl Client {
             self.handle.ssl_cert_type("PEM")?;
         }
         if let Some(client_cert_file) = &options.client_cert_file {
-            match parse_cert_password(client_cert_file) {
-                (cert, Some(password)) => {
-                    self.handle.ssl_cert(cert)?;
-                    self.handle.key_password(&password)?;
-                }
-                (cert, None) => {
-                    self.handle.ssl_cert(cert)?;
-                }
+            let (cert, password) = parse_cert_password(client_cert_file);
+            self.handle.ssl_cert_type(cert_type(&cert))?;
+            self.handle.ssl_cert(cert)?;
+            if let Some(password) = password {
+                self.handle.key_password(&password)?;
             }
-            self.handle.ssl_cert_type("PEM")?;
         }
         if let Some(client_key_file) = &options.client_key_file {
             self.handle.ssl_key(client_key_file)?;
-            self.handle.ssl_cert_type("PEM")?;
+            self.handle.ssl_key_type("PEM")?;
         }
         self.handle.path_as_is(options.path_as_is)?;
         if let Some(proxy) = &options.proxy {
@@ -434,6 +820,9 @@ impl Client {
         if let Some(unix_socket) = &options.unix_socket {
             self.handle.unix_socket(unix_socket)?;
         }
+        if let Some(doh_url) = &options.doh_url {
+            self.handle.doh_url(Some(doh_url))?;
+        }
         if let Some(filename) = &options.netrc_file {
             easy_ext::netrc_file(&mut self.handle, filename)?;
             self.handle.netrc(if options.netrc_optional {
@@ -548,15 +937,25 @@ impl Client {
             list.append(&format!("{}: {user_agent}", USER_AGENT))?;
         }
 
-        if let Some(user) = &options.user {
-            if options.aws_sigv4.is_some() {
+        if let Some(oauth2_bearer) = &options.oauth2_bearer {
+            if !request_spec.headers.contains_key(AUTHORIZATION) {
+                list.append(&format!("{}: Bearer {oauth2_bearer}", AUTHORIZATION))?;
+            }
+        } else if let Some(user) = &options.user {
+            if options.aws_sigv4.is_some() || options.digest || options.ntlm {
                 // curl's aws_sigv4 support needs to know the username and password for the
                 // request, as it uses those values to calculate the Authorization header for the
-                // AWS V4 signature.
+                // AWS V4 signature. Digest and NTLM authentication also need them to answer the
+                // server challenge.
                 if let Some((username, password)) = user.split_once(':') {
                     self.handle.username(username)?;
                     self.handle.password(password)?;
                 }
+                if options.digest || options.ntlm {
+                    let mut auth = Auth::new();
+                    auth.digest(options.digest).ntlm(options.ntlm);
+                    self.handle.http_auth(&auth)?;
+                }
             } else {
                 let user = user.as_bytes();
                 let authorization = general_purpose::STANDARD.encode(user);
@@ -653,6 +1052,146 @@ impl Client {
             .join("&")
     }
 
+    /// Writes `data` to the connection opened by libcurl, waiting until `deadline`.
+    fn ws_send(&mut self, data: &[u8], deadline: Instant) -> Result<(), HttpError> {
+        let mut pos = 0;
+        while pos < data.len() {
+            match self.handle.send(&data[pos..]) {
+                Ok(n) => pos += n,
+                // The socket is not ready: we wait until it is writable before retrying.
+                Err(e) if e.is_again() => {
+                    if Instant::now() >= deadline {
+                        return Err(HttpError::Timeout {
+                            code: curl_sys::CURLE_OPERATION_TIMEDOUT as i32,
+                            description: "WebSocket send timed out".to_string(),
+                        });
+                    }
+                    self.ws_wait(true, deadline)?;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads available bytes from the connection opened by libcurl into `data`, waiting until
+    /// `deadline`.
+    ///
+    /// Returns the number of bytes read, 0 if the connection has been closed or if the deadline is
+    /// reached.
+    fn ws_recv(&mut self, data: &mut Vec<u8>, deadline: Instant) -> Result<usize, HttpError> {
+        let mut buffer = [0; 16 * 1024];
+        loop {
+            match self.handle.recv(&mut buffer) {
+                Ok(n) => {
+                    data.extend(&buffer[..n]);
+                    return Ok(n);
+                }
+                Err(e) if e.is_again() => {
+                    if Instant::now() >= deadline {
+                        return Ok(0);
+                    }
+                    self.ws_wait(false, deadline)?;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Waits until the connection opened by libcurl is readable (or writable if `write` is true),
+    /// or until `deadline`.
+    fn ws_wait(&mut self, write: bool, deadline: Instant) -> Result<(), HttpError> {
+        let socket = easy_ext::active_socket(&self.handle)?;
+        let mut wait_fd = WaitFd::new();
+        wait_fd.set_fd(socket);
+        if write {
+            wait_fd.poll_on_write(true);
+        } else {
+            wait_fd.poll_on_read(true);
+        }
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        Multi::new()
+            .wait(&mut [wait_fd], timeout)
+            .map_err(|e| HttpError::WebSocket {
+                description: e.to_string(),
+            })?;
+        Ok(())
+    }
+
+    /// Receives frames from the WebSocket, `data` being the bytes already read.
+    ///
+    /// Frames are received until the server closes the WebSocket, or `options.stream_count`
+    /// frames have been received, or `options.stream_timeout` is reached. Control frames are
+    /// answered and are not returned.
+    fn ws_receive_frames(
+        &mut self,
+        mut data: Vec<u8>,
+        options: &ClientOptions,
+        logger: &mut Logger,
+    ) -> Result<Vec<Frame>, HttpError> {
+        let verbose = options.verbosity.is_some();
+        let deadline = Instant::now() + options.stream_timeout.unwrap_or(DEFAULT_STREAM_TIMEOUT);
+        let mut frames = vec![];
+        // Opcode and payload of a fragmented message, until its last frame is received.
+        let mut message: Option<(u8, Vec<u8>)> = None;
+        loop {
+            if let Count::Finite(count) = options.stream_count {
+                if frames.len() >= count {
+                    break;
+                }
+            }
+            let Some((raw_frame, len)) = websocket::decode(&data) else {
+                if self.ws_recv(&mut data, deadline)? == 0 {
+                    break;
+                }
+                continue;
+            };
+            data.drain(..len);
+            match raw_frame.opcode {
+                OPCODE_PING => {
+                    let pong = websocket::encode(OPCODE_PONG, &raw_frame.payload);
+                    self.ws_send(&pong, deadline)?;
+                    continue;
+                }
+                OPCODE_PONG => continue,
+                OPCODE_CLOSE => {
+                    // We echo the status code of the server to complete the closing handshake.
+                    let code = &raw_frame.payload[..raw_frame.payload.len().min(2)];
+                    let _ = self.ws_send(&websocket::encode(OPCODE_CLOSE, code), deadline);
+                    return Ok(frames);
+                }
+                _ => {}
+            }
+            let (opcode, payload) = match message.take() {
+                Some((opcode, mut payload)) if raw_frame.opcode == OPCODE_CONTINUATION => {
+                    payload.extend(raw_frame.payload);
+                    (opcode, payload)
+                }
+                _ => (raw_frame.opcode, raw_frame.payload),
+            };
+            if !raw_frame.fin {
+                message = Some((opcode, payload));
+                continue;
+            }
+            let frame = if opcode == OPCODE_TEXT {
+                let text = String::from_utf8(payload).map_err(|_| HttpError::WebSocket {
+                    description: "text frame is not valid UTF-8".to_string(),
+                })?;
+                Frame::Text(text)
+            } else {
+                Frame::Binary(payload)
+            };
+            if verbose {
+                logger.debug(&format!("Receive {}", frame_debug(&frame)));
+            }
+            frames.push(frame);
+        }
+        // We close the WebSocket (normal closure), without waiting for the server.
+        let close = websocket::encode(OPCODE_CLOSE, &1000_u16.to_be_bytes());
+        let _ = self.ws_send(&close, Instant::now() + Duration::from_secs(1));
+        Ok(frames)
+    }
+
     /// Parses HTTP response version.
     fn parse_response_version(&mut self, line: &str) -> Result<HttpVersion, HttpError> {
         if line.starts_with("HTTP/1.0") {
@@ -679,108 +1218,6 @@ impl Client {
         headers
     }
 
-    /// Retrieves an optional location to follow
-    ///
-    /// You need:
-    /// 1. the option follow_location set to true
-    /// 2. a 3xx response code
-    /// 3. a header Location
-    fn follow_location(
-        &mut self,
-        request_url: &Url,
-        response: &Response,
-    ) -> Result<Option<Url>, HttpError> {
-        let response_code = response.status;
-        if !(300..400).contains(&response_code) {
-            return Ok(None);
-        }
-        let Some(location) = response.headers.get(LOCATION) else {
-            return Ok(None);
-        };
-        let url = request_url.join(&location.value)?;
-        Ok(Some(url))
-    }
-
-    /// Returns cookie storage.
-    pub fn cookie_storage(&mut self) -> Vec<Cookie> {
-        let list = self.handle.cookies().unwrap();
-        let mut cookies = vec![];
-        for cookie in list.iter() {
-            let line = str::from_utf8(cookie).unwrap();
-            if let Ok(cookie) = Cookie::from_str(line) {
-                cookies.push(cookie);
-            } else {
-                eprintln!("warning: line <{line}> can not be parsed as cookie");
-            }
-        }
-        cookies
-    }
-
-    /// Adds a cookie to the cookie jar.
-    pub fn add_cookie(&mut self, cookie: &Cookie, options: &ClientOptions) {
-        if options.verbosity.is_some() {
-            eprintln!("* add to cookie store: {cookie}");
-        }
-        self.handle
-            .cookie_list(cookie.to_string().as_str())
-            .unwrap();
-    }
-
-    /// Clears cookie storage.
-    pub fn clear_cookie_storage(&mut self, options: &ClientOptions) {
-        if options.verbosity.is_some() {
-            eprintln!("* clear cookie storage");
-        }
-        self.handle.cookie_list("ALL").unwrap();
-    }
-
-    /// Returns curl command-line for the HTTP `request_spec` run by this client.
-    pub fn curl_command_line(
-        &mut self,
-        request_spec: &RequestSpec,
-        context_dir: &ContextDir,
-        output: Option<&Output>,
-        options: &ClientOptions,
-    ) -> String {
-        let mut arguments = vec!["curl".to_string()];
-        arguments.append(&mut request_spec.curl_args(context_dir));
-
-        // We extract the last part of the arguments (the url) to insert it
-        // after all the options
-        let url = arguments.pop().unwrap();
-
-        let cookies = all_cookies(&self.cookie_storage(), request_spec);
-        if !cookies.is_empty() {
-            arguments.push("--cookie".to_string());
-            arguments.push(format!(
-                "'{}'",
-                cookies
-                    .iter()
-                    .map(|c| c.to_string())
-                    .collect::<Vec<String>>()
-                    .join("; ")
-            ));
-        }
-        arguments.append(&mut options.curl_args());
-
-        // --output is not an option of the HTTP client, we deal with it here:
-        match output {
-            Some(Output::File(filename)) => {
-                let filename = context_dir.resolved_path(filename);
-                arguments.push("--output".to_string());
-                arguments.push(filename.to_string_lossy().to_string());
-            }
-            Some(Output::Stdout) => {
-                arguments.push("--output".to_string());
-                arguments.push("-".to_string());
-            }
-            None => {}
-        }
-
-        arguments.push(url);
-        arguments.join(" ")
-    }
-
     /// Returns the SSL certificates information associated to this call.
     ///
     /// Certificate information are cached by libcurl handle connection id, in order to get
@@ -815,6 +1252,73 @@ impl Client {
     }
 }
 
+/// Converts a libcurl transfer error `e` to an [`HttpError`].
+fn transfer_error(e: &curl::Error) -> HttpError {
+    let code = e.code() as i32; // due to windows build
+    let description = match e.extra_description() {
+        None => e.description().to_string(),
+        Some(s) => s.to_string(),
+    };
+    if e.is_operation_timedout() {
+        return HttpError::Timeout { code, description };
+    }
+    // A wrong password for an encrypted private key is reported as a generic SSL certificate
+    // problem by libcurl.
+    if e.is_ssl_certproblem() {
+        return HttpError::ClientCertificate { code, description };
+    }
+    HttpError::Libcurl { code, description }
+}
+
+/// Returns true if the request `headers` accept a `text/event-stream` response.
+fn accepts_event_stream(headers: &HeaderVec) -> bool {
+    headers
+        .get_all(ACCEPT)
+        .iter()
+        .any(|h| h.value.to_lowercase().contains("text/event-stream"))
+}
+
+/// Returns true if the response header line `header` declares a `text/event-stream` content.
+fn is_event_stream(header: &str) -> bool {
+    match Header::parse(header) {
+        Some(header) => {
+            header.name_eq(CONTENT_TYPE)
+                && header
+                    .value
+                    .trim()
+                    .to_lowercase()
+                    .starts_with("text/event-stream")
+        }
+        None => false,
+    }
+}
+
+/// Returns a description of a WebSocket `frame` for the logs.
+fn frame_debug(frame: &Frame) -> String {
+    match frame {
+        Frame::Text(text) => format!("text frame: {text}"),
+        Frame::Binary(bytes) => format!("binary frame: {}", hex::encode(bytes)),
+    }
+}
+
+/// Retrieves an optional location to follow
+///
+/// You need:
+/// 1. the option follow_location set to true
+/// 2. a 3xx response code
+/// 3. a header Location
+fn follow_location(request_url: &Url, response: &Response) -> Result<Option<Url>, HttpError> {
+    let response_code = response.status;
+    if !(300..400).contains(&response_code) {
+        return Ok(None);
+    }
+    let Some(location) = response.headers.get(LOCATION) else {
+        return Ok(None);
+    };
+    let url = request_url.join(&location.value)?;
+    Ok(Some(url))
+}
+
 /// Returns the method used for redirecting a request/response with `response_status`.
 fn redirect_method(response_status: u32, original_method: Method) -> Method {
     // This replicates curl's behavior
@@ -922,7 +1426,7 @@ fn to_list(items: &[String]) -> List {
 /// > In the <certificate> portion of the argument, you must escape the character ":" as "\:" so
 /// > that it is not recognized as the password delimiter. Similarly, you must escape the character
 /// > "\" as "\\" so that it is not recognized as an escape character.
-fn parse_cert_password(cert_and_pass: &str) -> (String, Option<String>) {
+pub(crate) fn parse_cert_password(cert_and_pass: &str) -> (String, Option<String>) {
     let mut iter = cert_and_pass.chars();
     let mut cert = String::new();
     let mut password = String::new();
@@ -962,6 +1466,19 @@ fn parse_cert_password(cert_and_pass: &str) -> (String, Option<String>) {
     }
 }
 
+/// Returns the type of the client certificate file `cert`, given its extension.
+///
+/// PKCS#12 files (with `.p12` or `.pfx` extension) bundle the certificate and its private key,
+/// every other file is considered to be in PEM format.
+pub(crate) fn cert_type(cert: &str) -> &'static str {
+    let cert = cert.to_lowercase();
+    if cert.ends_with(".p12") || cert.ends_with(".pfx") {
+        "P12"
+    } else {
+        "PEM"
+    }
+}
+
 impl From<RequestedHttpVersion> for easy::HttpVersion {
     fn from(value: RequestedHttpVersion) -> Self {
         match value {
@@ -990,6 +1507,8 @@ mod tests {
     use std::path::PathBuf;
 
     use super::*;
+    use crate::util::logger::LoggerOptionsBuilder;
+    use crate::util::term::{Stderr, WriteMode};
 
     #[test]
     fn test_parse_header() {
@@ -1014,6 +1533,16 @@ mod tests {
         assert_eq!(lines.get(2).unwrap().as_str(), "");
     }
 
+    #[test]
+    fn test_accepts_event_stream() {
+        let mut headers = HeaderVec::new();
+        assert!(!accepts_event_stream(&headers));
+        headers.push(Header::new("Accept", "application/json"));
+        assert!(!accepts_event_stream(&headers));
+        headers.push(Header::new("accept", "Text/Event-Stream"));
+        assert!(accepts_event_stream(&headers));
+    }
+
     #[test]
     fn test_match_cookie() {
         let cookie = Cookie {
@@ -1161,7 +1690,7 @@ mod tests {
             ..Default::default()
         };
 
-        let cmd = client.curl_command_line(&request, &context_dir, output, &options);
+        let cmd = curl_command_line(&mut client, &request, &context_dir, output, &options);
         assert_eq!(
             cmd,
             "curl \
@@ -1212,4 +1741,121 @@ mod tests {
             ("foo\\".to_string(), Some("toto\\:tata:tutu".to_string()))
         );
     }
+
+    /// A client that redirects the first request to `location`, and records the OAuth 2.0 token
+    /// of each executed request.
+    struct RedirectClient {
+        location: String,
+        bearers: Vec<Option<String>>,
+    }
+
+    impl HttpClient for RedirectClient {
+        fn execute(
+            &mut self,
+            request_spec: &RequestSpec,
+            options: &ClientOptions,
+            _logger: &mut Logger,
+        ) -> Result<Call, HttpError> {
+            let url = Url::from_str(&request_spec.url).unwrap();
+            let mut headers = HeaderVec::new();
+            let status = if self.bearers.is_empty() {
+                headers.push(Header::new(LOCATION, &self.location));
+                302
+            } else {
+                200
+            };
+            self.bearers.push(options.oauth2_bearer.clone());
+            let request = Request::new(
+                &request_spec.method.0,
+                url.clone(),
+                HeaderVec::new(),
+                vec![],
+            );
+            let response = Response::new(
+                HttpVersion::Http11,
+                status,
+                headers,
+                vec![],
+                Duration::default(),
+                url,
+                None,
+            );
+            Ok(Call {
+                request,
+                response,
+                timings: Timings::default(),
+            })
+        }
+
+        fn cookie_storage(&mut self) -> Vec<Cookie> {
+            vec![]
+        }
+
+        fn add_cookie(&mut self, _cookie: &Cookie, _options: &ClientOptions) {}
+
+        fn clear_cookie_storage(&mut self, _options: &ClientOptions) {}
+    }
+
+    #[test]
+    fn test_redirect_oauth2_bearer() {
+        let bearers = |location: &str, follow_location_trusted: bool| {
+            let mut client = RedirectClient {
+                location: location.to_string(),
+                bearers: vec![],
+            };
+            let request_spec = RequestSpec {
+                url: "http://localhost:8000/redirect".to_string(),
+                ..Default::default()
+            };
+            let options = ClientOptions {
+                follow_location: true,
+                follow_location_trusted,
+                oauth2_bearer: Some("s3cr3t".to_string()),
+                ..Default::default()
+            };
+            let logger_options = LoggerOptionsBuilder::new().build();
+            let mut logger = Logger::new(&logger_options, Stderr::new(WriteMode::Buffered));
+            let calls = client
+                .execute_with_redirect(&request_spec, &options, &mut logger)
+                .unwrap();
+            assert_eq!(calls.len(), 2);
+            client.bearers
+        };
+        let token = Some("s3cr3t".to_string());
+
+        // The token is not sent to another host...
+        assert_eq!(
+            bearers("http://example.org/hello", false),
+            vec![token.clone(), None]
+        );
+        // ... unless the redirected host is trusted.
+        assert_eq!(
+            bearers("http://example.org/hello", true),
+            vec![token.clone(), token.clone()]
+        );
+        assert_eq!(bearers("/hello", false), vec![token.clone(), token.clone()]);
+    }
+
+    #[test]
+    fn test_transfer_error() {
+        let error = transfer_error(&curl::Error::new(58));
+        assert!(matches!(
+            error,
+            HttpError::ClientCertificate { code: 58, .. }
+        ));
+        assert!(error
+            .message()
+            .contains("check the client certificate and key files, and the password of the key"));
+
+        let error = transfer_error(&curl::Error::new(7));
+        assert!(matches!(error, HttpError::Libcurl { code: 7, .. }));
+    }
+
+    #[test]
+    fn test_cert_type() {
+        assert_eq!(cert_type("client.pem"), "PEM");
+        assert_eq!(cert_type("client.crt"), "PEM");
+        assert_eq!(cert_type("client.p12"), "P12");
+        assert_eq!(cert_type("/tmp/Client.PFX"), "P12");
+    }
 }
@@ -21,7 +21,9 @@ use std::time::Duration;
 
 use curl::easy::Easy;
 use curl::Error;
-use curl_sys::{curl_certinfo, curl_off_t, curl_slist, CURLINFO, CURLOPT_NETRC_FILE};
+use curl_sys::{
+    curl_certinfo, curl_off_t, curl_slist, curl_socket_t, CURLINFO, CURLOPT_NETRC_FILE,
+};
 
 /// Some definitions not present in curl-sys
 const CURLINFO_OFF_T: CURLINFO = 0x600000;
@@ -33,6 +35,8 @@ const CURLINFO_PRETRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 53;
 const CURLINFO_STARTTRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 54;
 const CURLINFO_APPCONNECT_TIME_T: CURLINFO = CURLINFO_OFF_T + 56;
 const CURLINFO_CONN_ID: CURLINFO = CURLINFO_OFF_T + 64;
+const CURLINFO_SOCKET: CURLINFO = 0x500000;
+const CURLINFO_ACTIVESOCKET: CURLINFO = CURLINFO_SOCKET + 44;
 
 /// Represents certificate information.
 /// `data` has format "name:content";
@@ -71,6 +75,16 @@ pub fn conn_id(easy: &Easy) -> Result<i64, Error> {
     }
 }
 
+/// Returns the socket of the connection opened by this libcurl handle, with `connect_only`.
+pub fn active_socket(easy: &Easy) -> Result<curl_socket_t, Error> {
+    unsafe {
+        let mut socket: curl_socket_t = 0 as curl_socket_t;
+        let rc = curl_sys::curl_easy_getinfo(easy.raw(), CURLINFO_ACTIVESOCKET, &mut socket);
+        cvt(easy, rc)?;
+        Ok(socket)
+    }
+}
+
 // Timing of a typical HTTP exchange (over TLS 1.2 connection) from libcurl
 // (courtesy of <https://blog.cloudflare.com/a-question-of-timing/>
 // =========================================================================
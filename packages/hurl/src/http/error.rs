@@ -20,6 +20,10 @@ use crate::http::RequestedHttpVersion;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HttpError {
+    ClientCertificate {
+        code: i32,
+        description: String,
+    },
     CouldNotParseResponse,
     CouldNotUncompressResponse {
         description: String,
@@ -38,12 +42,25 @@ pub enum HttpError {
         option: String,
         minimum_version: String,
     },
+    RedirectLoop {
+        url: String,
+    },
+    Timeout {
+        code: i32,
+        description: String,
+    },
     TooManyRedirect,
+    UncompressedBodyTooLarge {
+        max_size: u64,
+    },
     UnsupportedContentEncoding {
         description: String,
     },
     UnsupportedHttpVersion(RequestedHttpVersion),
     InvalidUrl(String, String),
+    WebSocket {
+        description: String,
+    },
 }
 
 impl From<curl::Error> for HttpError {
@@ -57,6 +74,7 @@ impl From<curl::Error> for HttpError {
 impl HttpError {
     pub fn description(&self) -> String {
         match self {
+            HttpError::ClientCertificate { .. } => "Client certificate".to_string(),
             HttpError::CouldNotParseResponse => "HTTP connection".to_string(),
             HttpError::CouldNotUncompressResponse { .. } => "Decompression error".to_string(),
             HttpError::InvalidCharset { .. } => "Invalid charset".to_string(),
@@ -64,14 +82,22 @@ impl HttpError {
             HttpError::InvalidUrl(..) => "Invalid URL".to_string(),
             HttpError::Libcurl { .. } => "HTTP connection".to_string(),
             HttpError::LibcurlUnknownOption { .. } => "HTTP connection".to_string(),
+            HttpError::RedirectLoop { .. } => "HTTP connection".to_string(),
+            HttpError::Timeout { .. } => "HTTP connection".to_string(),
             HttpError::TooManyRedirect => "HTTP connection".to_string(),
+            HttpError::UncompressedBodyTooLarge { .. } => "Decompression error".to_string(),
             HttpError::UnsupportedContentEncoding { .. } => "Decompression error".to_string(),
             HttpError::UnsupportedHttpVersion(_) => "Unsupported HTTP version".to_string(),
+            HttpError::WebSocket { .. } => "WebSocket".to_string(),
         }
     }
 
     pub fn message(&self) -> String {
         match self {
+            HttpError::ClientCertificate { code, description } => format!(
+                "({code}) {description}, check the client certificate and key files, and the \
+                password of the key"
+            ),
             HttpError::CouldNotParseResponse => "could not parse Response".to_string(),
             HttpError::CouldNotUncompressResponse { description } => {
                 format!("could not uncompress response with {description}")
@@ -90,13 +116,21 @@ impl HttpError {
                 option,
                 minimum_version,
             } => format!("Option {option} requires libcurl version {minimum_version} or higher"),
+            HttpError::RedirectLoop { url } => {
+                format!("redirect loop detected, <{url}> has already been requested")
+            }
+            HttpError::Timeout { code, description } => format!("({code}) {description}"),
             HttpError::TooManyRedirect => "too many redirect".to_string(),
+            HttpError::UncompressedBodyTooLarge { max_size } => {
+                format!("decompressed response body is larger than {max_size} bytes")
+            }
             HttpError::UnsupportedHttpVersion(version) => {
                 format!("{version} is not supported, check --version").to_string()
             }
             HttpError::UnsupportedContentEncoding { description } => {
                 format!("compression {description} is not supported").to_string()
             }
+            HttpError::WebSocket { description } => description.clone(),
         }
     }
 }
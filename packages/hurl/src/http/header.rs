@@ -18,6 +18,8 @@
 use core::fmt;
 use std::slice::Iter;
 
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Accept>
+pub const ACCEPT: &str = "Accept";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Accept-Encoding>
 pub const ACCEPT_ENCODING: &str = "Accept-Encoding";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Authorization>
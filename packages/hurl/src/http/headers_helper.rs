@@ -46,19 +46,23 @@ impl HeaderVec {
 
     /// Returns list of content encoding from HTTP response headers.
     ///
+    /// Values of multiple `Content-Encoding` headers are concatenated, in the order in which
+    /// they appear.
+    ///
     /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Encoding>
     pub fn content_encoding(&self) -> Result<Vec<ContentEncoding>, HttpError> {
-        for header in self {
-            if header.name_eq(CONTENT_ENCODING) {
-                let mut encodings = vec![];
-                for value in header.value.split(',') {
-                    let encoding = ContentEncoding::parse(value.trim())?;
-                    encodings.push(encoding);
+        let mut encodings = vec![];
+        for header in self.get_all(CONTENT_ENCODING) {
+            for value in header.value.split(',') {
+                let value = value.trim();
+                if value.is_empty() {
+                    continue;
                 }
-                return Ok(encodings);
+                let encoding = ContentEncoding::parse(value)?;
+                encodings.push(encoding);
             }
         }
-        Ok(vec![])
+        Ok(encodings)
     }
 }
 
@@ -89,6 +93,19 @@ mod tests {
             headers.content_encoding(),
             Ok(vec![ContentEncoding::Deflate, ContentEncoding::Gzip])
         );
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Encoding", "deflate"));
+        headers.push(Header::new("Content-Type", "text/plain"));
+        headers.push(Header::new("content-encoding", "br, zstd"));
+        assert_eq!(
+            headers.content_encoding(),
+            Ok(vec![
+                ContentEncoding::Deflate,
+                ContentEncoding::Brotli,
+                ContentEncoding::Zstd
+            ])
+        );
     }
 
     #[test]
@@ -104,5 +121,16 @@ mod tests {
         let mut headers = HeaderVec::new();
         headers.push(Header::new("content-type", "text/plain"));
         assert_eq!(headers.character_encoding().unwrap().name(), "utf-8");
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new(
+            "content-type",
+            "text/plain; charset=\"UTF-16LE\"",
+        ));
+        assert_eq!(headers.character_encoding().unwrap().name(), "utf-16le");
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("content-type", "text/html; charset=Shift_JIS"));
+        assert_eq!(headers.character_encoding().unwrap().name(), "windows-31j");
     }
 }
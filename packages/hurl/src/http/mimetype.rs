@@ -54,7 +54,14 @@ pub fn charset(mime_type: &str) -> Option<String> {
     for part in parts {
         let param = part.trim().split('=').collect::<Vec<_>>();
         if param.len() == 2 && param[0].trim().eq_ignore_ascii_case("charset") {
-            return Some(param[1].trim().to_string());
+            // The charset value can be a quoted string
+            // (see <https://www.rfc-editor.org/rfc/rfc9110#name-parameters>).
+            let value = param[1].trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+            return Some(value.to_string());
         }
     }
     None
@@ -87,5 +94,10 @@ pub mod tests {
             charset("text/plain; version=0.0.4; charset=utf-8; escaping=values"),
             Some("utf-8".to_string())
         );
+
+        assert_eq!(
+            charset("text/plain; charset=\"Shift_JIS\""),
+            Some("Shift_JIS".to_string())
+        );
     }
 }
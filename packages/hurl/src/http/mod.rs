@@ -18,26 +18,34 @@
 //! Various HTTP structures like requests, responses, cookies etc.
 //!
 //! The Hurl HTTP engine is not public. It's a wrapper around libcurl and only the models
-//! returned by an HTTP exchange are exposed.
+//! returned by an HTTP exchange are exposed, along with the [`HttpClient`] trait to provide
+//! another HTTP engine.
 pub use self::call::Call;
 pub use self::certificate::Certificate;
-pub(crate) use self::client::Client;
+pub use self::client::HttpClient;
+pub(crate) use self::client::{curl_command_line, Client};
 pub use self::cookie::{CookieAttribute, ResponseCookie};
 pub use self::core::Cookie;
 pub(crate) use self::core::{Param, RequestCookie};
-pub(crate) use self::error::HttpError;
+pub use self::error::HttpError;
+pub(crate) use self::header::LOCATION;
 pub use self::header::{
     Header, HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, USER_AGENT,
 };
-pub(crate) use self::options::{ClientOptions, Verbosity};
+pub use self::options::ClientOptions;
+pub(crate) use self::options::Verbosity;
 pub use self::request::{IpResolve, Request, RequestedHttpVersion};
-pub(crate) use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
+pub use self::request_spec::RequestSpec;
+pub(crate) use self::request_spec::{Body, FileParam, Method, MultipartParam};
 pub use self::response::{HttpVersion, Response};
 #[cfg(test)]
+pub(crate) use self::sse::Event;
+#[cfg(test)]
 pub use self::tests::*;
 pub use self::timings::Timings;
 pub use self::url::Url;
 pub use self::version::libcurl_version_info;
+pub(crate) use self::websocket::Frame;
 
 mod call;
 mod certificate;
@@ -58,9 +66,11 @@ mod response;
 mod response_cookie;
 mod response_debug;
 mod response_decoding;
+mod sse;
 #[cfg(test)]
 mod tests;
 mod timings;
 mod timings_debug;
 mod url;
 mod version;
+mod websocket;
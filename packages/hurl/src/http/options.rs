@@ -18,9 +18,13 @@
 use hurl_core::typing::Count;
 use std::time::Duration;
 
+use crate::http::client;
 use crate::http::request::RequestedHttpVersion;
 use crate::http::IpResolve;
 
+/// Maximum time to receive WebSocket frames or server-sent events, when no stream timeout is set.
+pub const DEFAULT_STREAM_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[derive(Debug, Clone)]
 pub struct ClientOptions {
     pub aws_sigv4: Option<String>,
@@ -31,6 +35,8 @@ pub struct ClientOptions {
     pub connect_timeout: Duration,
     pub connects_to: Vec<String>,
     pub cookie_input_file: Option<String>,
+    pub digest: bool,
+    pub doh_url: Option<String>,
     pub follow_location: bool,
     pub follow_location_trusted: bool,
     pub http_version: RequestedHttpVersion,
@@ -42,10 +48,15 @@ pub struct ClientOptions {
     pub netrc_file: Option<String>,
     pub netrc_optional: bool,
     pub no_proxy: Option<String>,
+    pub ntlm: bool,
+    pub oauth2_bearer: Option<String>,
     pub path_as_is: bool,
     pub proxy: Option<String>,
     pub resolves: Vec<String>,
     pub ssl_no_revoke: bool,
+    pub stream_count: Count,
+    /// Maximum time to receive frames or events, [`DEFAULT_STREAM_TIMEOUT`] if not set.
+    pub stream_timeout: Option<Duration>,
     pub timeout: Duration,
     pub unix_socket: Option<String>,
     pub user: Option<String>,
@@ -71,6 +82,8 @@ impl Default for ClientOptions {
             connect_timeout: Duration::from_secs(300),
             connects_to: vec![],
             cookie_input_file: None,
+            digest: false,
+            doh_url: None,
             follow_location: false,
             follow_location_trusted: false,
             http_version: RequestedHttpVersion::default(),
@@ -82,10 +95,14 @@ impl Default for ClientOptions {
             netrc_file: None,
             netrc_optional: false,
             no_proxy: None,
+            ntlm: false,
+            oauth2_bearer: None,
             path_as_is: false,
             proxy: None,
             resolves: vec![],
             ssl_no_revoke: false,
+            stream_count: Count::Infinite,
+            stream_timeout: None,
             timeout: Duration::from_secs(300),
             unix_socket: None,
             user: None,
@@ -111,6 +128,11 @@ impl ClientOptions {
         if let Some(ref client_cert_file) = self.client_cert_file {
             arguments.push("--cert".to_string());
             arguments.push(client_cert_file.clone());
+            let (cert, _) = client::parse_cert_password(client_cert_file);
+            if client::cert_type(&cert) == "P12" {
+                arguments.push("--cert-type".to_string());
+                arguments.push("P12".to_string());
+            }
         }
         if let Some(ref client_key_file) = self.client_key_file {
             arguments.push("--key".to_string());
@@ -131,6 +153,13 @@ impl ClientOptions {
             arguments.push("--cookie".to_string());
             arguments.push(cookie_file.clone());
         }
+        if self.digest {
+            arguments.push("--digest".to_string());
+        }
+        if let Some(ref doh_url) = self.doh_url {
+            arguments.push("--doh-url".to_string());
+            arguments.push(doh_url.clone());
+        }
         match self.http_version {
             RequestedHttpVersion::Default => {}
             RequestedHttpVersion::Http10 => arguments.push("--http1.0".to_string()),
@@ -173,6 +202,14 @@ impl ClientOptions {
         if self.netrc {
             arguments.push("--netrc".to_string());
         }
+        if self.ntlm {
+            arguments.push("--ntlm".to_string());
+        }
+        // The OAuth2 access token is a secret, and is exported as a placeholder.
+        if self.oauth2_bearer.is_some() {
+            arguments.push("--oauth2-bearer".to_string());
+            arguments.push("'{{oauth2_token}}'".to_string());
+        }
         if self.path_as_is {
             arguments.push("--path-as-is".to_string());
         }
@@ -222,6 +259,8 @@ mod tests {
                 connect_timeout: Duration::from_secs(20),
                 connects_to: vec!["example.com:443:host-47.example.com:443".to_string()],
                 cookie_input_file: Some("cookie_file".to_string()),
+                digest: false,
+                doh_url: Some("https://dns.example.com/dns-query".to_string()),
                 follow_location: true,
                 follow_location_trusted: false,
                 http_version: RequestedHttpVersion::Http10,
@@ -235,11 +274,15 @@ mod tests {
                 path_as_is: true,
                 proxy: Some("localhost:3128".to_string()),
                 no_proxy: None,
+                ntlm: false,
+                oauth2_bearer: None,
                 resolves: vec![
                     "foo.com:80:192.168.0.1".to_string(),
                     "bar.com:443:127.0.0.1".to_string(),
                 ],
                 ssl_no_revoke: false,
+                stream_count: Count::Infinite,
+                stream_timeout: None,
                 timeout: Duration::from_secs(10),
                 unix_socket: Some("/var/run/example.sock".to_string()),
                 user: Some("user:password".to_string()),
@@ -255,6 +298,8 @@ mod tests {
                 "example.com:443:host-47.example.com:443",
                 "--cookie",
                 "cookie_file",
+                "--doh-url",
+                "https://dns.example.com/dns-query",
                 "--http1.0",
                 "--insecure",
                 "--ipv6",
@@ -283,4 +328,48 @@ mod tests {
             .map(|a| a.to_string())
         );
     }
+
+    #[test]
+    fn test_curl_args_client_cert() {
+        let options = ClientOptions {
+            client_cert_file: Some("client.pem:secret".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(options.curl_args(), ["--cert", "client.pem:secret"]);
+
+        let options = ClientOptions {
+            client_cert_file: Some("client.p12:secret".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.curl_args(),
+            ["--cert", "client.p12:secret", "--cert-type", "P12"]
+        );
+    }
+
+    #[test]
+    fn test_curl_args_auth() {
+        let options = ClientOptions {
+            digest: true,
+            user: Some("bob:secret".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(options.curl_args(), ["--digest", "--user", "'bob:secret'"]);
+
+        let options = ClientOptions {
+            ntlm: true,
+            user: Some("bob:secret".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(options.curl_args(), ["--ntlm", "--user", "'bob:secret'"]);
+
+        let options = ClientOptions {
+            oauth2_bearer: Some("s3cr3t".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.curl_args(),
+            ["--oauth2-bearer", "'{{oauth2_token}}'"]
+        );
+    }
 }
@@ -15,11 +15,14 @@
  * limitations under the License.
  *
  */
+use std::cell::OnceCell;
 use std::fmt;
 use std::time::Duration;
 
 use crate::http::certificate::Certificate;
-use crate::http::{HeaderVec, Url};
+use crate::http::sse::Event;
+use crate::http::websocket::Frame;
+use crate::http::{HeaderVec, HttpError, Url};
 
 /// Represents a runtime HTTP response.
 /// This is a real response, that has been executed by our HTTP client.
@@ -33,8 +36,29 @@ pub struct Response {
     pub url: Url,
     /// The end-user certificate, in the response certificate chain
     pub certificate: Option<Certificate>,
+    /// The frames received, if the request has opened a WebSocket
+    pub frames: Vec<Frame>,
+    /// The events received, if the response is a `text/event-stream`
+    pub events: Vec<Event>,
+    /// The decompressed body, computed on first access (see [`Response::uncompress_body`]).
+    pub(crate) decoded_body: DecodedBody,
 }
 
+/// The decompressed body of a response, computed once and shared by every query, assert, capture
+/// and output of the response.
+///
+/// This is a cache: two responses are equal regardless of their decoded bodies.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DecodedBody(pub(crate) OnceCell<Result<Vec<u8>, HttpError>>);
+
+impl PartialEq for DecodedBody {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for DecodedBody {}
+
 impl Response {
     /// Creates a new HTTP response
     pub fn new(
@@ -54,6 +78,9 @@ impl Response {
             duration,
             url,
             certificate,
+            frames: vec![],
+            events: vec![],
+            decoded_body: Default::default(),
         }
     }
 }
@@ -97,6 +124,9 @@ mod tests {
             duration: Default::default(),
             url: "http://localhost".parse().unwrap(),
             certificate: None,
+            frames: vec![],
+            events: vec![],
+            decoded_body: Default::default(),
         };
         assert_eq!(response.headers.values("Content-Length"), vec!["12"]);
         assert!(response.headers.values("Unknown").is_empty());
@@ -21,6 +21,9 @@
 /// using the Content-Encoding response header
 ///
 /// See https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Encoding
+use std::error::Error;
+use std::fmt;
+use std::io;
 use std::io::prelude::*;
 
 use encoding::DecoderTrap;
@@ -37,6 +40,8 @@ pub enum ContentEncoding {
     Deflate,
     /// No encoding.
     Identity,
+    /// A format using the Zstandard algorithm (defined in RFC 8878).
+    Zstd,
 }
 
 impl ContentEncoding {
@@ -47,6 +52,7 @@ impl ContentEncoding {
             "gzip" => Ok(ContentEncoding::Gzip),
             "deflate" => Ok(ContentEncoding::Deflate),
             "identity" => Ok(ContentEncoding::Identity),
+            "zstd" => Ok(ContentEncoding::Zstd),
             v => Err(HttpError::UnsupportedContentEncoding {
                 description: v.to_string(),
             }),
@@ -55,15 +61,65 @@ impl ContentEncoding {
 
     /// Decompresses `data` bytes.
     pub fn decode(&self, data: &[u8]) -> Result<Vec<u8>, HttpError> {
-        match self {
-            ContentEncoding::Identity => Ok(data.to_vec()),
-            ContentEncoding::Gzip => uncompress_gzip(data),
-            ContentEncoding::Deflate => uncompress_zlib(data),
-            ContentEncoding::Brotli => uncompress_brotli(data),
+        let mut buf = Vec::new();
+        match self.reader(data)?.read_to_end(&mut buf) {
+            Ok(_) => Ok(buf),
+            Err(_) => Err(self.uncompress_error()),
+        }
+    }
+
+    /// Returns a streaming decoder of `data` for this encoding.
+    fn reader<'a, R: Read + 'a>(&self, data: R) -> Result<Box<dyn Read + 'a>, HttpError> {
+        let reader: Box<dyn Read + 'a> = match self {
+            ContentEncoding::Identity => Box::new(data),
+            ContentEncoding::Gzip => match libflate::gzip::Decoder::new(data) {
+                Ok(decoder) => Box::new(decoder),
+                Err(_) => return Err(self.uncompress_error()),
+            },
+            ContentEncoding::Deflate => match libflate::zlib::Decoder::new(data) {
+                Ok(decoder) => Box::new(decoder),
+                Err(_) => return Err(self.uncompress_error()),
+            },
+            ContentEncoding::Brotli => {
+                let buffer_size = 4096;
+                Box::new(brotli::Decompressor::new(data, buffer_size))
+            }
+            ContentEncoding::Zstd => match zstd::stream::read::Decoder::new(data) {
+                Ok(decoder) => Box::new(decoder),
+                Err(_) => return Err(self.uncompress_error()),
+            },
+        };
+        Ok(reader)
+    }
+
+    /// Returns the error raised when `data` can not be decompressed with this encoding.
+    fn uncompress_error(&self) -> HttpError {
+        let description = match self {
+            ContentEncoding::Brotli => "brotli",
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Deflate => "zlib",
+            ContentEncoding::Identity => "identity",
+            ContentEncoding::Zstd => "zstd",
+        };
+        HttpError::CouldNotUncompressResponse {
+            description: description.to_string(),
         }
     }
 }
 
+impl fmt::Display for ContentEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            ContentEncoding::Brotli => "br",
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Deflate => "deflate",
+            ContentEncoding::Identity => "identity",
+            ContentEncoding::Zstd => "zstd",
+        };
+        write!(f, "{value}")
+    }
+}
+
 impl Response {
     /// Returns response body as text.
     pub fn text(&self) -> Result<String, HttpError> {
@@ -98,67 +154,124 @@ impl Response {
     }
 
     /// Decompresses HTTP body response.
+    ///
+    /// Content encodings are listed in the order in which they have been applied, so we decode
+    /// them in reverse order (see <https://www.rfc-editor.org/rfc/rfc9110.html#name-content-encoding>).
+    ///
+    /// The body is decompressed once: the result of the first call to this method, or to
+    /// [`Response::uncompress_body_with_max_size`], is reused by the following calls.
     pub fn uncompress_body(&self) -> Result<Vec<u8>, HttpError> {
+        self.decoded_body
+            .0
+            .get_or_init(|| self.decode_body(u64::MAX))
+            .clone()
+    }
+
+    /// Decompresses HTTP body response, failing if it is larger than `max_size` bytes.
+    ///
+    /// Decompression layers are streamed and decoding stops after `max_size` bytes, so an oversized
+    /// body is never fully decoded in memory. The result is reused by the following calls to
+    /// [`Response::uncompress_body`]: calling this method first caps the decompressed body for
+    /// every user of the response.
+    pub fn uncompress_body_with_max_size(&self, max_size: u64) -> Result<Vec<u8>, HttpError> {
+        let too_large = HttpError::UncompressedBodyTooLarge { max_size };
+        let data = self.decoded_body.0.get_or_init(|| {
+            // We read one more byte than the limit to detect an oversized body.
+            match self.decode_body(max_size.saturating_add(1)) {
+                Ok(data) if data.len() as u64 > max_size => Err(too_large.clone()),
+                result => result,
+            }
+        });
+        match data {
+            Ok(data) if data.len() as u64 > max_size => Err(too_large),
+            data => data.clone(),
+        }
+    }
+
+    /// Decompresses HTTP body response, reading at most `limit` decompressed bytes.
+    ///
+    /// Each decompression layer reads the output of the previous one as a stream, without
+    /// buffering the intermediate layers.
+    fn decode_body(&self, limit: u64) -> Result<Vec<u8>, HttpError> {
         let encodings = self.headers.content_encoding()?;
-        let mut data = self.body.clone();
-        for encoding in &encodings {
-            data = encoding.decode(&data)?;
+        let mut reader: Box<dyn Read + '_> = Box::new(self.body.as_slice());
+        for (index, encoding) in encodings.iter().enumerate().rev() {
+            let inner = encoding
+                .reader(reader)
+                .map_err(|e| layer_error(&encodings, index, e))?;
+            reader = Box::new(LayerReader { inner, index });
+        }
+        let mut data = Vec::new();
+        match reader.take(limit).read_to_end(&mut data) {
+            Ok(_) => Ok(data),
+            Err(e) => {
+                let index = layer_index(&e).unwrap_or_default();
+                let error = encodings[index].uncompress_error();
+                Err(layer_error(&encodings, index, error))
+            }
         }
-        Ok(data)
     }
 }
 
-/// Decompresses Brotli compressed `data`.
-fn uncompress_brotli(data: &[u8]) -> Result<Vec<u8>, HttpError> {
-    let buffer_size = 4096;
-    let mut reader = brotli::Decompressor::new(data, buffer_size);
-    let mut buf = Vec::new();
-    match reader.read_to_end(&mut buf) {
-        Ok(_) => Ok(buf),
-        Err(_) => Err(HttpError::CouldNotUncompressResponse {
-            description: "brotli".to_string(),
-        }),
+/// Returns a decompression `error` of the layer `index`, given all the content `encodings` of a
+/// response. The failed layer is described when there are multiple layers.
+fn layer_error(encodings: &[ContentEncoding], index: usize, error: HttpError) -> HttpError {
+    match error {
+        HttpError::CouldNotUncompressResponse { description } if encodings.len() > 1 => {
+            let layers = encodings
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let description = format!(
+                "{description} (layer {} of {}, Content-Encoding: {layers})",
+                index + 1,
+                encodings.len()
+            );
+            HttpError::CouldNotUncompressResponse { description }
+        }
+        error => error,
     }
 }
 
-/// Decompresses GZip compressed `data`.
-fn uncompress_gzip(data: &[u8]) -> Result<Vec<u8>, HttpError> {
-    let mut decoder = match libflate::gzip::Decoder::new(data) {
-        Ok(v) => v,
-        Err(_) => {
-            return Err(HttpError::CouldNotUncompressResponse {
-                description: "gzip".to_string(),
-            })
-        }
-    };
-    let mut buf = Vec::new();
-    match decoder.read_to_end(&mut buf) {
-        Ok(_) => Ok(buf),
-        Err(_) => Err(HttpError::CouldNotUncompressResponse {
-            description: "gzip".to_string(),
-        }),
+/// A decompression layer, whose read errors are tagged with the layer index.
+struct LayerReader<'a> {
+    inner: Box<dyn Read + 'a>,
+    index: usize,
+}
+
+impl Read for LayerReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf).map_err(|e| {
+            // An error of an inner layer is kept as it is.
+            if layer_index(&e).is_some() {
+                e
+            } else {
+                io::Error::new(e.kind(), LayerError(self.index))
+            }
+        })
     }
 }
 
-/// Decompresses Zlib compressed `data`.
-fn uncompress_zlib(data: &[u8]) -> Result<Vec<u8>, HttpError> {
-    let mut decoder = match libflate::zlib::Decoder::new(data) {
-        Ok(v) => v,
-        Err(_) => {
-            return Err(HttpError::CouldNotUncompressResponse {
-                description: "zlib".to_string(),
-            })
-        }
-    };
-    let mut buf = Vec::new();
-    match decoder.read_to_end(&mut buf) {
-        Ok(_) => Ok(buf),
-        Err(_) => Err(HttpError::CouldNotUncompressResponse {
-            description: "zlib".to_string(),
-        }),
+/// The error of the decompression layer with this index.
+#[derive(Debug)]
+struct LayerError(usize);
+
+impl fmt::Display for LayerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "decompression error in layer {}", self.0 + 1)
     }
 }
 
+impl Error for LayerError {}
+
+/// Returns the index of the decompression layer that has raised the error `e`.
+fn layer_index(e: &io::Error) -> Option<usize> {
+    e.get_ref()
+        .and_then(|e| e.downcast_ref::<LayerError>())
+        .map(|e| e.0)
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -173,6 +286,9 @@ pub mod tests {
             duration: Default::default(),
             url: "http://localhost".parse().unwrap(),
             certificate: None,
+            frames: vec![],
+            events: vec![],
+            decoded_body: Default::default(),
         }
     }
 
@@ -182,6 +298,10 @@ pub mod tests {
             ContentEncoding::parse("br").unwrap(),
             ContentEncoding::Brotli
         );
+        assert_eq!(
+            ContentEncoding::parse("zstd").unwrap(),
+            ContentEncoding::Zstd
+        );
         assert_eq!(
             ContentEncoding::parse("xx").err().unwrap(),
             HttpError::UnsupportedContentEncoding {
@@ -270,13 +390,128 @@ pub mod tests {
         assert_eq!(response.uncompress_body().unwrap(), b"Hello World!");
     }
 
+    #[test]
+    fn test_uncompress_body_multiple_layers() {
+        // "Hello World!" compressed with deflate, then with gzip.
+        let body = vec![
+            0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0xab, 0x98, 0xf3, 0xd9,
+            0xe3, 0xec, 0xc9, 0x93, 0xe1, 0x1c, 0xe7, 0xf5, 0x4f, 0x79, 0x06, 0xb2, 0x30, 0xc8,
+            0x78, 0xb2, 0xd8, 0x01, 0x00, 0xa8, 0xf3, 0xad, 0xdc, 0x14, 0x00, 0x00, 0x00,
+        ];
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Encoding", "deflate, gzip"));
+        let response = Response {
+            headers,
+            body: body.clone(),
+            ..default_response()
+        };
+        assert_eq!(response.uncompress_body().unwrap(), b"Hello World!");
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Encoding", "deflate"));
+        headers.push(Header::new("Content-Encoding", "gzip"));
+        let response = Response {
+            headers,
+            body: body.clone(),
+            ..default_response()
+        };
+        assert_eq!(response.uncompress_body().unwrap(), b"Hello World!");
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Encoding", "br, gzip"));
+        let response = Response {
+            headers,
+            body,
+            ..default_response()
+        };
+        assert_eq!(
+            response.uncompress_body().err().unwrap(),
+            HttpError::CouldNotUncompressResponse {
+                description: "brotli (layer 1 of 2, Content-Encoding: br, gzip)".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_uncompress_body_with_max_size() {
+        assert!(brotli_hello_response()
+            .uncompress_body_with_max_size(12)
+            .is_ok());
+        assert_eq!(
+            brotli_hello_response()
+                .uncompress_body_with_max_size(11)
+                .err()
+                .unwrap(),
+            HttpError::UncompressedBodyTooLarge { max_size: 11 }
+        );
+        assert_eq!(
+            brotli_hello_response()
+                .uncompress_body_with_max_size(u64::MAX)
+                .unwrap(),
+            b"Hello World!"
+        );
+
+        assert_eq!(
+            hello_response().uncompress_body_with_max_size(12).unwrap(),
+            b"Hello World!"
+        );
+        assert_eq!(
+            hello_response()
+                .uncompress_body_with_max_size(0)
+                .err()
+                .unwrap(),
+            HttpError::UncompressedBodyTooLarge { max_size: 0 }
+        );
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Encoding", "gzip"));
+        let response = Response {
+            headers,
+            body: b"Hello World!".to_vec(),
+            ..default_response()
+        };
+        assert_eq!(
+            response.uncompress_body_with_max_size(100).err().unwrap(),
+            HttpError::CouldNotUncompressResponse {
+                description: "gzip".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_uncompress_body_is_cached() {
+        // Once capped, the body stays capped for every user of the response.
+        let response = brotli_hello_response();
+        assert!(response.uncompress_body_with_max_size(11).is_err());
+        assert_eq!(
+            response.uncompress_body().err().unwrap(),
+            HttpError::UncompressedBodyTooLarge { max_size: 11 }
+        );
+
+        let response = brotli_hello_response();
+        assert_eq!(
+            response.uncompress_body_with_max_size(12).unwrap(),
+            b"Hello World!"
+        );
+        assert_eq!(response.uncompress_body().unwrap(), b"Hello World!");
+        assert!(response.decoded_body.0.get().is_some());
+        assert_eq!(
+            response.uncompress_body_with_max_size(5).err().unwrap(),
+            HttpError::UncompressedBodyTooLarge { max_size: 5 }
+        );
+    }
+
     #[test]
     fn test_uncompress_brotli() {
         let data = [
             0x21, 0x2c, 0x00, 0x04, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x57, 0x6f, 0x72, 0x6c,
             0x64, 0x21, 0x03,
         ];
-        assert_eq!(uncompress_brotli(&data[..]).unwrap(), b"Hello World!");
+        assert_eq!(
+            ContentEncoding::Brotli.decode(&data[..]).unwrap(),
+            b"Hello World!"
+        );
     }
 
     #[test]
@@ -286,7 +521,10 @@ pub mod tests {
             0x2e, 0x74, 0x78, 0x74, 0x00, 0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0x08, 0xcf, 0x2f,
             0xca, 0x49, 0x51, 0x04, 0x00, 0xa3, 0x1c, 0x29, 0x1c, 0x0c, 0x00, 0x00, 0x00,
         ];
-        assert_eq!(uncompress_gzip(&data[..]).unwrap(), b"Hello World!");
+        assert_eq!(
+            ContentEncoding::Gzip.decode(&data[..]).unwrap(),
+            b"Hello World!"
+        );
     }
 
     #[test]
@@ -295,24 +533,45 @@ pub mod tests {
             0x78, 0x9c, 0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0x08, 0xcf, 0x2f, 0xca, 0x49, 0x51,
             0x04, 0x00, 0x1c, 0x49, 0x04, 0x3e,
         ];
-        assert_eq!(uncompress_zlib(&data[..]).unwrap(), b"Hello World!");
+        assert_eq!(
+            ContentEncoding::Deflate.decode(&data[..]).unwrap(),
+            b"Hello World!"
+        );
+    }
+
+    #[test]
+    fn test_uncompress_zstd() {
+        let data = [
+            0x28, 0xb5, 0x2f, 0xfd, 0x00, 0x58, 0x61, 0x00, 0x00, 0x48, 0x65, 0x6c, 0x6c, 0x6f,
+            0x20, 0x57, 0x6f, 0x72, 0x6c, 0x64, 0x21,
+        ];
+        assert_eq!(
+            ContentEncoding::Zstd.decode(&data[..]).unwrap(),
+            b"Hello World!"
+        );
     }
 
     #[test]
     fn test_uncompress_error() {
         let data = [0x21];
         assert_eq!(
-            uncompress_brotli(&data[..]).err().unwrap(),
+            ContentEncoding::Brotli.decode(&data[..]).err().unwrap(),
             HttpError::CouldNotUncompressResponse {
                 description: "brotli".to_string()
             }
         );
         assert_eq!(
-            uncompress_gzip(&data[..]).err().unwrap(),
+            ContentEncoding::Gzip.decode(&data[..]).err().unwrap(),
             HttpError::CouldNotUncompressResponse {
                 description: "gzip".to_string()
             }
         );
+        assert_eq!(
+            ContentEncoding::Zstd.decode(&data[..]).err().unwrap(),
+            HttpError::CouldNotUncompressResponse {
+                description: "zstd".to_string()
+            }
+        );
     }
 
     fn hello_response() -> Response {
@@ -322,6 +581,19 @@ pub mod tests {
         }
     }
 
+    fn brotli_hello_response() -> Response {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Encoding", "br"));
+        Response {
+            headers,
+            body: vec![
+                0x21, 0x2c, 0x00, 0x04, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x57, 0x6f, 0x72, 0x6c,
+                0x64, 0x21, 0x03,
+            ],
+            ..default_response()
+        }
+    }
+
     fn utf8_encoding_response() -> Response {
         let mut headers = HeaderVec::new();
         headers.push(Header::new("Content-Type", "text/plain; charset=utf-8"));
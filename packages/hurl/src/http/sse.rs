@@ -0,0 +1,139 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Server-sent events (see <https://html.spec.whatwg.org/multipage/server-sent-events.html>).
+
+/// An event received from a `text/event-stream` response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    /// The event type, `message` by default.
+    pub event: String,
+    /// The event data, with multiple `data` fields joined by a newline.
+    pub data: String,
+    /// The last event ID set by the stream, if any.
+    pub id: Option<String>,
+}
+
+/// Parses the events of a `text/event-stream` body, as its bytes are received.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EventParser {
+    /// The bytes of the current line, not yet terminated.
+    line: Vec<u8>,
+    event: Option<String>,
+    data: Vec<String>,
+    id: Option<String>,
+}
+
+impl EventParser {
+    /// Returns a new parser.
+    pub fn new() -> Self {
+        EventParser::default()
+    }
+
+    /// Parses the bytes `data` and returns the events completed by these bytes.
+    pub fn parse(&mut self, data: &[u8]) -> Vec<Event> {
+        let mut events = vec![];
+        for byte in data {
+            if *byte != b'\n' {
+                self.line.push(*byte);
+                continue;
+            }
+            let mut line = std::mem::take(&mut self.line);
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            let line = String::from_utf8_lossy(&line);
+            if let Some(event) = self.parse_line(&line) {
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    /// Parses a `line` and returns an event if this line ends it.
+    fn parse_line(&mut self, line: &str) -> Option<Event> {
+        if line.is_empty() {
+            let event = self.event.take();
+            if self.data.is_empty() {
+                return None;
+            }
+            let data = std::mem::take(&mut self.data).join("\n");
+            return Some(Event {
+                event: event.unwrap_or_else(|| "message".to_string()),
+                data,
+                id: self.id.clone(),
+            });
+        }
+        // Lines starting with a colon are comments.
+        if line.starts_with(':') {
+            return None;
+        }
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => self.data.push(value.to_string()),
+            "id" if !value.contains('\0') => self.id = Some(value.to_string()),
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut parser = EventParser::new();
+        assert_eq!(
+            parser.parse(b": a comment\ndata: Hello\n\n"),
+            vec![Event {
+                event: "message".to_string(),
+                data: "Hello".to_string(),
+                id: None,
+            }]
+        );
+
+        // An event can be received in several chunks.
+        assert_eq!(
+            parser.parse(b"event: update\r\nid: 1\r\ndata: {\"price\": 10}\r\nda"),
+            vec![]
+        );
+        assert_eq!(
+            parser.parse(b"ta\n\ndata:first\ndata:second\n\n"),
+            vec![
+                Event {
+                    event: "update".to_string(),
+                    data: "{\"price\": 10}\n".to_string(),
+                    id: Some("1".to_string()),
+                },
+                Event {
+                    event: "message".to_string(),
+                    data: "first\nsecond".to_string(),
+                    id: Some("1".to_string()),
+                }
+            ]
+        );
+
+        // Events without data are not dispatched.
+        assert_eq!(parser.parse(b"event: ping\n\n"), vec![]);
+    }
+}
@@ -32,6 +32,9 @@ fn default_response() -> Response {
         duration: Default::default(),
         url: Url::from_str("http://localhost").unwrap(),
         certificate: None,
+        frames: vec![],
+        events: vec![],
+        decoded_body: Default::default(),
     }
 }
 
@@ -69,7 +69,7 @@ impl FromStr for Url {
             Err(e) => return Err(HttpError::InvalidUrl(value.to_string(), e.to_string())),
         };
         let scheme = inner.scheme();
-        if scheme != "http" && scheme != "https" {
+        if !matches!(scheme, "http" | "https" | "ws" | "wss") {
             return Err(HttpError::InvalidUrl(
                 value.to_string(),
                 "Missing protocol http or https".to_string(),
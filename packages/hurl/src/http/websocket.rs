@@ -0,0 +1,194 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! WebSocket framing (see <https://datatracker.ietf.org/doc/html/rfc6455>).
+//!
+//! libcurl only opens the connection (TCP and TLS): the opening handshake and the frames are
+//! written and read "by hand" on this connection.
+use base64::engine::general_purpose;
+use base64::Engine;
+use sha1::{Digest, Sha1};
+use uuid::Uuid;
+
+/// A data frame sent or received over a WebSocket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Frame {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+pub(crate) const OPCODE_CONTINUATION: u8 = 0x0;
+pub(crate) const OPCODE_TEXT: u8 = 0x1;
+pub(crate) const OPCODE_BINARY: u8 = 0x2;
+pub(crate) const OPCODE_CLOSE: u8 = 0x8;
+pub(crate) const OPCODE_PING: u8 = 0x9;
+pub(crate) const OPCODE_PONG: u8 = 0xa;
+
+/// GUID appended to the client key to compute the accept key of the server.
+const GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// A frame, as written on the wire.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RawFrame {
+    pub fin: bool,
+    pub opcode: u8,
+    pub payload: Vec<u8>,
+}
+
+/// Returns a new random `Sec-WebSocket-Key` header value.
+pub(crate) fn new_key() -> String {
+    general_purpose::STANDARD.encode(Uuid::new_v4().as_bytes())
+}
+
+/// Returns the `Sec-WebSocket-Accept` header value that a server must reply for a client `key`.
+pub(crate) fn accept_key(key: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(key.as_bytes());
+    hasher.update(GUID.as_bytes());
+    general_purpose::STANDARD.encode(hasher.finalize())
+}
+
+/// Encodes a final client frame with `opcode` and `payload`.
+///
+/// Frames sent by a client are always masked, with a random masking key.
+pub(crate) fn encode(opcode: u8, payload: &[u8]) -> Vec<u8> {
+    let mask = Uuid::new_v4().as_bytes()[..4].to_vec();
+    encode_with_mask(opcode, payload, &mask)
+}
+
+fn encode_with_mask(opcode: u8, payload: &[u8], mask: &[u8]) -> Vec<u8> {
+    let mut data = vec![0x80 | opcode];
+    let len = payload.len();
+    if len < 126 {
+        data.push(0x80 | len as u8);
+    } else if len <= u16::MAX as usize {
+        data.push(0x80 | 126);
+        data.extend((len as u16).to_be_bytes());
+    } else {
+        data.push(0x80 | 127);
+        data.extend((len as u64).to_be_bytes());
+    }
+    data.extend(mask);
+    data.extend(
+        payload
+            .iter()
+            .enumerate()
+            .map(|(i, byte)| byte ^ mask[i % 4]),
+    );
+    data
+}
+
+/// Decodes a frame at the start of `data`.
+///
+/// Returns the frame and the number of bytes read, or `None` if `data` doesn't hold a whole frame
+/// yet.
+pub(crate) fn decode(data: &[u8]) -> Option<(RawFrame, usize)> {
+    if data.len() < 2 {
+        return None;
+    }
+    let fin = data[0] & 0x80 != 0;
+    let opcode = data[0] & 0x0f;
+    let masked = data[1] & 0x80 != 0;
+    let (len, mut pos) = match data[1] & 0x7f {
+        126 => {
+            let bytes = data.get(2..4)?;
+            (u16::from_be_bytes([bytes[0], bytes[1]]) as usize, 4)
+        }
+        127 => {
+            let bytes = data.get(2..10)?;
+            (u64::from_be_bytes(bytes.try_into().unwrap()) as usize, 10)
+        }
+        len => (len as usize, 2),
+    };
+    let mask = if masked {
+        let mask = data.get(pos..pos + 4)?.to_vec();
+        pos += 4;
+        Some(mask)
+    } else {
+        None
+    };
+    let payload = data.get(pos..pos.checked_add(len)?)?;
+    let payload = match mask {
+        Some(mask) => payload
+            .iter()
+            .enumerate()
+            .map(|(i, byte)| byte ^ mask[i % 4])
+            .collect(),
+        None => payload.to_vec(),
+    };
+    let frame = RawFrame {
+        fin,
+        opcode,
+        payload,
+    };
+    Some((frame, pos + len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_key() {
+        // Sample of the RFC 6455.
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        let mask = [0x37, 0xfa, 0x21, 0x3d];
+        assert_eq!(
+            encode_with_mask(OPCODE_TEXT, b"Hello", &mask),
+            vec![0x81, 0x85, 0x37, 0xfa, 0x21, 0x3d, 0x7f, 0x9f, 0x4d, 0x51, 0x58]
+        );
+        let data = encode_with_mask(OPCODE_BINARY, &[0; 300], &mask);
+        assert_eq!(data[..4], [0x82, 0xfe, 0x01, 0x2c]);
+        assert_eq!(data.len(), 4 + 4 + 300);
+    }
+
+    #[test]
+    fn test_decode() {
+        let data = [0x81, 0x05, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x89];
+        assert_eq!(
+            decode(&data),
+            Some((
+                RawFrame {
+                    fin: true,
+                    opcode: OPCODE_TEXT,
+                    payload: b"Hello".to_vec()
+                },
+                7
+            ))
+        );
+        assert_eq!(decode(&data[..4]), None);
+        let data = encode(OPCODE_BINARY, &[1; 200]);
+        assert_eq!(
+            decode(&data),
+            Some((
+                RawFrame {
+                    fin: true,
+                    opcode: OPCODE_BINARY,
+                    payload: vec![1; 200]
+                },
+                data.len()
+            ))
+        );
+    }
+}
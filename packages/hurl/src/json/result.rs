@@ -21,7 +21,7 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use chrono::SecondsFormat;
-use hurl_core::ast::SourceInfo;
+use hurl_core::ast::{SourceInfo, StatusValue};
 use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
 use serde::{Deserialize, Serialize};
@@ -77,6 +77,8 @@ struct EntryResultJson {
     captures: Vec<CaptureJson>,
     asserts: Vec<AssertJson>,
     time: u64,
+    #[serde(default, skip_serializing_if = "is_false")]
+    skipped: bool,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -108,6 +110,10 @@ struct AssertJson {
     success: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<serde_json::Value>,
     line: usize,
 }
 
@@ -118,6 +124,8 @@ struct RequestJson {
     headers: Vec<HeaderJson>,
     cookies: Vec<RequestCookieJson>,
     query_string: Vec<ParamJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -248,10 +256,15 @@ impl EntryResultJson {
             captures,
             asserts,
             time: entry.transfer_duration.as_millis() as u64,
+            skipped: entry.skipped,
         })
     }
 }
 
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
 impl CookieJson {
     fn from_cookie(c: &Cookie) -> Self {
         CookieJson {
@@ -268,7 +281,7 @@ impl CookieJson {
 
 impl CallJson {
     fn from_call(call: &Call, response_dir: Option<&Path>) -> Result<Self, io::Error> {
-        let request = RequestJson::from_request(&call.request);
+        let request = RequestJson::from_request(&call.request, response_dir)?;
         let response = ResponseJson::from_response(&call.response, response_dir)?;
         let timings = TimingsJson::from_timings(&call.timings);
         Ok(CallJson {
@@ -280,7 +293,7 @@ impl CallJson {
 }
 
 impl RequestJson {
-    fn from_request(request: &Request) -> Self {
+    fn from_request(request: &Request, response_dir: Option<&Path>) -> Result<Self, io::Error> {
         let headers = request
             .headers
             .iter()
@@ -297,13 +310,24 @@ impl RequestJson {
             .iter()
             .map(ParamJson::from_param)
             .collect::<Vec<_>>();
-        RequestJson {
+        // Like responses, request bodies are only saved in the context of the JSON report.
+        let body = match response_dir {
+            Some(response_dir) if !request.body.is_empty() => {
+                let file = write_body(&request.body, "request", None, response_dir)?;
+                let parent = response_dir.components().last().unwrap();
+                let parent: &Path = parent.as_ref();
+                Some(format!("{}/{}", parent.display(), file.display()))
+            }
+            _ => None,
+        };
+        Ok(RequestJson {
             method: request.method.clone(),
             url: request.url.to_string(),
             headers,
             cookies,
             query_string,
-        }
+            body,
+        })
     }
 }
 
@@ -461,14 +485,69 @@ impl AssertJson {
                 OutputFormat::Plain,
             )
         });
+        // Actual and expected values are only serialized for failed asserts, like the message.
+        let (actual, expected) = if message.is_some() {
+            assert_values(a)
+        } else {
+            (None, None)
+        };
         AssertJson {
             success: a.error().is_none(),
             message,
+            actual,
+            expected,
             line: a.line(),
         }
     }
 }
 
+/// Returns the actual and expected values of an assert `a`, if they can be evaluated.
+///
+/// The expected value of an explicit assert depends on its predicate and is only available
+/// through the assert message.
+fn assert_values(a: &AssertResult) -> (Option<serde_json::Value>, Option<serde_json::Value>) {
+    match a {
+        AssertResult::Version {
+            actual, expected, ..
+        } => (
+            Some(serde_json::Value::String(actual.clone())),
+            Some(serde_json::Value::String(expected.clone())),
+        ),
+        AssertResult::Status {
+            actual, expected, ..
+        } => {
+            let expected = match expected {
+                StatusValue::Specific(expected) => serde_json::Value::from(*expected),
+                _ => serde_json::Value::String(expected.to_string()),
+            };
+            (Some(serde_json::Value::from(*actual)), Some(expected))
+        }
+        AssertResult::Header {
+            actual, expected, ..
+        } => (
+            actual
+                .as_ref()
+                .ok()
+                .map(|v| serde_json::Value::String(v.clone())),
+            Some(serde_json::Value::String(expected.clone())),
+        ),
+        AssertResult::Body {
+            actual, expected, ..
+        } => (
+            actual.as_ref().ok().map(|v| v.to_json()),
+            expected.as_ref().ok().map(|v| v.to_json()),
+        ),
+        AssertResult::Explicit { actual, .. } => (
+            actual
+                .as_ref()
+                .ok()
+                .and_then(|v| v.as_ref())
+                .map(|v| v.to_json()),
+            None,
+        ),
+    }
+}
+
 /// Write the HTTP `response` body to directory `dir`.
 fn write_response(response: &Response, dir: &Path) -> Result<PathBuf, io::Error> {
     let extension = if response.is_json() {
@@ -480,8 +559,18 @@ fn write_response(response: &Response, dir: &Path) -> Result<PathBuf, io::Error>
     } else {
         None
     };
+    write_body(&response.body, "response", extension, dir)
+}
+
+/// Write a HTTP `body` to directory `dir`, in a uniquely named file with suffix `kind`.
+fn write_body(
+    body: &[u8],
+    kind: &str,
+    extension: Option<&str>,
+    dir: &Path,
+) -> Result<PathBuf, io::Error> {
     let id = Uuid::new_v4();
-    let relative_path = format!("{id}_response");
+    let relative_path = format!("{id}_{kind}");
     let relative_path = Path::new(&relative_path);
     let relative_path = match extension {
         Some(ext) => relative_path.with_extension(ext),
@@ -489,6 +578,6 @@ fn write_response(response: &Response, dir: &Path) -> Result<PathBuf, io::Error>
     };
     let path = dir.join(relative_path.clone());
     let mut file = File::create(path)?;
-    file.write_all(&response.body)?;
+    file.write_all(body)?;
     Ok(relative_path)
 }
@@ -38,6 +38,7 @@
 //! The main function of this crate is [`runner::run`].
 //!
 //! This crate works on Windows, macOS and Linux.
+mod grpc;
 mod html;
 pub mod http;
 mod json;
@@ -23,9 +23,11 @@ use std::path::Path;
 use std::time::Instant;
 use std::{env, process, thread};
 
-use hurl::report::{html, json, junit, tap};
+use hurl::http::HttpError;
+use hurl::report::{har, html, json, junit, tap};
 use hurl::runner;
-use hurl::runner::HurlResult;
+use hurl::runner::{HurlResult, RunnerErrorKind};
+use hurl::util::redacted::Redactor;
 use hurl_core::input::Input;
 use hurl_core::text;
 
@@ -37,6 +39,7 @@ const EXIT_ERROR_COMMANDLINE: i32 = 1;
 const EXIT_ERROR_PARSING: i32 = 2;
 const EXIT_ERROR_RUNTIME: i32 = 3;
 const EXIT_ERROR_ASSERT: i32 = 4;
+const EXIT_ERROR_TIMEOUT: i32 = 5;
 const EXIT_ERROR_UNDEFINED: i32 = 127;
 
 /// Structure that stores the result of an Hurl file execution, and the content of the file.
@@ -46,9 +49,21 @@ struct HurlRun {
     content: String,
     /// Content's source file
     filename: Input,
+    /// Row of the data set used for this run (starting at 1), if any
+    row: Option<usize>,
     hurl_result: HurlResult,
 }
 
+impl HurlRun {
+    /// Returns the name of this run in reports: its filename, with the data set row if any.
+    fn report_name(&self) -> Input {
+        match self.row {
+            Some(row) => Input::new(&format!("{} (row {row})", self.filename)),
+            None => self.filename.clone(),
+        }
+    }
+}
+
 /// Executes Hurl entry point.
 fn main() {
     text::init_crate_colored();
@@ -110,7 +125,7 @@ fn main() {
         base_logger.info(summary.as_str());
     }
 
-    process::exit(exit_code(&runs));
+    process::exit(exit_code(&runs, opts.warn_asserts));
 }
 
 /// Unwraps a `result` or exit with message.
@@ -132,57 +147,105 @@ fn exit_with_error(message: &str, code: i32, logger: &BaseLogger) -> ! {
     process::exit(code);
 }
 
-/// Writes `runs` results on file, in HTML, TAP, JUnit or Cookie file format.
+/// Writes `runs` results on file, in HTML, HAR, TAP, JUnit, Cookie file or curl commands format.
 fn export_results(
     runs: &[HurlRun],
     opts: &CliOptions,
     logger: &BaseLogger,
 ) -> Result<(), CliError> {
+    let mut secrets = opts.secret_values();
+    // Values captured with `redact` during the runs are also redacted from the reports.
+    for run in runs {
+        for secret in &run.hurl_result.secrets {
+            if !secrets.contains(secret) {
+                secrets.push(secret.clone());
+            }
+        }
+    }
     if let Some(file) = &opts.junit_file {
         logger.debug(&format!("Writing JUnit report to {}", file.display()));
-        create_junit_report(runs, file)?;
+        create_junit_report(runs, file, &secrets)?;
     }
     if let Some(file) = &opts.tap_file {
         logger.debug(&format!("Writing TAP report to {}", file.display()));
-        create_tap_report(runs, file)?;
+        create_tap_report(runs, file, &secrets)?;
+    }
+    if let Some(file) = &opts.har_file {
+        logger.debug(&format!("Writing HAR report to {}", file.display()));
+        create_har_report(runs, file, &secrets)?;
     }
     if let Some(dir) = &opts.html_dir {
         logger.debug(&format!("Writing HTML report to {}", dir.display()));
-        create_html_report(runs, dir)?;
+        create_html_report(runs, dir, &secrets)?;
     }
     if let Some(dir) = &opts.json_report_dir {
         logger.debug(&format!("Writing JSON report to {}", dir.display()));
-        create_json_report(runs, dir)?;
+        create_json_report(runs, dir, &secrets)?;
     }
     if let Some(file) = &opts.cookie_output_file {
         logger.debug(&format!("Writing cookies to {}", file.display()));
         create_cookies_file(runs, file)?;
     }
+    if let Some(file) = &opts.curl_file {
+        logger.debug(&format!("Writing curl commands to {}", file.display()));
+        create_curl_export(runs, file, &secrets)?;
+    }
     Ok(())
 }
 
 /// Create a JUnit report for this run.
-fn create_junit_report(runs: &[HurlRun], filename: &Path) -> Result<(), CliError> {
+fn create_junit_report(
+    runs: &[HurlRun],
+    filename: &Path,
+    secrets: &[String],
+) -> Result<(), CliError> {
     let testcases = runs
         .iter()
-        .map(|r| junit::Testcase::from(&r.hurl_result, &r.content, &r.filename))
+        .flat_map(|r| {
+            let testcase = junit::Testcase::from(&r.hurl_result, &r.content, &r.report_name());
+            let skipped = junit::Testcase::skipped_entries(&r.hurl_result, &r.report_name());
+            std::iter::once(testcase).chain(skipped)
+        })
         .collect::<Vec<_>>();
-    junit::write_report(filename, &testcases)?;
+    junit::write_report(filename, &testcases, secrets)?;
     Ok(())
 }
 
 /// Create a TAP report for this run.
-fn create_tap_report(runs: &[HurlRun], filename: &Path) -> Result<(), CliError> {
+fn create_tap_report(
+    runs: &[HurlRun],
+    filename: &Path,
+    secrets: &[String],
+) -> Result<(), CliError> {
     let testcases = runs
         .iter()
-        .map(|r| tap::Testcase::from(&r.hurl_result, &r.filename))
+        .flat_map(|r| {
+            let testcase = tap::Testcase::from(&r.hurl_result, &r.content, &r.report_name());
+            let skipped = tap::Testcase::skipped_entries(&r.hurl_result, &r.report_name());
+            std::iter::once(testcase).chain(skipped)
+        })
         .collect::<Vec<_>>();
-    tap::write_report(filename, &testcases)?;
+    tap::write_report(filename, &testcases, secrets)?;
+    Ok(())
+}
+
+/// Create a HAR report for this run.
+fn create_har_report(
+    runs: &[HurlRun],
+    filename: &Path,
+    secrets: &[String],
+) -> Result<(), CliError> {
+    let results = runs.iter().map(|r| &r.hurl_result).collect::<Vec<_>>();
+    har::write_report(filename, &results, secrets)?;
     Ok(())
 }
 
 /// Create an HTML report for this run.
-fn create_html_report(runs: &[HurlRun], dir_path: &Path) -> Result<(), CliError> {
+fn create_html_report(
+    runs: &[HurlRun],
+    dir_path: &Path,
+    secrets: &[String],
+) -> Result<(), CliError> {
     // We ensure that the containing folder exists.
     let store_path = dir_path.join("store");
     std::fs::create_dir_all(&store_path)?;
@@ -190,8 +253,8 @@ fn create_html_report(runs: &[HurlRun], dir_path: &Path) -> Result<(), CliError>
     let mut testcases = vec![];
     for run in runs.iter() {
         let result = &run.hurl_result;
-        let testcase = html::Testcase::from(result, &run.filename);
-        testcase.write_html(&run.content, &result.entries, &store_path)?;
+        let testcase = html::Testcase::from(result, &run.report_name());
+        testcase.write_html(&run.content, &result.entries, &store_path, secrets)?;
         testcases.push(testcase);
     }
     html::write_report(dir_path, &testcases)?;
@@ -199,37 +262,57 @@ fn create_html_report(runs: &[HurlRun], dir_path: &Path) -> Result<(), CliError>
 }
 
 /// Create an JSON report for this run.
-fn create_json_report(runs: &[HurlRun], dir_path: &Path) -> Result<(), CliError> {
+fn create_json_report(
+    runs: &[HurlRun],
+    dir_path: &Path,
+    secrets: &[String],
+) -> Result<(), CliError> {
     // We ensure that the containing folder exists.
     let store_path = dir_path.join("store");
     std::fs::create_dir_all(&store_path)?;
 
+    let names = runs.iter().map(|r| r.report_name()).collect::<Vec<_>>();
     let testcases = runs
         .iter()
-        .map(|r| json::Testcase::new(&r.hurl_result, &r.content, &r.filename))
+        .zip(&names)
+        .map(|(r, name)| json::Testcase::new(&r.hurl_result, &r.content, name))
         .collect::<Vec<_>>();
 
     let index_path = dir_path.join("report.json");
-    json::write_report(&index_path, &testcases, &store_path)?;
+    json::write_report(&index_path, &testcases, &store_path, secrets)?;
     Ok(())
 }
 
 /// Returns an exit code for a list of HurlResult.
-fn exit_code(runs: &[HurlRun]) -> i32 {
+///
+/// The most severe error gives the exit code: runtime errors, then timeouts, then assert errors
+/// (ignored if `warn_asserts` is true).
+fn exit_code(runs: &[HurlRun], warn_asserts: bool) -> i32 {
     let mut count_errors_runner = 0;
+    let mut count_errors_timeout = 0;
     let mut count_errors_assert = 0;
     for run in runs.iter() {
         let errors = run.hurl_result.errors();
+        let runtime_errors = errors
+            .iter()
+            .filter(|(error, _)| !error.assert)
+            .collect::<Vec<_>>();
         if errors.is_empty() {
-        } else if errors.iter().filter(|(error, _)| !error.assert).count() == 0 {
+        } else if runtime_errors.is_empty() {
             count_errors_assert += 1;
+        } else if runtime_errors.iter().all(|(error, _)| {
+            matches!(error.kind, RunnerErrorKind::Http(HttpError::Timeout { .. }))
+        }) {
+            count_errors_timeout += 1;
         } else {
             count_errors_runner += 1;
         }
     }
     if count_errors_runner > 0 {
         EXIT_ERROR_RUNTIME
-    } else if count_errors_assert > 0 {
+    } else if count_errors_timeout > 0 {
+        EXIT_ERROR_TIMEOUT
+    } else if count_errors_assert > 0 && !warn_asserts {
         EXIT_ERROR_ASSERT
     } else {
         EXIT_OK
@@ -271,3 +354,39 @@ fn create_cookies_file(runs: &[HurlRun], filename: &Path) -> Result<(), CliError
     }
     Ok(())
 }
+
+/// Exports the requests of `runs` to a list of curl commands, one per line.
+fn create_curl_export(
+    runs: &[HurlRun],
+    filename: &Path,
+    secrets: &[String],
+) -> Result<(), CliError> {
+    let mut file = match std::fs::File::create(filename) {
+        Err(why) => {
+            return Err(CliError::IO(format!(
+                "Issue writing to {}: {why:?}",
+                filename.display()
+            )));
+        }
+        Ok(file) => file,
+    };
+    let redactor = Redactor::new(secrets);
+    let mut s = String::new();
+    for run in runs.iter() {
+        for entry in run.hurl_result.entries.iter() {
+            if entry.curl_cmd.is_empty() {
+                continue;
+            }
+            s.push_str(&redactor.redact(&entry.curl_cmd));
+            s.push('\n');
+        }
+    }
+
+    if let Err(why) = file.write_all(s.as_bytes()) {
+        return Err(CliError::IO(format!(
+            "Issue writing to {}: {why:?}",
+            filename.display()
+        )));
+    }
+    Ok(())
+}
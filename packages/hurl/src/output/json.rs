@@ -20,6 +20,7 @@ use std::io;
 use hurl_core::input::Input;
 
 use crate::runner::{HurlResult, Output};
+use crate::util::redacted::redact_json;
 use crate::util::term::Stdout;
 
 /// Writes the `hurl_result` JSON representation to the file `filename_out`.
@@ -27,7 +28,8 @@ use crate::util::term::Stdout;
 /// If `filename_out` is `None`, stdout is used. If `append` is true, any existing file will
 /// be appended instead of being truncated. The original `content` of the Hurl file and the
 /// source `filename_in` is necessary in order to construct error fields with column, line number
-/// etc... when processing failed asserts and captures.
+/// etc... when processing failed asserts and captures. The secret values of the run are redacted
+/// from the JSON.
 pub fn write_json(
     hurl_result: &HurlResult,
     content: &str,
@@ -36,7 +38,8 @@ pub fn write_json(
     stdout: &mut Stdout,
     append: bool,
 ) -> Result<(), io::Error> {
-    let json_result = hurl_result.to_json(content, filename_in, None)?;
+    let mut json_result = hurl_result.to_json(content, filename_in, None)?;
+    redact_json(&mut json_result, &hurl_result.secrets);
     let serialized = serde_json::to_string(&json_result).unwrap();
     let bytes = format!("{serialized}\n");
     let bytes = bytes.into_bytes();
@@ -137,6 +137,9 @@ mod tests {
             duration: Default::default(),
             url: Url::from_str("http://localhost").unwrap(),
             certificate: None,
+            frames: vec![],
+            events: vec![],
+            decoded_body: Default::default(),
         }
     }
 
@@ -166,8 +169,10 @@ mod tests {
                     captures: vec![],
                     asserts: vec![],
                     errors: vec![],
+                    curl_cmd: String::new(),
                     transfer_duration: Duration::from_millis(0),
                     compressed: false,
+                    skipped: false,
                 },
                 EntryResult {
                     entry_index: 2,
@@ -185,8 +190,10 @@ mod tests {
                     captures: vec![],
                     asserts: vec![],
                     errors: vec![],
+                    curl_cmd: String::new(),
                     transfer_duration: Duration::from_millis(0),
                     compressed: false,
+                    skipped: false,
                 },
                 EntryResult {
                     entry_index: 3,
@@ -206,20 +213,26 @@ mod tests {
                             duration: Default::default(),
                             url: Url::from_str("https://baz.com").unwrap(),
                             certificate: None,
+                            frames: vec![],
+                            events: vec![],
+                            decoded_body: Default::default(),
                         },
                         timings: Default::default(),
                     }],
                     captures: vec![],
                     asserts: vec![],
                     errors: vec![],
+                    curl_cmd: String::new(),
                     transfer_duration: Duration::from_millis(0),
                     compressed: false,
+                    skipped: false,
                 },
             ],
             duration: Duration::from_millis(100),
             success: true,
             cookies: vec![],
             timestamp: 0,
+            secrets: vec![],
         }
     }
 
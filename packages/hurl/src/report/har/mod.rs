@@ -0,0 +1,485 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! [HTTP Archive (HAR) 1.2](http://www.softwareishard.com/blog/har-12-spec/) report.
+//!
+//! Every HTTP exchange of a run (including redirections) is exported as a HAR entry, so the
+//! report can be opened in browser devtools or replayed by other tools.
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use chrono::{DateTime, NaiveDateTime, SecondsFormat};
+use serde::{Deserialize, Serialize};
+
+use crate::http::{Call, Header, HttpVersion, Request, Response, Timings, LOCATION};
+use crate::report::ReportError;
+use crate::runner::HurlResult;
+use crate::util::redacted::redact_json;
+
+const HAR_VERSION: &str = "1.2";
+
+/// Creates/Append a HAR report from a list of Hurl `results`, redacting the `secrets` values.
+pub fn write_report(
+    filename: &Path,
+    results: &[&HurlResult],
+    secrets: &[String],
+) -> Result<(), ReportError> {
+    let mut har = parse_har_file(filename)?;
+    let entries = results
+        .iter()
+        .flat_map(|r| r.entries.iter())
+        .flat_map(|e| e.calls.iter())
+        .map(EntryHar::from_call);
+    har.log.entries.extend(entries);
+
+    // We ensure that parent folder is created.
+    if let Some(parent) = filename.parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            return Err(ReportError::from_error(
+                e,
+                filename,
+                "Issue writing HAR report",
+            ));
+        }
+    }
+    let mut har = serde_json::to_value(&har).unwrap();
+    redact_json(&mut har, secrets);
+    let serialized = serde_json::to_string(&har).unwrap();
+    let bytes = format!("{serialized}\n");
+    let mut file = match File::create(filename) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(ReportError::from_error(
+                e,
+                filename,
+                "Issue writing HAR report",
+            ))
+        }
+    };
+    match file.write_all(bytes.as_bytes()) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(ReportError::from_error(
+            e,
+            filename,
+            "Issue writing HAR report",
+        )),
+    }
+}
+
+/// Parses an existing HAR report `filename`, or returns an empty archive if there is none.
+fn parse_har_file(filename: &Path) -> Result<Har, ReportError> {
+    if !filename.exists() {
+        return Ok(Har::new());
+    }
+    let s = match fs::read_to_string(filename) {
+        Ok(s) => s,
+        Err(e) => {
+            return Err(ReportError::from_error(
+                e,
+                filename,
+                "Issue reading HAR report",
+            ))
+        }
+    };
+    match serde_json::from_str::<Har>(&s) {
+        Ok(har) => Ok(har),
+        Err(_) => Err(ReportError::from_string(&format!(
+            "HAR report {} is not valid",
+            filename.display()
+        ))),
+    }
+}
+
+/// These structures represent the HAR 1.2 schema.
+#[derive(Deserialize, Serialize)]
+struct Har {
+    log: LogHar,
+}
+
+#[derive(Deserialize, Serialize)]
+struct LogHar {
+    version: String,
+    creator: CreatorHar,
+    entries: Vec<EntryHar>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CreatorHar {
+    name: String,
+    version: String,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EntryHar {
+    started_date_time: String,
+    time: f64,
+    request: RequestHar,
+    response: ResponseHar,
+    cache: serde_json::Value,
+    timings: TimingsHar,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestHar {
+    method: String,
+    url: String,
+    http_version: String,
+    cookies: Vec<CookieHar>,
+    headers: Vec<NameValueHar>,
+    query_string: Vec<NameValueHar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_data: Option<PostDataHar>,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseHar {
+    status: u32,
+    status_text: String,
+    http_version: String,
+    cookies: Vec<CookieHar>,
+    headers: Vec<NameValueHar>,
+    content: ContentHar,
+    #[serde(rename = "redirectURL")]
+    redirect_url: String,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CookieHar {
+    name: String,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    http_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secure: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct NameValueHar {
+    name: String,
+    value: String,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostDataHar {
+    mime_type: String,
+    text: String,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ContentHar {
+    size: i64,
+    mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct TimingsHar {
+    blocked: f64,
+    dns: f64,
+    connect: f64,
+    ssl: f64,
+    send: f64,
+    wait: f64,
+    receive: f64,
+}
+
+impl Har {
+    fn new() -> Self {
+        Har {
+            log: LogHar {
+                version: HAR_VERSION.to_string(),
+                creator: CreatorHar {
+                    name: "hurl".to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                },
+                entries: vec![],
+            },
+        }
+    }
+}
+
+impl EntryHar {
+    fn from_call(call: &Call) -> Self {
+        let http_version = http_version(&call.response.version);
+        EntryHar {
+            started_date_time: call
+                .timings
+                .begin_call
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+            time: millis(call.timings.total),
+            request: RequestHar::from_request(&call.request, http_version),
+            response: ResponseHar::from_response(&call.response, http_version),
+            cache: serde_json::json!({}),
+            timings: TimingsHar::from_timings(&call.timings),
+        }
+    }
+}
+
+impl RequestHar {
+    fn from_request(request: &Request, http_version: &str) -> Self {
+        let cookies = request
+            .cookies()
+            .iter()
+            .map(|c| CookieHar {
+                name: c.name.clone(),
+                value: c.value.clone(),
+                path: None,
+                domain: None,
+                expires: None,
+                http_only: None,
+                secure: None,
+            })
+            .collect();
+        let headers = request
+            .headers
+            .iter()
+            .map(NameValueHar::from_header)
+            .collect();
+        let query_string = request
+            .url
+            .query_params()
+            .iter()
+            .map(|p| NameValueHar {
+                name: p.name.clone(),
+                value: p.value.clone(),
+            })
+            .collect();
+        let post_data = if request.body.is_empty() {
+            None
+        } else {
+            let mime_type = request.headers.content_type().unwrap_or_default();
+            Some(PostDataHar {
+                mime_type: mime_type.to_string(),
+                text: String::from_utf8_lossy(&request.body).to_string(),
+            })
+        };
+        RequestHar {
+            method: request.method.clone(),
+            url: request.url.to_string(),
+            http_version: http_version.to_string(),
+            cookies,
+            headers,
+            query_string,
+            post_data,
+            headers_size: -1,
+            body_size: request.body.len() as i64,
+        }
+    }
+}
+
+impl ResponseHar {
+    fn from_response(response: &Response, http_version: &str) -> Self {
+        let cookies = response
+            .cookies()
+            .iter()
+            .map(|c| CookieHar {
+                name: c.name.clone(),
+                value: c.value.clone(),
+                path: c.path(),
+                domain: c.domain(),
+                expires: c.expires().as_deref().and_then(expires),
+                http_only: Some(c.has_httponly()),
+                secure: Some(c.has_secure()),
+            })
+            .collect();
+        let headers = response
+            .headers
+            .iter()
+            .map(NameValueHar::from_header)
+            .collect();
+        let redirect_url = response
+            .headers
+            .get(LOCATION)
+            .map(|h| h.value.clone())
+            .unwrap_or_default();
+        ResponseHar {
+            status: response.status,
+            status_text: String::new(),
+            http_version: http_version.to_string(),
+            cookies,
+            headers,
+            content: ContentHar::from_response(response),
+            redirect_url,
+            headers_size: -1,
+            body_size: response.body.len() as i64,
+        }
+    }
+}
+
+impl ContentHar {
+    /// Creates the content of a `response`: textual bodies are exported as is, while other
+    /// bodies (binary, or that can't be decompressed) are base64 encoded.
+    fn from_response(response: &Response) -> Self {
+        let mime_type = response
+            .headers
+            .content_type()
+            .unwrap_or_default()
+            .to_string();
+        let (size, text, encoding) = match response.text() {
+            Ok(text) => (text.len() as i64, Some(text), None),
+            Err(_) => {
+                let text = general_purpose::STANDARD.encode(&response.body);
+                (
+                    response.body.len() as i64,
+                    Some(text),
+                    Some("base64".to_string()),
+                )
+            }
+        };
+        ContentHar {
+            size,
+            mime_type,
+            text,
+            encoding,
+        }
+    }
+}
+
+impl NameValueHar {
+    fn from_header(header: &Header) -> Self {
+        NameValueHar {
+            name: header.name.clone(),
+            value: header.value.clone(),
+        }
+    }
+}
+
+impl TimingsHar {
+    /// Converts libcurl timings, which are cumulative from the start of the call, to HAR timings,
+    /// which are the duration of each phase. `ssl` is included in `connect` and is -1 for non
+    /// TLS exchanges.
+    fn from_timings(timings: &Timings) -> Self {
+        let connected = timings.app_connect.max(timings.connect);
+        let ssl = if timings.app_connect.is_zero() {
+            -1.0
+        } else {
+            millis(timings.app_connect.saturating_sub(timings.connect))
+        };
+        TimingsHar {
+            blocked: -1.0,
+            dns: millis(timings.name_lookup),
+            connect: millis(connected.saturating_sub(timings.name_lookup)),
+            ssl,
+            send: millis(timings.pre_transfer.saturating_sub(connected)),
+            wait: millis(timings.start_transfer.saturating_sub(timings.pre_transfer)),
+            receive: millis(timings.total.saturating_sub(timings.start_transfer)),
+        }
+    }
+}
+
+/// Returns the HAR representation of an HTTP `version`.
+fn http_version(version: &HttpVersion) -> &'static str {
+    match version {
+        HttpVersion::Http10 => "HTTP/1.0",
+        HttpVersion::Http11 => "HTTP/1.1",
+        HttpVersion::Http2 => "HTTP/2",
+        HttpVersion::Http3 => "HTTP/3",
+    }
+}
+
+/// Returns the HAR representation (ISO 8601) of a cookie `Expires` attribute `value`, like
+/// `Wed, 21 Oct 2015 07:28:00 GMT`, or `None` if `value` is not a valid date.
+fn expires(value: &str) -> Option<String> {
+    let date = match DateTime::parse_from_rfc2822(value) {
+        Ok(date) => date.to_utc(),
+        // Some servers use the legacy Netscape format, with dashes in the date.
+        Err(_) => NaiveDateTime::parse_from_str(value, "%a, %d-%b-%Y %H:%M:%S GMT")
+            .ok()?
+            .and_utc(),
+    };
+    Some(date.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// Returns a `duration` in milliseconds, as used by HAR timings (with a microsecond precision).
+fn millis(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timings() {
+        let timings = Timings {
+            name_lookup: Duration::from_millis(2),
+            connect: Duration::from_millis(5),
+            app_connect: Duration::from_millis(15),
+            pre_transfer: Duration::from_millis(16),
+            start_transfer: Duration::from_millis(40),
+            total: Duration::from_millis(42),
+            ..Default::default()
+        };
+        let timings = TimingsHar::from_timings(&timings);
+        assert_eq!(timings.dns, 2.0);
+        assert_eq!(timings.connect, 13.0);
+        assert_eq!(timings.ssl, 10.0);
+        assert_eq!(timings.send, 1.0);
+        assert_eq!(timings.wait, 24.0);
+        assert_eq!(timings.receive, 2.0);
+
+        let timings = Timings {
+            name_lookup: Duration::from_millis(2),
+            connect: Duration::from_millis(5),
+            pre_transfer: Duration::from_millis(6),
+            start_transfer: Duration::from_millis(8),
+            total: Duration::from_millis(9),
+            ..Default::default()
+        };
+        let timings = TimingsHar::from_timings(&timings);
+        assert_eq!(timings.connect, 3.0);
+        assert_eq!(timings.ssl, -1.0);
+        assert_eq!(timings.send, 1.0);
+    }
+
+    #[test]
+    fn test_expires() {
+        assert_eq!(
+            expires("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some("2015-10-21T07:28:00.000Z".to_string())
+        );
+        assert_eq!(
+            expires("Thu, 01-Jan-1970 00:00:00 GMT"),
+            Some("1970-01-01T00:00:00.000Z".to_string())
+        );
+        assert_eq!(expires("tomorrow"), None);
+    }
+}
@@ -72,9 +72,17 @@ impl Testcase {
 }
 
 /// Returns an HTML view of an `entry` information as HTML (title, `entry_index` and captures).
+///
+/// A skipped entry is marked with a badge in its title.
 fn get_entry_html(entry: &EntryResult, entry_index: usize) -> String {
     let mut text = String::new();
-    text.push_str(&format!("<summary>Entry {entry_index}</summary>"));
+    if entry.skipped {
+        text.push_str(&format!(
+            "<summary>Entry {entry_index} <span class=\"skipped\">skipped</span></summary>"
+        ));
+    } else {
+        text.push_str(&format!("<summary>Entry {entry_index}</summary>"));
+    }
 
     if !entry.captures.is_empty() {
         let mut values = entry
@@ -172,3 +180,27 @@ fn new_table<T: AsRef<str>, U: AsRef<str>>(title: &str, data: &[(T, U)]) -> Stri
     text.push_str("</tbody></table>");
     text
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_html_skipped() {
+        let entry = EntryResult {
+            entry_index: 2,
+            ..Default::default()
+        };
+        assert_eq!(get_entry_html(&entry, 2), "<summary>Entry 2</summary>");
+
+        let entry = EntryResult {
+            entry_index: 2,
+            skipped: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            get_entry_html(&entry, 2),
+            "<summary>Entry 2 <span class=\"skipped\">skipped</span></summary>"
+        );
+    }
+}
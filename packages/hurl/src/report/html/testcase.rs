@@ -24,6 +24,7 @@ use hurl_core::parser;
 use uuid::Uuid;
 
 use crate::runner::{EntryResult, HurlResult, RunnerError};
+use crate::util::redacted::Redactor;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Testcase {
@@ -60,33 +61,40 @@ impl Testcase {
     /// - an HTML view of the Hurl source file (with potential errors and syntax colored),
     /// - an HTML timeline view of the executed entries (with potential errors, waterfall)
     /// - an HTML view of the executed run (headers, cookies, etc...)
+    ///
+    /// The `secrets` values are redacted from the HTML files.
     pub fn write_html(
         &self,
         content: &str,
         entries: &[EntryResult],
         dir: &Path,
+        secrets: &[String],
     ) -> Result<(), crate::report::ReportError> {
         // We parse the content as we'll reuse the AST to construct the HTML source file, and
         // the waterfall.
         // TODO: for the moment, we can only have parseable file.
         let hurl_file = parser::parse_hurl_file(content).unwrap();
+        let redactor = Redactor::new(secrets);
 
         // We create the timeline view.
         let output_file = dir.join(self.timeline_filename());
         let mut file = File::create(output_file)?;
         let html = self.get_timeline_html(&hurl_file, content, entries);
+        let html = redactor.redact(&html);
         file.write_all(html.as_bytes())?;
 
         // Then create the run view.
         let output_file = dir.join(self.run_filename());
         let mut file = File::create(output_file)?;
         let html = self.get_run_html(&hurl_file, content, entries);
+        let html = redactor.redact(&html);
         file.write_all(html.as_bytes())?;
 
         // And create the source view.
         let output_file = dir.join(self.source_filename());
         let mut file = File::create(output_file)?;
         let html = self.get_source_html(&hurl_file, content);
+        let html = redactor.redact(&html);
         file.write_all(html.as_bytes())?;
 
         Ok(())
@@ -42,15 +42,17 @@ use hurl_core::input::Input;
 
 use crate::report::ReportError;
 use crate::runner::HurlResult;
+use crate::util::redacted::redact_json;
 
 /// Exports a list of [`Testcase`] to a JSON file `filename`.
 ///
 /// Response file are saved under the `response_dir` directory and referenced by path in JSON report
-/// file.
+/// file. The `secrets` values are redacted from the report (but not from the response files).
 pub fn write_report(
     filename: &Path,
     testcases: &[Testcase],
     response_dir: &Path,
+    secrets: &[String],
 ) -> Result<(), ReportError> {
     // We parse any potential existing report.
     let mut report = deserialize::parse_json_report(filename)?;
@@ -62,6 +64,8 @@ pub fn write_report(
         .collect::<Result<Vec<_>, _>>()?;
     report.extend(json);
 
+    let mut report = serde_json::Value::Array(report);
+    redact_json(&mut report, secrets);
     let serialized = serde_json::to_string(&report).unwrap();
     let bytes = format!("{serialized}\n");
     let bytes = bytes.into_bytes();
@@ -21,7 +21,9 @@
 //! The link below seems the most "official" spec
 //! <https://www.ibm.com/docs/fr/developer-for-zos/9.1.1?topic=formats-junit-xml-format>
 //!
-//! One Hurl file will result into one JUnit `<testcase>`.
+//! One Hurl file will result into one JUnit `<testcase>`. Entries skipped with `--continue-on-error`
+//! because they use a variable that has not been captured are reported as additional
+//! `<testcase>` with a `<skipped>` child.
 //!
 //! The `<testcase>` can include `<error>` (for runtime error) or `<failure>` (for assert error)
 //! Each Hurl execution will generate its own `<testsuite>` within the root `<testsuites>`.
@@ -65,7 +67,11 @@ use crate::report::junit::xml::{Element, XmlDocument};
 use crate::report::ReportError;
 
 /// Creates a JUnit from a list of `testcases`.
-pub fn write_report(filename: &Path, testcases: &[Testcase]) -> Result<(), ReportError> {
+pub fn write_report(
+    filename: &Path,
+    testcases: &[Testcase],
+    secrets: &[String],
+) -> Result<(), ReportError> {
     // We ensure that parent folder is created.
     if let Some(parent) = filename.parent() {
         match std::fs::create_dir_all(parent) {
@@ -98,7 +104,12 @@ pub fn write_report(filename: &Path, testcases: &[Testcase]) -> Result<(), Repor
         Element::new("testsuites")
     };
 
-    let testsuite = create_testsuite(testcases);
+    // Secrets are redacted before the XML serialization escapes them.
+    let testcases = testcases
+        .iter()
+        .map(|t| t.redact(secrets))
+        .collect::<Vec<_>>();
+    let testsuite = create_testsuite(&testcases);
     root = root.add_child(testsuite);
 
     let doc = XmlDocument::new(root);
@@ -125,17 +136,22 @@ fn create_testsuite(testcases: &[Testcase]) -> Element {
     let mut tests = 0;
     let mut errors = 0;
     let mut failures = 0;
+    let mut skipped = 0;
 
     for cases in testcases.iter() {
         tests += 1;
         errors += cases.get_error_count();
         failures += cases.get_fail_count();
+        if cases.is_skipped() {
+            skipped += 1;
+        }
     }
 
     let mut element = Element::new("testsuite")
         .attr("tests", &tests.to_string())
         .attr("errors", &errors.to_string())
-        .attr("failures", &failures.to_string());
+        .attr("failures", &failures.to_string())
+        .attr("skipped", &skipped.to_string());
 
     for testcase in testcases.iter() {
         let child = testcase.to_xml();
@@ -169,6 +185,7 @@ mod tests {
             success: true,
             cookies: vec![],
             timestamp: 1,
+            secrets: vec![],
         };
         let tc = Testcase::from(&res, content, &filename);
         testcases.push(tc);
@@ -187,13 +204,16 @@ mod tests {
                     },
                     true,
                 )],
+                curl_cmd: String::new(),
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
+                skipped: false,
             }],
             duration: Duration::from_millis(230),
             success: true,
             cookies: vec![],
             timestamp: 1,
+            secrets: vec![],
         };
         let tc = Testcase::from(&res, content, &filename);
         testcases.push(tc);
@@ -213,13 +233,16 @@ mod tests {
                     }),
                     false,
                 )],
+                curl_cmd: String::new(),
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
+                skipped: false,
             }],
             duration: Duration::from_millis(230),
             success: true,
             cookies: vec![],
             timestamp: 1,
+            secrets: vec![],
         };
         let tc = Testcase::from(&res, content, &filename);
         testcases.push(tc);
@@ -229,7 +252,7 @@ mod tests {
         assert_eq!(
             doc.to_string().unwrap(),
             "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
-            <testsuite tests=\"3\" errors=\"1\" failures=\"1\">\
+            <testsuite tests=\"3\" errors=\"1\" failures=\"1\" skipped=\"0\">\
                 <testcase id=\"-\" name=\"-\" time=\"0.230\" />\
                 <testcase id=\"-\" name=\"-\" time=\"0.230\">\
                     <failure>Assert status code\n  \
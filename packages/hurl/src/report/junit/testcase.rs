@@ -20,6 +20,7 @@ use hurl_core::input::Input;
 
 use crate::report::junit::xml::Element;
 use crate::runner::HurlResult;
+use crate::util::redacted::Redactor;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Testcase {
@@ -28,8 +29,13 @@ pub struct Testcase {
     time_in_ms: u128,
     failures: Vec<String>,
     errors: Vec<String>,
+    /// Reason of a testcase that has been skipped.
+    skipped: Option<String>,
 }
 
+/// Reason of entries skipped because they use a variable that has not been captured.
+const SKIPPED_MESSAGE: &str = "a variable used by this entry has not been captured";
+
 impl Testcase {
     /// Creates an XML Junit &lt;testcase&gt; from an [`HurlResult`].
     pub fn from(hurl_result: &HurlResult, content: &str, filename: &Input) -> Testcase {
@@ -58,6 +64,40 @@ impl Testcase {
             time_in_ms,
             failures,
             errors,
+            skipped: None,
+        }
+    }
+
+    /// Creates one skipped XML Junit &lt;testcase&gt; per skipped entry of an [`HurlResult`].
+    pub fn skipped_entries(hurl_result: &HurlResult, filename: &Input) -> Vec<Testcase> {
+        hurl_result
+            .entries
+            .iter()
+            .filter(|e| e.skipped)
+            .map(|e| {
+                let line = e.source_info.start.line;
+                Testcase {
+                    id: format!("{filename}:{line}"),
+                    name: format!("{filename} (entry {})", e.entry_index),
+                    time_in_ms: 0,
+                    failures: vec![],
+                    errors: vec![],
+                    skipped: Some(SKIPPED_MESSAGE.to_string()),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns a copy of this testcase, with the `secrets` values redacted.
+    pub fn redact(&self, secrets: &[String]) -> Testcase {
+        let redactor = Redactor::new(secrets);
+        Testcase {
+            id: redactor.redact(&self.id),
+            name: redactor.redact(&self.name),
+            time_in_ms: self.time_in_ms,
+            failures: self.failures.iter().map(|f| redactor.redact(f)).collect(),
+            errors: self.errors.iter().map(|e| redactor.redact(e)).collect(),
+            skipped: self.skipped.clone(),
         }
     }
 
@@ -77,6 +117,10 @@ impl Testcase {
         for error in self.errors.iter() {
             element = element.add_child(Element::new("error").text(error));
         }
+
+        if let Some(message) = &self.skipped {
+            element = element.add_child(Element::new("skipped").attr("message", message));
+        }
         element
     }
 
@@ -87,6 +131,10 @@ impl Testcase {
     pub fn get_fail_count(&self) -> usize {
         self.failures.len()
     }
+
+    pub fn is_skipped(&self) -> bool {
+        self.skipped.is_some()
+    }
 }
 
 #[cfg(test)]
@@ -110,6 +158,7 @@ mod test {
             success: true,
             cookies: vec![],
             timestamp: 1,
+            secrets: vec![],
         };
 
         let content = "";
@@ -142,13 +191,16 @@ HTTP/1.0 200
                     },
                     true,
                 )],
+                curl_cmd: String::new(),
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
+                skipped: false,
             }],
             duration: Duration::from_millis(230),
             success: true,
             cookies: vec![],
             timestamp: 1,
+            secrets: vec![],
         };
 
         let element = Testcase::from(&hurl_result, content, &filename).to_xml();
@@ -184,13 +236,16 @@ HTTP/1.0 200
                     }),
                     false,
                 )],
+                curl_cmd: String::new(),
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
+                skipped: false,
             }],
             duration: Duration::from_millis(230),
             success: true,
             cookies: vec![],
             timestamp: 1,
+            secrets: vec![],
         };
         let element = Testcase::from(&hurl_result, content, &filename).to_xml();
         let doc = XmlDocument::new(element);
@@ -204,4 +259,36 @@ HTTP/1.0 200
    |</error></testcase>"#
         );
     }
+
+    #[test]
+    fn test_create_testcase_skipped() {
+        let filename = Input::new("test.hurl");
+        let hurl_result = HurlResult {
+            entries: vec![
+                EntryResult {
+                    entry_index: 1,
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 18)),
+                    ..Default::default()
+                },
+                EntryResult {
+                    entry_index: 2,
+                    source_info: SourceInfo::new(Pos::new(4, 1), Pos::new(4, 18)),
+                    skipped: true,
+                    ..Default::default()
+                },
+            ],
+            duration: Duration::from_millis(230),
+            success: false,
+            cookies: vec![],
+            timestamp: 1,
+            secrets: vec![],
+        };
+        let testcases = Testcase::skipped_entries(&hurl_result, &filename);
+        assert_eq!(testcases.len(), 1);
+        let doc = XmlDocument::new(testcases[0].to_xml());
+        assert_eq!(
+            doc.to_string().unwrap(),
+            r#"<?xml version="1.0" encoding="utf-8"?><testcase id="test.hurl:4" name="test.hurl (entry 2)" time="0.000"><skipped message="a variable used by this entry has not been captured" /></testcase>"#
+        );
+    }
 }
@@ -20,6 +20,7 @@
 //! a single unit.
 
 mod error;
+pub mod har;
 pub mod html;
 pub mod json;
 pub mod junit;
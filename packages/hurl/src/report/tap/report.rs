@@ -24,12 +24,19 @@ use regex::Regex;
 
 use super::Testcase;
 use crate::report::ReportError;
+use crate::util::redacted::redact;
 
 /// See <https://testanything.org/tap-version-13-specification.html>
 const TAP_REPORT_VERSION_MARKER: &str = "TAP version 13";
+const YAML_BLOCK_START: &str = "  ---";
+const YAML_BLOCK_END: &str = "  ...";
 
-/// Creates/Append a Tap report from a list of `testcases`
-pub fn write_report(filename: &Path, testcases: &[Testcase]) -> Result<(), ReportError> {
+/// Creates/Append a Tap report from a list of `testcases`, redacting the `secrets` values.
+pub fn write_report(
+    filename: &Path,
+    testcases: &[Testcase],
+    secrets: &[String],
+) -> Result<(), ReportError> {
     let mut all_testcases = vec![];
 
     let existing_testcases = parse_tap_file(filename)?;
@@ -39,11 +46,15 @@ pub fn write_report(filename: &Path, testcases: &[Testcase]) -> Result<(), Repor
     for testcase in testcases {
         all_testcases.push(testcase);
     }
-    write_tap_file(filename, &all_testcases)
+    write_tap_file(filename, &all_testcases, secrets)
 }
 
 /// Creates a Tap from a list of `testcases`.
-fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), ReportError> {
+fn write_tap_file(
+    filename: &Path,
+    testcases: &[&Testcase],
+    secrets: &[String],
+) -> Result<(), ReportError> {
     // We ensure that parent folder is created.
     if let Some(parent) = filename.parent() {
         match std::fs::create_dir_all(parent) {
@@ -77,8 +88,14 @@ fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Report
         let state = if testcase.success { "ok" } else { "not ok" };
         let number = i + 1;
         let description = &testcase.description;
-        s.push_str(format!("{state} {number} - {description}\n").as_str());
+        let directive = match &testcase.skip {
+            Some(reason) => format!(" # SKIP {reason}"),
+            None => String::new(),
+        };
+        s.push_str(format!("{state} {number} - {description}{directive}\n").as_str());
+        s.push_str(&diagnostics_block(&testcase.diagnostics));
     }
+    let s = redact(&s, secrets);
     match file.write_all(s.as_bytes()) {
         Ok(_) => Ok(()),
         Err(e) => Err(ReportError::from_error(
@@ -89,6 +106,59 @@ fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Report
     }
 }
 
+/// Returns the YAML diagnostic block of a testcase with these `diagnostics` messages.
+///
+/// ```text
+///   ---
+///   message: |
+///     Assert status code
+///   severity: fail
+///   ...
+/// ```
+fn diagnostics_block(diagnostics: &[String]) -> String {
+    if diagnostics.is_empty() {
+        return String::new();
+    }
+    let mut s = format!("{YAML_BLOCK_START}\n  message: |\n");
+    let message = diagnostics.join("\n\n");
+    for line in message.lines() {
+        if line.is_empty() {
+            s.push('\n');
+        } else {
+            s.push_str(&format!("    {line}\n"));
+        }
+    }
+    s.push_str(&format!("  severity: fail\n{YAML_BLOCK_END}\n"));
+    s
+}
+
+/// Parses the `lines` of a YAML diagnostic block (without start and end markers)
+/// and returns the diagnostic message.
+fn parse_diagnostics_block(lines: &[&str]) -> Vec<String> {
+    let mut message = vec![];
+    let mut in_message = false;
+    for line in lines {
+        if *line == "  message: |" {
+            in_message = true;
+        } else if line.is_empty() && in_message {
+            message.push("");
+        } else if let Some(line) = line.strip_prefix("    ") {
+            if in_message {
+                message.push(line);
+            }
+        } else {
+            in_message = false;
+        }
+    }
+    let message = message.join("\n");
+    let message = message.trim_end();
+    if message.is_empty() {
+        vec![]
+    } else {
+        vec![message.to_string()]
+    }
+}
+
 /// Parse Tap report file
 fn parse_tap_file(filename: &Path) -> Result<Vec<Testcase>, ReportError> {
     if !filename.exists() {
@@ -109,7 +179,7 @@ fn parse_tap_file(filename: &Path) -> Result<Vec<Testcase>, ReportError> {
 
 /// Parse Tap report
 fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
-    let mut testcases = vec![];
+    let mut testcases: Vec<Testcase> = vec![];
     let mut lines: Vec<&str> = s.lines().collect::<Vec<&str>>();
     if !lines.is_empty() {
         let mut header = lines.remove(0);
@@ -123,7 +193,19 @@ fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
                 "Invalid TAP Header <{header}>"
             )));
         }
-        for line in lines {
+        let mut lines = lines.into_iter();
+        while let Some(line) = lines.next() {
+            // A YAML diagnostic block is attached to the previous testcase.
+            if line == YAML_BLOCK_START {
+                let block = lines
+                    .by_ref()
+                    .take_while(|l| *l != YAML_BLOCK_END)
+                    .collect::<Vec<_>>();
+                if let Some(testcase) = testcases.last_mut() {
+                    testcase.diagnostics = parse_diagnostics_block(&block);
+                }
+                continue;
+            }
             let line = line.trim();
             if !line.is_empty() {
                 let testcase = Testcase::parse(line)?;
@@ -151,15 +233,21 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
+                    skip: None,
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
+                    skip: None,
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostics: vec![],
+                    skip: None,
                 }
             ]
         );
@@ -179,15 +267,21 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
+                    skip: None,
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
+                    skip: None,
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostics: vec![],
+                    skip: None,
                 }
             ]
         );
@@ -205,28 +299,129 @@ ok 5 - test.5.hurl
             vec![
                 Testcase {
                     description: "test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
+                    skip: None,
                 },
                 Testcase {
                     description: "test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
+                    skip: None,
                 },
                 Testcase {
                     description: "test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostics: vec![],
+                    skip: None,
                 },
                 Testcase {
                     description: "test.4.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostics: vec![],
+                    skip: None,
                 },
                 Testcase {
                     description: "test.5.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostics: vec![],
+                    skip: None,
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagnostics_block() {
+        let testcases = [
+            Testcase {
+                description: "tests_ok/test.1.hurl".to_string(),
+                success: true,
+                diagnostics: vec![],
+                skip: None,
+            },
+            Testcase {
+                description: "tests_ok/test.2.hurl".to_string(),
+                success: false,
+                diagnostics: vec![
+                    "Assert status code\n  --> tests_ok/test.2.hurl:2:6\n   |\n 2 | HTTP 200\n   |"
+                        .to_string(),
+                    "Assert failure".to_string(),
+                ],
+                skip: None,
+            },
+        ];
+        let mut s = String::new();
+        for testcase in testcases.iter() {
+            s.push_str(&diagnostics_block(&testcase.diagnostics));
+        }
+        assert_eq!(
+            s,
+            r#"  ---
+  message: |
+    Assert status code
+      --> tests_ok/test.2.hurl:2:6
+       |
+     2 | HTTP 200
+       |
+
+    Assert failure
+  severity: fail
+  ...
+"#
+        );
+
+        let report = format!(
+            "TAP version 13\n1..2\nok 1 - tests_ok/test.1.hurl\nnot ok 2 - tests_ok/test.2.hurl\n{s}"
+        );
+        assert_eq!(
+            parse_tap_report(&report).unwrap(),
+            vec![
+                testcases[0].clone(),
+                Testcase {
+                    description: "tests_ok/test.2.hurl".to_string(),
+                    success: false,
+                    diagnostics: vec![
+                        "Assert status code\n  --> tests_ok/test.2.hurl:2:6\n   |\n 2 | HTTP 200\n   |\n\nAssert failure"
+                            .to_string()
+                    ],
+                    skip: None,
                 }
             ]
         );
     }
 
+    #[test]
+    fn test_write_skipped_testcase() {
+        let testcases = [
+            Testcase {
+                description: "tests_ok/test.1.hurl".to_string(),
+                success: false,
+                diagnostics: vec![],
+                skip: None,
+            },
+            Testcase {
+                description: "tests_ok/test.1.hurl (entry 2)".to_string(),
+                success: true,
+                diagnostics: vec![],
+                skip: Some("variable not captured".to_string()),
+            },
+        ];
+        let dir = std::env::temp_dir().join(format!("hurl-tap-{}", std::process::id()));
+        let filename = dir.join("report.tap");
+        write_tap_file(&filename, &testcases.iter().collect::<Vec<_>>(), &[]).unwrap();
+        let s = std::fs::read_to_string(&filename).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            s,
+            "TAP version 13\n\
+             1..2\n\
+             not ok 1 - tests_ok/test.1.hurl\n\
+             ok 2 - tests_ok/test.1.hurl (entry 2) # SKIP variable not captured\n"
+        );
+        assert_eq!(parse_tap_report(&s).unwrap(), testcases.to_vec());
+    }
+
     #[test]
     fn test_parse_error() {
         let s = r#"Dummy header
@@ -15,6 +15,7 @@
  * limitations under the License.
  *
  */
+use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
 
 use crate::report::ReportError;
@@ -24,22 +25,59 @@ use crate::runner::HurlResult;
 pub struct Testcase {
     pub(crate) description: String,
     pub(crate) success: bool,
+    /// Diagnostic messages of a failed testcase, one per error.
+    pub(crate) diagnostics: Vec<String>,
+    /// Reason of a skipped testcase, written as a TAP `# SKIP` directive.
+    pub(crate) skip: Option<String>,
 }
 
+/// Reason of entries skipped because they use a variable that has not been captured.
+const SKIP_REASON: &str = "a variable used by this entry has not been captured";
+
 impl Testcase {
     /// Creates an Tap &lt;testcase&gt; from an [`HurlResult`].
-    pub fn from(hurl_result: &HurlResult, filename: &Input) -> Testcase {
+    pub fn from(hurl_result: &HurlResult, content: &str, filename: &Input) -> Testcase {
         let description = filename.to_string();
-        let success = hurl_result.errors().is_empty();
+        let errors = hurl_result.errors();
+        let success = errors.is_empty();
+        let diagnostics = errors
+            .iter()
+            .map(|(error, entry_src_info)| {
+                error.to_string(
+                    &description,
+                    content,
+                    Some(*entry_src_info),
+                    OutputFormat::Terminal(false),
+                )
+            })
+            .collect();
         Testcase {
             description,
             success,
+            diagnostics,
+            skip: None,
         }
     }
 
+    /// Creates one skipped Tap &lt;testcase&gt; per skipped entry of an [`HurlResult`].
+    pub fn skipped_entries(hurl_result: &HurlResult, filename: &Input) -> Vec<Testcase> {
+        hurl_result
+            .entries
+            .iter()
+            .filter(|e| e.skipped)
+            .map(|e| Testcase {
+                description: format!("{filename} (entry {})", e.entry_index),
+                success: true,
+                diagnostics: vec![],
+                skip: Some(SKIP_REASON.to_string()),
+            })
+            .collect()
+    }
+
     /// Creates an Tap &lt;testcase&gt; from a TAP line
     /// ok 1 - this is the first test
     /// nok 2 - this is the second test
+    /// ok 3 - this is the third test # SKIP reason
     pub(crate) fn parse(line: &str) -> Result<Testcase, ReportError> {
         let mut line = line;
         let success = if line.starts_with("ok") {
@@ -69,9 +107,19 @@ impl Testcase {
                 line.split_at(index).1[1..].trim().to_string()
             }
         };
+        // The TAP directive is case-insensitive.
+        let (description, skip) = match description.to_ascii_uppercase().find("# SKIP") {
+            Some(index) => (
+                description[..index].trim().to_string(),
+                Some(description[index + 6..].trim().to_string()),
+            ),
+            None => (description, None),
+        };
         Ok(Testcase {
             description,
             success,
+            diagnostics: vec![],
+            skip,
         })
     }
 }
@@ -91,7 +139,19 @@ mod tests {
             Testcase::parse("ok 1 - tests_ok/test.1.hurl").unwrap(),
             Testcase {
                 description: "tests_ok/test.1.hurl".to_string(),
-                success: true
+                success: true,
+                diagnostics: vec![],
+                skip: None,
+            }
+        );
+
+        assert_eq!(
+            Testcase::parse("ok 2 - tests_ok/test.1.hurl (entry 2) # skip not captured").unwrap(),
+            Testcase {
+                description: "tests_ok/test.1.hurl (entry 2)".to_string(),
+                success: true,
+                diagnostics: vec![],
+                skip: Some("not captured".to_string()),
             }
         );
     }
@@ -48,6 +48,9 @@ pub fn run_seq(
     // it on subsequent write.
     let mut append = false;
 
+    // Each file is run once per set of variables (one set per row of the data set, if any).
+    let variables_sets = options.variables_sets();
+
     for filename in queue {
         let content = filename.read_to_string();
         let content = match content {
@@ -57,45 +60,47 @@ pub fn run_seq(
                 return Err(error);
             }
         };
-        let variables = &options.variables;
-        let runner_options = options.to_runner_options(&filename, current_dir);
-        let logger_options = options.to_logger_options();
+        for (index, variables) in variables_sets.iter().enumerate() {
+            let runner_options = options.to_runner_options(&filename, current_dir);
+            let logger_options = options.to_logger_options();
 
-        // Run our Hurl file now, we can only fail if there is a parsing error.
-        // The parsing error is displayed in the `execute` call, that's why we gobble the error
-        // string.
-        let Ok(hurl_result) = runner::run(
-            &content,
-            Some(&filename),
-            &runner_options,
-            variables,
-            &logger_options,
-        ) else {
-            return Err(CliError::Parsing);
-        };
+            // Run our Hurl file now, we can only fail if there is a parsing error.
+            // The parsing error is displayed in the `execute` call, that's why we gobble the error
+            // string.
+            let Ok(hurl_result) = runner::run(
+                &content,
+                Some(&filename),
+                &runner_options,
+                variables,
+                &logger_options,
+            ) else {
+                return Err(CliError::Parsing);
+            };
 
-        // We can output the result, either the last raw body response or a structured JSON
-        // representation of the full Hurl result.
-        // In sequential run, we use an immediate (non-buffered) standard output.
-        // When we output to a file, the first time we truncate the output file, and we append it
-        // for subsequent writes.
-        let mut stdout = Stdout::new(WriteMode::Immediate);
-        print_output(
-            &hurl_result,
-            &content,
-            &filename,
-            options,
-            &mut stdout,
-            append,
-        )?;
-        append = true;
+            // We can output the result, either the last raw body response or a structured JSON
+            // representation of the full Hurl result.
+            // In sequential run, we use an immediate (non-buffered) standard output.
+            // When we output to a file, the first time we truncate the output file, and we append
+            // it for subsequent writes.
+            let mut stdout = Stdout::new(WriteMode::Immediate);
+            print_output(
+                &hurl_result,
+                &content,
+                &filename,
+                options,
+                &mut stdout,
+                append,
+            )?;
+            append = true;
 
-        let run = HurlRun {
-            content,
-            filename: filename.clone(),
-            hurl_result,
-        };
-        runs.push(run);
+            let run = HurlRun {
+                content: content.clone(),
+                filename: filename.clone(),
+                row: options.dataset.as_ref().map(|_| index + 1),
+                hurl_result,
+            };
+            runs.push(run);
+        }
     }
 
     Ok(runs)
@@ -168,14 +173,17 @@ pub fn run_par(
     options: &CliOptions,
     workers_count: usize,
 ) -> Result<Vec<HurlRun>, CliError> {
+    // Each file is run once per set of variables (one set per row of the data set, if any).
+    let variables_sets = options.variables_sets();
+    let runs_count = files.len() * variables_sets.len();
+
     // We're going to use the right numbers of workers. We don't need to use more workers than there
     // are input files (repeat option act as if we're dealing with a multiplied number of files)
     let workers_count = match options.repeat {
-        Some(Count::Finite(n)) => min(files.len() * n, workers_count),
+        Some(Count::Finite(n)) => min(runs_count * n, workers_count),
         Some(Count::Infinite) => workers_count,
-        None => min(files.len(), workers_count),
+        None => min(runs_count, workers_count),
     };
-    let variables = &options.variables;
     let output_type = options
         .output_type
         .to_output_type(options.include, options.color);
@@ -183,8 +191,13 @@ pub fn run_par(
 
     let jobs = files
         .iter()
+        .flat_map(|input| {
+            variables_sets
+                .iter()
+                .map(move |variables| (input, variables))
+        })
         .enumerate()
-        .map(|(seq, input)| {
+        .map(|(seq, (input, variables))| {
             let runner_options = options.to_runner_options(input, current_dir);
             let logger_options = options.to_logger_options();
             Job::new(input, seq, &runner_options, variables, &logger_options)
@@ -201,7 +214,20 @@ pub fn run_par(
         max_width,
     );
     let results = runner.run(&jobs)?;
-    let results = results.into_iter().map(HurlRun::from).collect();
+    let results = results
+        .into_iter()
+        .map(|result| {
+            // Jobs are ordered by file then by data set row, even when repeated.
+            let row = options
+                .dataset
+                .as_ref()
+                .map(|_| result.job.seq % variables_sets.len() + 1);
+            HurlRun {
+                row,
+                ..HurlRun::from(result)
+            }
+        })
+        .collect();
     Ok(results)
 }
 
@@ -210,6 +236,7 @@ impl From<JobResult> for HurlRun {
         HurlRun {
             content: job_result.content,
             filename: job_result.job.filename,
+            row: None,
             hurl_result: job_result.hurl_result,
         }
     }
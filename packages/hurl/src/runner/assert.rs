@@ -57,7 +57,13 @@ impl AssertResult {
                 expected,
                 source_info,
             } => {
-                if actual == expected {
+                let matched = match expected {
+                    StatusValue::Any => true,
+                    StatusValue::Specific(expected) => actual == expected,
+                    StatusValue::Class(class) => actual / 100 == *class,
+                    StatusValue::List(expected) => expected.contains(actual),
+                };
+                if matched {
                     None
                 } else {
                     let kind = RunnerErrorKind::AssertStatus {
@@ -92,6 +98,16 @@ impl AssertResult {
                     Ok(actual) => {
                         if actual == expected {
                             None
+                        } else if let (Value::Bytes(expected), Value::Bytes(actual)) =
+                            (expected, actual)
+                        {
+                            let offset = first_mismatch(expected, actual);
+                            let kind = RunnerErrorKind::AssertBodyBytesDiff {
+                                offset,
+                                actual: bytes_excerpt(actual, offset),
+                                expected: bytes_excerpt(expected, offset),
+                            };
+                            Some(RunnerError::new(*source_info, kind, false))
                         } else if use_diff(expected, actual) {
                             let actual = actual.to_string();
                             let expected = expected.to_string();
@@ -146,8 +162,35 @@ fn use_diff(expected: &Value, actual: &Value) -> bool {
     }
 }
 
-/// Evaluates an explicit `assert`, given a set of `variables`, a HTTP response and a context
-/// directory `context_dir`.
+/// Returns the offset of the first byte that differs between `expected` and `actual`.
+fn first_mismatch(expected: &[u8], actual: &[u8]) -> usize {
+    expected
+        .iter()
+        .zip(actual)
+        .position(|(e, a)| e != a)
+        .unwrap_or(expected.len().min(actual.len()))
+}
+
+/// Returns a short hexadecimal representation of `bytes`, around `offset`.
+///
+/// A few bytes before `offset` are kept so that an excerpt is still displayed when `bytes` ends
+/// at `offset` (for instance, when the actual body is a prefix of the expected one).
+fn bytes_excerpt(bytes: &[u8], offset: usize) -> String {
+    const BEFORE_LEN: usize = 4;
+    const AFTER_LEN: usize = 12;
+    let start = offset.saturating_sub(BEFORE_LEN).min(bytes.len());
+    let end = offset.saturating_add(AFTER_LEN).min(bytes.len());
+    let prefix = if start > 0 { "..." } else { "" };
+    let suffix = if end < bytes.len() { "..." } else { "" };
+    format!(
+        "hex, {prefix}{}{suffix}; ({} bytes)",
+        hex::encode(&bytes[start..end]),
+        bytes.len()
+    )
+}
+
+/// Evaluates an explicit `assert`, given a set of `variables`, a HTTP response, the URLs
+/// followed by redirection `redirects` and a context directory `context_dir`.
 ///
 /// The `cache` is used to store XML / JSON structured response data and avoid redundant parsing
 /// operation on the response.
@@ -155,10 +198,19 @@ pub fn eval_explicit_assert(
     assert: &Assert,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    redirects: &[http::Url],
+    timings: &http::Timings,
     cache: &mut BodyCache,
     context_dir: &ContextDir,
 ) -> AssertResult {
-    let query_result = eval_query(&assert.query, variables, http_response, cache);
+    let query_result = eval_query(
+        &assert.query,
+        variables,
+        http_response,
+        redirects,
+        timings,
+        cache,
+    );
 
     let actual = if assert.filters.is_empty() {
         query_result
@@ -274,6 +326,8 @@ pub mod tests {
                 &assert_count_user(),
                 &variables,
                 &xml_three_users_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache,
                 &context_dir
             ),
@@ -285,6 +339,59 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_status_error() {
+        let source_info = SourceInfo::new(Pos::new(2, 10), Pos::new(2, 13));
+        let status = |actual, expected| AssertResult::Status {
+            actual,
+            expected,
+            source_info,
+        };
+        assert!(status(200, StatusValue::Specific(200)).error().is_none());
+        assert!(status(201, StatusValue::Specific(200)).error().is_some());
+        assert!(status(204, StatusValue::Class(2)).error().is_none());
+        assert!(status(302, StatusValue::Class(2)).error().is_some());
+        let list = StatusValue::List(vec![200, 201, 204]);
+        assert!(status(201, list.clone()).error().is_none());
+        assert!(status(202, list).error().is_some());
+    }
+
+    #[test]
+    fn test_first_mismatch() {
+        assert_eq!(first_mismatch(b"Hello", b"Help"), 3);
+        assert_eq!(first_mismatch(b"Hello", b"Hello World!"), 5);
+        assert_eq!(first_mismatch(b"", b"Hello"), 0);
+    }
+
+    #[test]
+    fn test_bytes_excerpt() {
+        assert_eq!(bytes_excerpt(b"Hello", 3), "hex, 48656c6c6f; (5 bytes)");
+        assert_eq!(bytes_excerpt(b"Hello", 5), "hex, ...656c6c6f; (5 bytes)");
+        assert_eq!(
+            bytes_excerpt(&[0; 20], 2),
+            "hex, 0000000000000000000000000000...; (20 bytes)"
+        );
+        assert_eq!(
+            bytes_excerpt(&[0; 30], 10),
+            "hex, ...00000000000000000000000000000000...; (30 bytes)"
+        );
+    }
+
+    #[test]
+    fn test_bytes_excerpt_prefix() {
+        // Actual body is a prefix of the expected body: both excerpts must show the bytes
+        // surrounding the first mismatch.
+        let actual = b"Hello";
+        let expected = b"Hello World!";
+        let offset = first_mismatch(expected, actual);
+        assert_eq!(offset, 5);
+        assert_eq!(bytes_excerpt(actual, offset), "hex, ...656c6c6f; (5 bytes)");
+        assert_eq!(
+            bytes_excerpt(expected, offset),
+            "hex, ...656c6c6f20576f726c6421; (12 bytes)"
+        );
+    }
+
     #[test]
     pub fn test_use_diff() {
         assert!(!use_diff(&Value::Bool(true), &Value::Bool(false)));
@@ -29,6 +29,8 @@ pub struct BodyCache {
     xml: Option<Document>,
     /// The parsed JSON body
     json: Option<Value>,
+    /// The XML namespaces (prefix and URI) registered to the parsed XML document.
+    xml_namespaces: Vec<(String, String)>,
 }
 
 impl BodyCache {
@@ -37,6 +39,16 @@ impl BodyCache {
         BodyCache::default()
     }
 
+    /// Returns the XML namespaces to register to a parsed XML document.
+    pub fn xml_namespaces(&self) -> &[(String, String)] {
+        &self.xml_namespaces
+    }
+
+    /// Sets the XML `namespaces` to register to a parsed XML document.
+    pub fn set_xml_namespaces(&mut self, namespaces: &[(String, String)]) {
+        self.xml_namespaces = namespaces.to_vec();
+    }
+
     /// Returns a reference to a cached XML response.
     pub fn xml(&self) -> Option<&Document> {
         self.xml.as_ref()
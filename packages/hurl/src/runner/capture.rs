@@ -28,8 +28,8 @@ use crate::runner::result::CaptureResult;
 use crate::runner::template::eval_template;
 use crate::runner::Value;
 
-/// Evaluates a `capture` with `variables` map and `http_response`, returns a
-/// [`CaptureResult`] on success or an [`RunnerError`].
+/// Evaluates a `capture` with `variables` map, `http_response` and the URLs followed by
+/// redirection `redirects`, returns a [`CaptureResult`] on success or an [`RunnerError`].
 ///
 /// The `cache` is used to store XML / JSON structured response data and avoid redundant parsing
 /// operation on the response.
@@ -37,10 +37,19 @@ pub fn eval_capture(
     capture: &Capture,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    redirects: &[http::Url],
+    timings: &http::Timings,
     cache: &mut BodyCache,
 ) -> Result<CaptureResult, RunnerError> {
     let name = eval_template(&capture.name, variables)?;
-    let value = eval_query(&capture.query, variables, http_response, cache)?;
+    let value = eval_query(
+        &capture.query,
+        variables,
+        http_response,
+        redirects,
+        timings,
+        cache,
+    )?;
     let value = match value {
         None => {
             return Err(RunnerError::new(
@@ -57,8 +66,13 @@ pub fn eval_capture(
                 .collect::<Vec<_>>();
             match eval_filters(&filters, &value, variables, false)? {
                 None => {
+                    // Only the last filter can return no result, the previous ones raise an error.
+                    let source_info = capture
+                        .filters
+                        .last()
+                        .map_or(capture.query.source_info, |(_, f)| f.source_info);
                     return Err(RunnerError::new(
-                        capture.query.source_info,
+                        source_info,
                         RunnerErrorKind::NoQueryResult,
                         false,
                     ));
@@ -106,6 +120,8 @@ pub mod tests {
             // xpath count(//user)
             query: query::tests::xpath_count_user_query(),
             filters: vec![],
+            space3: whitespace.clone(),
+            redacted: false,
             line_terminator0: LineTerminator {
                 space0: whitespace.clone(),
                 comment: None,
@@ -137,6 +153,8 @@ pub mod tests {
             // xpath count(//user)
             query: query::tests::jsonpath_duration(),
             filters: vec![],
+            space3: whitespace.clone(),
+            redacted: false,
             line_terminator0: LineTerminator {
                 space0: whitespace.clone(),
                 comment: None,
@@ -169,6 +187,8 @@ pub mod tests {
             space2: whitespace.clone(),
 
             query: query::tests::xpath_invalid_query(),
+            space3: whitespace.clone(),
+            redacted: false,
             line_terminator0: LineTerminator {
                 space0: whitespace.clone(),
                 comment: None,
@@ -180,6 +200,8 @@ pub mod tests {
             &capture,
             &variables,
             &http::xml_three_users_http_response(),
+            &[],
+            &http::Timings::default(),
             &mut cache,
         )
         .err()
@@ -225,6 +247,8 @@ pub mod tests {
                 },
             },
             filters: vec![],
+            space3: whitespace.clone(),
+            redacted: false,
             line_terminator0: LineTerminator {
                 space0: whitespace.clone(),
                 comment: None,
@@ -233,6 +257,31 @@ pub mod tests {
         };
     }
 
+    #[test]
+    fn test_capture_filter_no_result() {
+        let content = r#"GET http://localhost:8000/errors
+HTTP 200
+[Captures]
+id: jsonpath "$.errors[0].id" regex "(\\d{3})"
+"#;
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let capture = &hurl_file.entries[0].response.as_ref().unwrap().captures()[0];
+        let error = eval_capture(
+            capture,
+            &HashMap::new(),
+            &http::json_http_response(),
+            &[],
+            &http::Timings::default(),
+            &mut BodyCache::new(),
+        )
+        .unwrap_err();
+        assert_eq!(error.kind, RunnerErrorKind::NoQueryResult);
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(4, 31), Pos::new(4, 47))
+        );
+    }
+
     #[test]
     fn test_capture() {
         let variables = HashMap::new();
@@ -243,6 +292,8 @@ pub mod tests {
                 &user_count_capture(),
                 &variables,
                 &http::xml_three_users_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache,
             )
             .unwrap(),
@@ -257,6 +308,8 @@ pub mod tests {
                 &duration_capture(),
                 &variables,
                 &http::json_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache
             )
             .unwrap(),
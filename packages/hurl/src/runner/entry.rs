@@ -20,13 +20,14 @@ use std::collections::HashMap;
 use hurl_core::ast::*;
 
 use crate::http;
-use crate::http::ClientOptions;
+use crate::http::{ClientOptions, HttpClient, RequestedHttpVersion};
 use crate::runner::cache::BodyCache;
 use crate::runner::error::RunnerError;
+use crate::runner::event::EventListener;
 use crate::runner::result::{AssertResult, EntryResult};
 use crate::runner::runner_options::RunnerOptions;
 use crate::runner::value::Value;
-use crate::runner::{request, response, CaptureResult, RunnerErrorKind};
+use crate::runner::{grpc, request, response, CaptureResult, RunnerErrorKind};
 use crate::util::logger::{Logger, Verbosity};
 
 /// Runs an `entry` with `http_client` and returns one [`EntryResult`].
@@ -35,12 +36,14 @@ use crate::util::logger::{Logger, Verbosity};
 /// been executed. If `http_client` has been configured to follow redirection, the `calls` list contains
 /// every step of the redirection for the first to the last.
 /// `variables` are used to render values at runtime, and can be updated by captures.
+/// HTTP requests and responses are reported to an optional `listener`.
 pub fn run(
     entry: &Entry,
     entry_index: usize,
-    http_client: &mut http::Client,
+    http_client: &mut dyn HttpClient,
     variables: &mut HashMap<String, Value>,
     runner_options: &RunnerOptions,
+    listener: Option<&dyn EventListener>,
     logger: &mut Logger,
 ) -> EntryResult {
     let compressed = runner_options.compressed;
@@ -48,7 +51,7 @@ pub fn run(
     let context_dir = &runner_options.context_dir;
 
     // Evaluates our source requests given our set of variables
-    let http_request = match request::eval_request(&entry.request, variables, context_dir) {
+    let mut http_request = match request::eval_request(&entry.request, variables, context_dir) {
         Ok(r) => r,
         Err(error) => {
             return EntryResult {
@@ -60,7 +63,47 @@ pub fn run(
             };
         }
     };
-    let client_options = ClientOptions::from(runner_options, logger.verbosity);
+    // An URL with a `ws` or `wss` scheme opens a WebSocket, instead of sending an HTTP request.
+    let websocket = http_request.url.starts_with("ws://") || http_request.url.starts_with("wss://");
+    let frames = if websocket {
+        match request::eval_frames(&entry.request, variables) {
+            Ok(frames) => frames,
+            Err(error) => {
+                return EntryResult {
+                    entry_index,
+                    source_info,
+                    errors: vec![error],
+                    compressed,
+                    ..Default::default()
+                };
+            }
+        }
+    } else {
+        vec![]
+    };
+    // With a `proto` option, the request is a unary gRPC call.
+    let grpc_method = match &runner_options.proto {
+        Some(proto) => {
+            let source_info = entry.request.url.source_info;
+            match grpc::eval_grpc_request(&mut http_request, proto, source_info) {
+                Ok(method) => Some(method),
+                Err(error) => {
+                    return EntryResult {
+                        entry_index,
+                        source_info,
+                        errors: vec![error],
+                        compressed,
+                        ..Default::default()
+                    };
+                }
+            }
+        }
+        None => None,
+    };
+    let mut client_options = ClientOptions::from(runner_options, logger.verbosity);
+    if grpc_method.is_some() && client_options.http_version == RequestedHttpVersion::Default {
+        client_options.http_version = RequestedHttpVersion::Http2;
+    }
 
     // Experimental features with cookie storage
     use std::str::FromStr;
@@ -75,16 +118,29 @@ pub fn run(
         http_client.clear_cookie_storage(&client_options);
     }
 
-    log_request(
+    let output = &runner_options.output;
+    let curl_cmd = http::curl_command_line(
         http_client,
         &http_request,
-        runner_options,
+        context_dir,
+        output.as_ref(),
         &client_options,
-        logger,
     );
 
+    log_request(http_client, &http_request, &curl_cmd, logger);
+    if let Some(listener) = listener {
+        listener.on_request_sent(entry_index - 1, &http_request);
+    }
+
     // Run the HTTP requests (optionally follow redirection)
-    let calls = match http_client.execute_with_redirect(&http_request, &client_options, logger) {
+    let calls = if websocket {
+        http_client
+            .execute_websocket(&http_request, &frames, &client_options, logger)
+            .map(|call| vec![call])
+    } else {
+        http_client.execute_with_redirect(&http_request, &client_options, logger)
+    };
+    let mut calls = match calls {
         Ok(calls) => calls,
         Err(http_error) => {
             let start = entry.request.url.source_info.start;
@@ -96,24 +152,76 @@ pub fn run(
                 entry_index,
                 source_info,
                 errors: vec![error],
+                curl_cmd,
                 compressed,
                 ..Default::default()
             };
         }
     };
 
+    if let (Some(proto), Some(method)) = (&runner_options.proto, &grpc_method) {
+        let response = &mut calls.last_mut().unwrap().response;
+        let source_info = entry.request.url.source_info;
+        if let Err(error) = grpc::decode_grpc_response(response, proto, method, source_info) {
+            return EntryResult {
+                entry_index,
+                source_info,
+                calls,
+                errors: vec![error],
+                curl_cmd,
+                compressed,
+                ..Default::default()
+            };
+        }
+    }
+
+    if let Some(listener) = listener {
+        calls
+            .iter()
+            .for_each(|call| listener.on_response_received(entry_index - 1, call));
+    }
+
     // Now, we can compute capture and asserts on the last HTTP request/response chains.
     let call = calls.last().unwrap();
     let http_response = &call.response;
+    // Redirections followed to get the last response, used by the `redirects` query.
+    let redirects = calls
+        .iter()
+        .skip(1)
+        .map(|call| call.request.url.clone())
+        .collect::<Vec<_>>();
 
     // `transfer_duration` represent the network time of calls, not including assert processing.
     let transfer_duration = calls.iter().map(|call| call.timings.total).sum();
 
+    // Decompresses the body once, under the size limit, before any capture, assert or output reads
+    // it: the decompressed body is kept in the response and reused afterwards.
+    if let Some(max_size) = runner_options.max_decompressed_size {
+        if let Err(http_error) = http_response.uncompress_body_with_max_size(max_size) {
+            let start = entry.request.url.source_info.start;
+            let end = entry.request.url.source_info.end;
+            let error_source_info = SourceInfo::new(start, end);
+            let error =
+                RunnerError::new(error_source_info, RunnerErrorKind::Http(http_error), false);
+            return EntryResult {
+                entry_index,
+                source_info,
+                calls,
+                errors: vec![error],
+                curl_cmd,
+                transfer_duration,
+                compressed,
+                ..Default::default()
+            };
+        }
+    }
+
     // We proceed asserts and captures in this order:
     // 1. first, check implicit assert on status and version. If KO, test is failed
     // 2. then, we compute captures, we might need them in asserts
     // 3. finally, run the remaining asserts
     let mut cache = BodyCache::new();
+    cache.set_xml_namespaces(&runner_options.xml_namespaces);
     let mut asserts = vec![];
 
     if !runner_options.ignore_asserts {
@@ -131,8 +239,10 @@ pub fn run(
                     captures: vec![],
                     asserts,
                     errors,
+                    curl_cmd,
                     transfer_duration,
                     compressed,
+                    skipped: false,
                 };
             }
         }
@@ -141,7 +251,14 @@ pub fn run(
     let captures = match &entry.response {
         None => vec![],
         Some(response_spec) => {
-            match response::eval_captures(response_spec, http_response, &mut cache, variables) {
+            match response::eval_captures(
+                response_spec,
+                http_response,
+                &redirects,
+                &call.timings,
+                &mut cache,
+                variables,
+            ) {
                 Ok(captures) => captures,
                 Err(e) => {
                     return EntryResult {
@@ -151,13 +268,24 @@ pub fn run(
                         captures: vec![],
                         asserts,
                         errors: vec![e],
+                        curl_cmd,
                         transfer_duration,
                         compressed,
+                        skipped: false,
                     };
                 }
             }
         }
     };
+    // Values captured with `redact` are secrets, redacted from now on from the logs.
+    if let Some(response_spec) = &entry.response {
+        response_spec
+            .captures()
+            .iter()
+            .zip(captures.iter())
+            .filter(|(capture, _)| capture.redacted)
+            .for_each(|(_, c)| logger.add_secret(&c.value.to_string()));
+    }
     log_captures(&captures, logger);
     logger.debug("");
 
@@ -168,6 +296,8 @@ pub fn run(
                 response_spec,
                 variables,
                 http_response,
+                &redirects,
+                &call.timings,
                 &mut cache,
                 context_dir,
             );
@@ -184,8 +314,10 @@ pub fn run(
         captures,
         asserts,
         errors,
+        curl_cmd,
         transfer_duration,
         compressed,
+        skipped: false,
     }
 }
 
@@ -205,7 +337,7 @@ fn asserts_to_errors(asserts: &[AssertResult]) -> Vec<RunnerError> {
 }
 
 impl ClientOptions {
-    fn from(runner_options: &RunnerOptions, verbosity: Option<Verbosity>) -> Self {
+    pub(crate) fn from(runner_options: &RunnerOptions, verbosity: Option<Verbosity>) -> Self {
         ClientOptions {
             aws_sigv4: runner_options.aws_sigv4.clone(),
             cacert_file: runner_options.cacert_file.clone(),
@@ -215,6 +347,8 @@ impl ClientOptions {
             connect_timeout: runner_options.connect_timeout,
             connects_to: runner_options.connects_to.clone(),
             cookie_input_file: runner_options.cookie_input_file.clone(),
+            digest: runner_options.digest,
+            doh_url: runner_options.doh_url.clone(),
             follow_location: runner_options.follow_location,
             follow_location_trusted: runner_options.follow_location_trusted,
             http_version: runner_options.http_version,
@@ -224,12 +358,16 @@ impl ClientOptions {
             netrc: runner_options.netrc,
             netrc_file: runner_options.netrc_file.clone(),
             netrc_optional: runner_options.netrc_optional,
+            ntlm: runner_options.ntlm,
+            oauth2_bearer: runner_options.oauth2_bearer.clone(),
             path_as_is: runner_options.path_as_is,
             proxy: runner_options.proxy.clone(),
             no_proxy: runner_options.no_proxy.clone(),
             insecure: runner_options.insecure,
             resolves: runner_options.resolves.clone(),
             ssl_no_revoke: runner_options.ssl_no_revoke,
+            stream_count: runner_options.stream_count,
+            stream_timeout: runner_options.stream_timeout,
             timeout: runner_options.timeout,
             unix_socket: runner_options.unix_socket.clone(),
             user: runner_options.user.clone(),
@@ -245,10 +383,9 @@ impl ClientOptions {
 
 /// Logs this HTTP `request`.
 fn log_request(
-    http_client: &mut http::Client,
+    http_client: &mut dyn HttpClient,
     request: &http::RequestSpec,
-    runner_options: &RunnerOptions,
-    client_options: &ClientOptions,
+    curl_cmd: &str,
     logger: &mut Logger,
 ) {
     logger.debug("");
@@ -289,11 +426,7 @@ fn log_request(
     }
     logger.debug("");
     logger.debug("Request can be run with the following curl command:");
-    let context_dir = &runner_options.context_dir;
-    let output = &runner_options.output;
-    let curl_command =
-        http_client.curl_command_line(request, context_dir, output.as_ref(), client_options);
-    logger.debug(&curl_command);
+    logger.debug(curl_cmd);
     logger.debug("");
 }
 
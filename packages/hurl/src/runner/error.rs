@@ -57,6 +57,20 @@ pub enum RunnerErrorKind {
         actual: String,
         expected: String,
     },
+    /// JSON body assert failure, `hunks` is the diff of the normalized expected and actual bodies.
+    AssertBodyJsonDiff {
+        hunks: Vec<DiffHunk>,
+    },
+    /// XML body assert failure, `hunks` is the diff of the normalized expected and actual bodies.
+    AssertBodyXmlDiff {
+        hunks: Vec<DiffHunk>,
+    },
+    /// Bytes body assert failure, `offset` is the position of the first mismatching byte.
+    AssertBodyBytesDiff {
+        offset: usize,
+        actual: String,
+        expected: String,
+    },
     AssertFailure {
         actual: String,
         expected: String,
@@ -84,12 +98,25 @@ pub enum RunnerErrorKind {
     FilterInvalidEncoding(String),
     FilterInvalidInput(String),
     FilterMissingInput,
+    /// A gRPC call can not be prepared, or its response can not be decoded.
+    Grpc {
+        message: String,
+    },
     Http(HttpError),
     InvalidJson {
         value: String,
     },
+    InvalidJsonSchema {
+        message: String,
+    },
+    InvalidHexFrame,
     InvalidRegex,
     NoQueryResult,
+    /// An OAuth2 access token can not be fetched from `url`.
+    OAuth2Token {
+        url: String,
+        message: String,
+    },
     QueryHeaderNotFound,
     QueryInvalidJsonpathExpression {
         value: String,
@@ -125,6 +152,9 @@ impl DisplaySourceError for RunnerError {
         match &self.kind {
             RunnerErrorKind::AssertBodyDiffError { .. } => "Assert body value".to_string(),
             RunnerErrorKind::AssertBodyValueError { .. } => "Assert body value".to_string(),
+            RunnerErrorKind::AssertBodyJsonDiff { .. } => "Assert body value".to_string(),
+            RunnerErrorKind::AssertBodyXmlDiff { .. } => "Assert body value".to_string(),
+            RunnerErrorKind::AssertBodyBytesDiff { .. } => "Assert body value".to_string(),
             RunnerErrorKind::AssertFailure { .. } => "Assert failure".to_string(),
             RunnerErrorKind::AssertHeaderValueError { .. } => "Assert header value".to_string(),
             RunnerErrorKind::AssertStatus { .. } => "Assert status code".to_string(),
@@ -136,10 +166,14 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::FilterInvalidEncoding { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidInput { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterMissingInput => "Filter error".to_string(),
+            RunnerErrorKind::Grpc { .. } => "gRPC error".to_string(),
             RunnerErrorKind::Http(http_error) => http_error.description(),
             RunnerErrorKind::InvalidJson { .. } => "Invalid JSON".to_string(),
+            RunnerErrorKind::InvalidJsonSchema { .. } => "Invalid JSON schema".to_string(),
+            RunnerErrorKind::InvalidHexFrame => "Invalid WebSocket frame".to_string(),
             RunnerErrorKind::InvalidRegex => "Invalid regex".to_string(),
             RunnerErrorKind::NoQueryResult => "No query result".to_string(),
+            RunnerErrorKind::OAuth2Token { .. } => "OAuth2 token".to_string(),
             RunnerErrorKind::QueryHeaderNotFound => "Header not found".to_string(),
             RunnerErrorKind::QueryInvalidJson => "Invalid JSON".to_string(),
             RunnerErrorKind::QueryInvalidJsonpathExpression { .. } => {
@@ -174,6 +208,29 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::AssertBodyJsonDiff { hunks } => normalized_diff_string(
+                "JSON body differs (keys sorted)",
+                hunks,
+                self.source_info,
+                content,
+            ),
+            RunnerErrorKind::AssertBodyXmlDiff { hunks } => normalized_diff_string(
+                "XML body differs (whitespaces normalized)",
+                hunks,
+                self.source_info,
+                content,
+            ),
+            RunnerErrorKind::AssertBodyBytesDiff {
+                offset,
+                actual,
+                expected,
+            } => {
+                let message = &format!(
+                    "bytes differ at offset {offset}\n   actual:   {actual}\n   expected: {expected}"
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::AssertFailure {
                 actual,
                 expected,
@@ -234,6 +291,10 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::Grpc { message } => {
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::Http(http_error) => {
                 let message = http_error.message();
                 let message = error::add_carets(&message, self.source_info, content);
@@ -244,6 +305,16 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::InvalidJsonSchema { message } => {
+                let message = &format!("JSON schema is not valid: {message}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::InvalidHexFrame => {
+                let message = "binary frame value is not a valid hexadecimal string";
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::InvalidRegex => {
                 let message = "regex expression is not valid";
                 let message = error::add_carets(message, self.source_info, content);
@@ -254,6 +325,11 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::OAuth2Token { url, message } => {
+                let message = &format!("access token can not be fetched from <{url}>: {message}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::QueryHeaderNotFound => {
                 let message = "this header has not been found in the response";
                 let message = error::add_carets(message, self.source_info, content);
@@ -351,6 +427,33 @@ fn color_red_multiline_string(s: &str) -> StyledString {
     s
 }
 
+/// Returns a `message` with carets under the body at `source_info`, followed by the `hunks` of
+/// the diff between normalized bodies.
+fn normalized_diff_string(
+    message: &str,
+    hunks: &[DiffHunk],
+    source_info: SourceInfo,
+    content: &[&str],
+) -> StyledString {
+    let carets = error::add_carets(message, source_info, content);
+    // Hunks are aligned with the message, just after the carets.
+    let caret_line = carets.lines().next().unwrap_or_default();
+    let column = caret_line.rfind('^').map_or(0, |index| index + 2);
+    let indent = " ".repeat(column);
+    let mut message = color_red_multiline_string(&carets);
+    for hunk in hunks {
+        for line in hunk.content.split('\n') {
+            if line.is_empty() {
+                continue;
+            }
+            message.push("\n");
+            message.push(&indent);
+            message.append(line);
+        }
+    }
+    message
+}
+
 fn hunk_string(
     hunk: &DiffHunk,
     source_line: usize,
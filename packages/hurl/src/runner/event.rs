@@ -15,9 +15,76 @@
  * limitations under the License.
  *
  */
+use crate::http::{Call, RequestSpec};
+use crate::runner::{AssertResult, EntryResult};
+
 /// This trait is implemented by run event observers, during the execution of one Hurl file.
+///
+/// A listener is given to [`crate::runner::run_with_listener`] (or
+/// [`crate::runner::run_with_client`]) and receives live progress of the run, for instance to
+/// display it in a GUI or an IDE plugin without parsing the standard output.
+///
+/// In every callback, `entry_index` is the entry 0-based index in the Hurl file. Except
+/// [`EventListener::on_entry_started`], callbacks have a default empty implementation so observers
+/// only implement the events they are interested in. Callbacks are prefixed with `on_`, so
+/// `on_entry_started` is called on the `entry_started` event, `on_request_sent` on the
+/// `request_sent` event etc...
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use hurl::runner;
+/// use hurl::runner::{EntryResult, EventListener, RunnerOptionsBuilder};
+/// use hurl::util::logger::LoggerOptionsBuilder;
+///
+/// struct Progress;
+///
+/// impl EventListener for Progress {
+///     fn on_entry_started(&self, entry_index: usize, entry_count: usize) {
+///         println!("Running entry {}/{entry_count}", entry_index + 1);
+///     }
+///
+///     fn on_entry_finished(&self, entry_index: usize, result: &EntryResult) {
+///         println!("Entry {} finished with {} error(s)", entry_index + 1, result.errors.len());
+///     }
+/// }
+///
+/// let content = r#"
+/// GET http://localhost:8000/hello
+/// HTTP 200
+/// "#;
+///
+/// let runner_opts = RunnerOptionsBuilder::new().build();
+/// let logger_opts = LoggerOptionsBuilder::new().build();
+/// let variables = HashMap::default();
+///
+/// let result = runner::run_with_listener(
+///     content,
+///     None,
+///     &runner_opts,
+///     &variables,
+///     &Progress,
+///     &logger_opts,
+/// );
+/// assert!(result.unwrap().success);
+/// ```
 pub trait EventListener {
-    /// Call when running an entry, `entry_index` is the entry 0-based index in the Hurl file,
-    /// and `entry_count` is the total number of entries in the Hurl file.
-    fn on_running(&self, entry_index: usize, entry_count: usize);
+    /// Call when an entry starts running, `entry_index` is the entry 0-based index in the Hurl
+    /// file, and `entry_count` is the total number of entries in the Hurl file.
+    fn on_entry_started(&self, entry_index: usize, entry_count: usize);
+
+    /// Call before sending the HTTP request `request_spec` of an entry, variables being rendered.
+    fn on_request_sent(&self, _entry_index: usize, _request_spec: &RequestSpec) {}
+
+    /// Call when an HTTP response has been received. When redirections are followed, this is
+    /// called for each `call`.
+    fn on_response_received(&self, _entry_index: usize, _call: &Call) {}
+
+    /// Call for each `assert` evaluated on the last HTTP response of an entry.
+    fn on_assert_evaluated(&self, _entry_index: usize, _assert: &AssertResult) {}
+
+    /// Call when an entry run is completed (including captures, asserts and output). When an
+    /// entry is retried, this is called for each attempt.
+    fn on_entry_finished(&self, _entry_index: usize, _result: &EntryResult) {}
 }
@@ -20,13 +20,19 @@ use std::collections::HashMap;
 use hurl_core::ast::Expr;
 
 use crate::runner::error::{RunnerError, RunnerErrorKind};
+use crate::runner::function::eval_function;
 use crate::runner::value::Value;
 
 /// Evaluates the expression `expr` with `variables` map and `http_response`, returns a
 /// [`Value`] on success or an [`RunnerError`] .
+///
+/// If there's no variable named as the expression, the built-in function with this name is
+/// evaluated, if any.
 pub fn eval_expr(expr: &Expr, variables: &HashMap<String, Value>) -> Result<Value, RunnerError> {
     if let Some(value) = variables.get(expr.variable.name.as_str()) {
         Ok(value.clone())
+    } else if let Some(value) = eval_function(&expr.variable.name) {
+        Ok(value)
     } else {
         let kind = RunnerErrorKind::TemplateVariableNotDefined {
             name: expr.variable.name.clone(),
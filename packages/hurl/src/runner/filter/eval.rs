@@ -20,6 +20,8 @@ use std::collections::HashMap;
 use hurl_core::ast::{Filter, FilterValue};
 
 use super::count::eval_count;
+use crate::runner::filter::base64_decode::eval_base64_decode;
+use crate::runner::filter::base64_encode::eval_base64_encode;
 use crate::runner::filter::days_after_now::eval_days_after_now;
 use crate::runner::filter::days_before_now::eval_days_before_now;
 use crate::runner::filter::decode::eval_decode;
@@ -70,6 +72,8 @@ pub fn eval_filter(
     in_assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     match &filter.value {
+        FilterValue::Base64Decode => eval_base64_decode(value, filter.source_info, in_assert),
+        FilterValue::Base64Encode => eval_base64_encode(value, filter.source_info, in_assert),
         FilterValue::Count => eval_count(value, filter.source_info, in_assert),
         FilterValue::DaysAfterNow => eval_days_after_now(value, filter.source_info, in_assert),
         FilterValue::DaysBeforeNow => eval_days_before_now(value, filter.source_info, in_assert),
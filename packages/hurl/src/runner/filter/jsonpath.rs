@@ -21,7 +21,7 @@ use hurl_core::ast::{SourceInfo, Template};
 
 use crate::jsonpath;
 use crate::runner::template::eval_template;
-use crate::runner::{RunnerError, RunnerErrorKind, Value};
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
 
 pub fn eval_jsonpath(
     value: &Value,
@@ -44,6 +44,15 @@ pub fn eval_jsonpath(
             };
             eval_jsonpath_json(&json, expr, variables)
         }
+        // Lists and objects (like server-sent events) can also be queried, as long as they only
+        // hold JSON values.
+        Value::List(_) | Value::Object(_) => match to_json(value) {
+            Some(json) => eval_jsonpath_json(&json, expr, variables),
+            None => {
+                let kind = RunnerErrorKind::FilterInvalidInput(value._type());
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v._type());
             Err(RunnerError::new(source_info, kind, assert))
@@ -76,6 +85,31 @@ pub fn eval_jsonpath_json(
     }
 }
 
+/// Converts a `value` to JSON, or returns `None` if it holds non JSON values (bytes, dates etc...).
+fn to_json(value: &Value) -> Option<serde_json::Value> {
+    let json = match value {
+        Value::Bool(value) => serde_json::Value::Bool(*value),
+        Value::Null => serde_json::Value::Null,
+        Value::Number(Number::Integer(value)) => serde_json::Value::from(*value),
+        Value::Number(Number::Float(value)) => {
+            serde_json::Value::Number(serde_json::Number::from_f64(*value)?)
+        }
+        Value::Number(Number::BigInteger(value)) => serde_json::from_str(value).ok()?,
+        Value::String(value) => serde_json::Value::String(value.clone()),
+        Value::List(values) => {
+            serde_json::Value::Array(values.iter().map(to_json).collect::<Option<_>>()?)
+        }
+        Value::Object(values) => serde_json::Value::Object(
+            values
+                .iter()
+                .map(|(key, value)| Some((key.clone(), to_json(value)?)))
+                .collect::<Option<_>>()?,
+        ),
+        _ => return None,
+    };
+    Some(json)
+}
+
 #[cfg(test)]
 pub mod tests {
     use std::collections::HashMap;
@@ -118,5 +152,33 @@ pub mod tests {
             .unwrap(),
             Value::String("Hello".to_string())
         );
+
+        let events = Value::List(vec![Value::Object(vec![
+            ("event".to_string(), Value::String("message".to_string())),
+            ("data".to_string(), Value::String("Bonjour".to_string())),
+        ])]);
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonPath {
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$[0].data".to_string(),
+                        encoded: "$[0].data".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        assert_eq!(
+            eval_filter(&filter, &events, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::String("Bonjour".to_string())
+        );
     }
 }
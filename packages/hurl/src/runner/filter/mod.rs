@@ -20,6 +20,8 @@ pub use eval::eval_filters;
 pub use jsonpath::eval_jsonpath_json;
 pub use xpath::eval_xpath_doc;
 
+mod base64_decode;
+mod base64_encode;
 mod count;
 mod days_after_now;
 mod days_before_now;
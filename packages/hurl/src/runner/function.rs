@@ -0,0 +1,82 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Built-in functions, available in templates like variables (`{{newUuid}}`).
+//!
+//! A function is only evaluated if there's no variable with the same name, so existing variables
+//! always take precedence.
+use chrono::Utc;
+use rand::distributions::{Alphanumeric, DistString};
+use rand::Rng;
+use uuid::Uuid;
+
+use crate::runner::{Number, Value};
+
+/// Length of the strings generated by `randomString`.
+const RANDOM_STRING_LEN: usize = 16;
+
+/// Evaluates the function `name`, returns `None` if there's no function with this name.
+///
+/// - `newUuid`: a random UUID v4 string
+/// - `newDate`: the current date, in UTC
+/// - `randomInt`: a random non-negative integer
+/// - `randomString`: a random string of 16 alphanumeric characters
+pub fn eval_function(name: &str) -> Option<Value> {
+    let value = match name {
+        "newUuid" => Value::String(Uuid::new_v4().to_string()),
+        "newDate" => Value::Date(Utc::now()),
+        "randomInt" => {
+            let n = rand::thread_rng().gen_range(0..=i64::MAX);
+            Value::Number(Number::Integer(n))
+        }
+        "randomString" => {
+            let s = Alphanumeric.sample_string(&mut rand::thread_rng(), RANDOM_STRING_LEN);
+            Value::String(s)
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eval_function() {
+        let Some(Value::String(uuid)) = eval_function("newUuid") else {
+            panic!("newUuid should return a string");
+        };
+        assert!(Uuid::parse_str(&uuid).is_ok());
+        assert_ne!(eval_function("newUuid"), eval_function("newUuid"));
+
+        assert!(matches!(eval_function("newDate"), Some(Value::Date(_))));
+
+        let Some(Value::Number(Number::Integer(n))) = eval_function("randomInt") else {
+            panic!("randomInt should return an integer");
+        };
+        assert!(n >= 0);
+
+        let Some(Value::String(s)) = eval_function("randomString") else {
+            panic!("randomString should return a string");
+        };
+        assert_eq!(s.len(), 16);
+        assert!(s.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        assert_eq!(eval_function("foo"), None);
+    }
+}
@@ -0,0 +1,132 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Unary gRPC calls of an entry with a `proto` option.
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::grpc::{self, Method, Proto};
+use crate::http;
+use crate::http::{Body, Header};
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+use crate::util::path::ContextDir;
+
+/// Content type of the gRPC requests.
+pub const GRPC_CONTENT_TYPE: &str = "application/grpc";
+
+/// Returns the definitions of the `.proto` file `filename` and of the files it imports.
+///
+/// Imports are resolved relatively to the directory of `filename`.
+pub fn eval_proto(
+    filename: &Template,
+    variables: &HashMap<String, Value>,
+    context_dir: &ContextDir,
+) -> Result<Proto, RunnerError> {
+    let source_info = filename.source_info;
+    let path = PathBuf::from(eval_template(filename, variables)?);
+    let dir = path.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+    let mut proto = Proto::default();
+    let mut files = vec![path];
+    let mut added = vec![];
+    while let Some(path) = files.pop() {
+        if added.contains(&path) {
+            continue;
+        }
+        if !context_dir.is_access_allowed(&path) {
+            let kind = RunnerErrorKind::UnauthorizedFileAccess { path };
+            return Err(RunnerError::new(source_info, kind, false));
+        }
+        let Ok(content) = std::fs::read_to_string(context_dir.resolved_path(&path)) else {
+            let kind = RunnerErrorKind::FileReadAccess { path };
+            return Err(RunnerError::new(source_info, kind, false));
+        };
+        let imports = proto.add_file(&content).map_err(|message| {
+            let message = format!("{}: {message}", path.display());
+            RunnerError::new(source_info, RunnerErrorKind::Grpc { message }, false)
+        })?;
+        files.extend(imports.iter().map(|import| dir.join(import)));
+        added.push(path);
+    }
+    proto.resolve().map_err(|message| {
+        RunnerError::new(source_info, RunnerErrorKind::Grpc { message }, false)
+    })?;
+    Ok(proto)
+}
+
+/// Converts the `request` to a unary gRPC call, and returns the called method.
+///
+/// The method is given by the URL path (`/package.Service/Method`), and the JSON body of the
+/// request is encoded to the method input message.
+pub fn eval_grpc_request(
+    request: &mut http::RequestSpec,
+    proto: &Proto,
+    source_info: SourceInfo,
+) -> Result<Method, RunnerError> {
+    let error =
+        |message: String| RunnerError::new(source_info, RunnerErrorKind::Grpc { message }, false);
+    let path = match url::Url::parse(&request.url) {
+        Ok(url) => url.path().to_string(),
+        Err(_) => String::new(),
+    };
+    let Some(method) = proto.method(&path) else {
+        return Err(error(format!("no method <{path}> in proto files")));
+    };
+    if method.streaming {
+        return Err(error(format!("streaming method <{path}> is not supported")));
+    }
+    // The body can be a JSON body, a string or a file: without body, the message is empty.
+    let body = request.body.bytes();
+    let json = if body.iter().all(|b| b.is_ascii_whitespace()) {
+        serde_json::Value::Object(Default::default())
+    } else {
+        serde_json::from_slice(&body)
+            .map_err(|_| error("request body is not a valid JSON message".to_string()))?
+    };
+    let message = proto
+        .encode(&method.input, &json)
+        .map_err(|e| error(format!("invalid message <{}>, {e}", method.input)))?;
+    request.body = Body::Binary(grpc::frame(&message));
+    request.implicit_content_type = Some(GRPC_CONTENT_TYPE.to_string());
+    request.headers.push(Header::new("te", "trailers"));
+    Ok(method.clone())
+}
+
+/// Decodes the gRPC message of the `response` body to JSON, with the output message of `method`.
+///
+/// Responses without body (errors are described in the `grpc-status` and `grpc-message`
+/// headers) are left as is.
+pub fn decode_grpc_response(
+    response: &mut http::Response,
+    proto: &Proto,
+    method: &Method,
+    source_info: SourceInfo,
+) -> Result<(), RunnerError> {
+    if response.body.is_empty() {
+        return Ok(());
+    }
+    let json = grpc::unframe(&response.body)
+        .and_then(|message| proto.decode(&method.output, message))
+        .map_err(|e| {
+            let message = format!("invalid response message <{}>, {e}", method.output);
+            RunnerError::new(source_info, RunnerErrorKind::Grpc { message }, false)
+        })?;
+    response.body = json.to_string().into_bytes();
+    Ok(())
+}
@@ -15,22 +15,24 @@
  * limitations under the License.
  *
  */
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::thread;
 use std::time::Instant;
 
 use chrono::Utc;
+use hurl_core::ast::visit::{self, Visitor};
 use hurl_core::ast::VersionValue::VersionAnyLegacy;
-use hurl_core::ast::{Entry, OptionKind, SourceInfo};
+use hurl_core::ast::{Entry, EntryOption, Expr, OptionKind, SourceInfo};
 use hurl_core::error::DisplaySourceError;
 use hurl_core::input::Input;
 use hurl_core::parser;
 use hurl_core::typing::Count;
 
-use crate::http::{Call, Client};
+use crate::http::{Call, Client, HttpClient};
 use crate::runner::event::EventListener;
 use crate::runner::runner_options::RunnerOptions;
-use crate::runner::{entry, options, EntryResult, HurlResult, Value};
+use crate::runner::template::eval_template;
+use crate::runner::{entry, oauth2, options, EntryResult, HurlResult, RunnerError, Value};
 use crate::util::logger::{ErrorFormat, Logger, LoggerOptions};
 use crate::util::term::{Stderr, Stdout, WriteMode};
 
@@ -88,6 +90,58 @@ pub fn run(
     runner_options: &RunnerOptions,
     variables: &HashMap<String, Value>,
     logger_options: &LoggerOptions,
+) -> Result<HurlResult, String> {
+    let mut http_client = Client::new();
+    run_with_client(
+        content,
+        filename,
+        &mut http_client,
+        runner_options,
+        variables,
+        None,
+        logger_options,
+    )
+}
+
+/// Runs a Hurl `content` and returns a [`HurlResult`] upon completion, reporting run events to
+/// `listener`.
+///
+/// This function behaves like [`run`], entries, requests, responses and asserts being reported to
+/// `listener` while the file is running (see [`EventListener`]).
+pub fn run_with_listener(
+    content: &str,
+    filename: Option<&Input>,
+    runner_options: &RunnerOptions,
+    variables: &HashMap<String, Value>,
+    listener: &dyn EventListener,
+    logger_options: &LoggerOptions,
+) -> Result<HurlResult, String> {
+    let mut http_client = Client::new();
+    run_with_client(
+        content,
+        filename,
+        &mut http_client,
+        runner_options,
+        variables,
+        Some(listener),
+        logger_options,
+    )
+}
+
+/// Runs a Hurl `content` with a custom `http_client` and returns a [`HurlResult`] upon completion.
+///
+/// This function behaves like [`run`], but the HTTP requests are executed by `http_client`
+/// instead of the libcurl client of Hurl: it can be used to run a Hurl file against a mock
+/// transport. Run events (entries, requests, responses and asserts) are reported to an optional
+/// `listener`.
+pub fn run_with_client(
+    content: &str,
+    filename: Option<&Input>,
+    http_client: &mut dyn HttpClient,
+    runner_options: &RunnerOptions,
+    variables: &HashMap<String, Value>,
+    listener: Option<&dyn EventListener>,
+    logger_options: &LoggerOptions,
 ) -> Result<HurlResult, String> {
     // In this method, we run Hurl content sequentially. Standard output and standard error messages
     // are written immediately (in parallel mode, we'll use buffered standard output and error).
@@ -109,14 +163,15 @@ pub fn run(
     };
 
     // Now, we have a syntactically correct HurlFile instance, we can run it.
-    let result = run_entries(
+    let result = run_entries_with_client(
         &hurl_file.entries,
         content,
         filename,
+        http_client,
         runner_options,
         variables,
         &mut stdout,
-        None,
+        listener,
         &mut logger,
     );
 
@@ -146,10 +201,39 @@ pub fn run_entries(
     logger: &mut Logger,
 ) -> HurlResult {
     let mut http_client = Client::new();
+    run_entries_with_client(
+        entries,
+        content,
+        filename,
+        &mut http_client,
+        runner_options,
+        variables,
+        stdout,
+        listener,
+        logger,
+    )
+}
+
+/// Runs a list of `entries` with `http_client` and returns a [`HurlResult`] upon completion.
+#[allow(clippy::too_many_arguments)]
+fn run_entries_with_client(
+    entries: &[Entry],
+    content: &str,
+    filename: Option<&Input>,
+    http_client: &mut dyn HttpClient,
+    runner_options: &RunnerOptions,
+    variables: &HashMap<String, Value>,
+    stdout: &mut Stdout,
+    listener: Option<&dyn EventListener>,
+    logger: &mut Logger,
+) -> HurlResult {
     let mut entries_result = vec![];
     let mut variables = variables.clone();
     let mut entry_index = runner_options.from_entry.unwrap_or(1);
     let mut repeat_count = 0;
+    let mut oauth2_token: Option<oauth2::Token> = None;
+    // Variables that should have been captured by failed entries.
+    let mut missing_captures = HashSet::new();
     let n = runner_options.to_entry.unwrap_or(entries.len());
     let default_verbosity = logger.verbosity;
     let start = Instant::now();
@@ -190,7 +274,30 @@ pub fn run_entries(
 
         // We can report the progression of the run for --test mode.
         if let Some(listener) = listener {
-            listener.on_running(entry_index - 1, n);
+            listener.on_entry_started(entry_index - 1, n);
+        }
+
+        // When we continue on error, entries using a variable that a failed entry should have
+        // captured are skipped instead of failing.
+        if runner_options.continue_on_error {
+            if let Some(name) = missing_variable(entry, &missing_captures) {
+                logger.warning(&format!(
+                    "Entry {entry_index} has been skipped, variable <{name}> has not been captured"
+                ));
+                let entry_result = EntryResult {
+                    entry_index,
+                    source_info: entry.source_info(),
+                    skipped: true,
+                    ..Default::default()
+                };
+                if let Some(listener) = listener {
+                    listener.on_entry_finished(entry_index - 1, &entry_result);
+                }
+                entries_result.push(entry_result);
+                update_missing_captures(entry, &variables, &mut missing_captures);
+                entry_index += 1;
+                continue;
+            }
         }
 
         // The real execution of the entry happens here, first: we compute the overridden request
@@ -206,6 +313,9 @@ pub fn run_entries(
                 ..Default::default()
             };
             log_errors(&entry_result, content, filename, false, logger);
+            if let Some(listener) = listener {
+                listener.on_entry_finished(entry_index - 1, &entry_result);
+            }
             entries_result.push(entry_result);
             if runner_options.continue_on_error {
                 entry_index += 1;
@@ -215,7 +325,7 @@ pub fn run_entries(
             }
         }
 
-        let options = options.unwrap();
+        let mut options = options.unwrap();
 
         // Should we skip?
         if options.skip {
@@ -242,6 +352,36 @@ pub fn run_entries(
             thread::sleep(delay);
         };
 
+        // Should we fetch an OAuth2 access token? The token is reused by the next entries until
+        // it expires.
+        if let (Some(token_url), Some(client)) = (&options.oauth2_token_url, &options.oauth2_client)
+        {
+            if oauth2_token.as_ref().map_or(true, |t| t.is_expired()) {
+                match oauth2::fetch_token(token_url, client, http_client, &options, logger) {
+                    Ok(token) => oauth2_token = Some(token),
+                    Err(kind) => {
+                        let source_info = entry.request.url.source_info;
+                        let error = RunnerError::new(source_info, kind, false);
+                        let entry_result = EntryResult {
+                            entry_index,
+                            source_info: entry.source_info(),
+                            errors: vec![error],
+                            ..Default::default()
+                        };
+                        log_errors(&entry_result, content, filename, false, logger);
+                        entries_result.push(entry_result);
+                        if runner_options.continue_on_error {
+                            entry_index += 1;
+                            continue;
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            }
+            options.oauth2_bearer = oauth2_token.as_ref().map(|t| t.access_token.clone());
+        }
+
         // Loop for executing HTTP run requests, with optional retry. Only "HTTP" errors in options
         // are taken into account for retry (errors while computing entry options and output error
         // are not retried).
@@ -250,16 +390,18 @@ pub fn run_entries(
             entry_index,
             content,
             filename,
-            &mut http_client,
+            http_client,
             &options,
             &mut variables,
             stdout,
+            listener,
             logger,
         );
 
         let has_error = results.last().map_or(false, |r| !r.errors.is_empty());
 
         entries_result.extend(results);
+        update_missing_captures(entry, &variables, &mut missing_captures);
 
         if let Some(post_entry) = runner_options.post_entry {
             let exit = post_entry();
@@ -303,6 +445,7 @@ pub fn run_entries(
         success,
         cookies,
         timestamp,
+        secrets: logger.secrets().to_vec(),
     }
 }
 
@@ -314,17 +457,26 @@ fn run_request(
     entry_index: usize,
     content: &str,
     filename: Option<&Input>,
-    http_client: &mut Client,
+    http_client: &mut dyn HttpClient,
     options: &RunnerOptions,
     variables: &mut HashMap<String, Value>,
     stdout: &mut Stdout,
+    listener: Option<&dyn EventListener>,
     logger: &mut Logger,
 ) -> Vec<EntryResult> {
     let mut results = vec![];
     let mut retry_count = 1;
 
     loop {
-        let mut result = entry::run(entry, entry_index, http_client, variables, options, logger);
+        let mut result = entry::run(
+            entry,
+            entry_index,
+            http_client,
+            variables,
+            options,
+            listener,
+            logger,
+        );
 
         // Check if we need to retry.
         let mut has_error = !result.errors.is_empty();
@@ -365,6 +517,13 @@ fn run_request(
         if has_error {
             log_errors(&result, content, filename, retry, logger);
         }
+        if let Some(listener) = listener {
+            result
+                .asserts
+                .iter()
+                .for_each(|a| listener.on_assert_evaluated(entry_index - 1, a));
+            listener.on_entry_finished(entry_index - 1, &result);
+        }
         results.push(result);
 
         // No retry, we leave the HTTP run requests loop.
@@ -430,6 +589,65 @@ fn is_success(entries: &[EntryResult]) -> bool {
     true
 }
 
+/// Updates the `missing` captures with the captures of `entry`, once executed or skipped: captures
+/// not in `variables` are missing, the others are not any more.
+fn update_missing_captures(
+    entry: &Entry,
+    variables: &HashMap<String, Value>,
+    missing: &mut HashSet<String>,
+) {
+    let Some(response) = &entry.response else {
+        return;
+    };
+    for capture in response.captures() {
+        let Ok(name) = eval_template(&capture.name, variables) else {
+            continue;
+        };
+        if variables.contains_key(&name) {
+            missing.remove(&name);
+        } else {
+            missing.insert(name);
+        }
+    }
+}
+
+/// Returns the first `missing` variable used by the `entry`.
+///
+/// The variables are looked up in the expressions of the entry, except in its `only-if` option:
+/// a condition can test that a variable has not been captured.
+fn missing_variable(entry: &Entry, missing: &HashSet<String>) -> Option<String> {
+    if missing.is_empty() {
+        return None;
+    }
+    let mut visitor = MissingVariable {
+        missing,
+        found: None,
+    };
+    visitor.visit_entry(entry);
+    visitor.found
+}
+
+/// Finds the first `missing` variable used in the expressions of an entry.
+struct MissingVariable<'a> {
+    missing: &'a HashSet<String>,
+    found: Option<String>,
+}
+
+impl Visitor for MissingVariable<'_> {
+    fn visit_entry_option(&mut self, option: &EntryOption) {
+        if !matches!(option.kind, OptionKind::OnlyIf(_)) {
+            visit::walk_entry_option(self, option);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        let name = &expr.variable.name;
+        if self.found.is_none() && self.missing.contains(name) {
+            self.found = Some(name.clone());
+        }
+    }
+}
+
 /// Logs deprecated syntax and provides alternatives.
 fn warn_deprecated(entry: &Entry, filename: Option<&Input>, logger: &mut Logger) {
     let filename = filename.map_or(String::new(), |f| f.to_string());
@@ -463,6 +681,12 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
         non_default_options.push(("delay", format!("{}ms", options.delay.as_millis() as u64)));
     }
 
+    if options.doh_url != default_options.doh_url {
+        if let Some(doh_url) = &options.doh_url {
+            non_default_options.push(("doh url", doh_url.to_string()));
+        }
+    }
+
     if options.follow_location != default_options.follow_location {
         non_default_options.push(("follow redirect", options.follow_location.to_string()));
     }
@@ -548,7 +772,11 @@ fn log_errors(
         }
     }
     entry_result.errors.iter().for_each(|error| {
-        logger.error_runtime_rich(content, filename, error, entry_result.source_info);
+        if error.assert && logger.warn_asserts {
+            logger.warning_runtime_rich(content, filename, error, entry_result.source_info);
+        } else {
+            logger.error_runtime_rich(content, filename, error, entry_result.source_info);
+        }
     });
 }
 
@@ -585,4 +813,45 @@ mod test {
         assert_eq!(first_non_default.0, "delay");
         assert_eq!(first_non_default.1, "500ms");
     }
+
+    #[test]
+    fn missing_variable_in_entry() {
+        let content = r#"POST http://localhost:8000/login
+HTTP 200
+[Captures]
+token: header "X-Token"
+
+GET http://localhost:8000/users
+Authorization: Bearer {{ token }}
+
+# Authorization: Bearer {{token}}
+GET http://localhost:8000/health?id={{id}}
+
+GET http://localhost:8000/login
+[Options]
+only-if: {{token}} not exists
+
+POST http://localhost:8000/users
+```json
+{"token": "{{token}}"}
+```
+"#;
+        let entries = parser::parse_hurl_file(content).unwrap().entries;
+        let missing = HashSet::from(["token".to_string()]);
+
+        assert_eq!(missing_variable(&entries[0], &missing), None);
+        assert_eq!(
+            missing_variable(&entries[1], &missing),
+            Some("token".to_string())
+        );
+        // Variables in comments are not used.
+        assert_eq!(missing_variable(&entries[2], &missing), None);
+        // A condition can test a missing variable.
+        assert_eq!(missing_variable(&entries[3], &missing), None);
+        assert_eq!(
+            missing_variable(&entries[4], &missing),
+            Some("token".to_string())
+        );
+        assert_eq!(missing_variable(&entries[1], &HashSet::new()), None);
+    }
 }
@@ -22,7 +22,7 @@ use hurl_core::parser::{parse_json_boolean, parse_json_null, parse_json_number};
 use hurl_core::reader::Reader;
 
 use crate::runner::error::{RunnerError, RunnerErrorKind};
-use crate::runner::template::render_expression;
+use crate::runner::template::{eval_expression, render_expression, render_expression_value};
 use crate::runner::value::Value;
 
 /// Evaluates a JSON value to a string given a set of `variables`.
@@ -66,10 +66,16 @@ pub fn eval_json_value(
             }
         }
         JsonValue::Expression(exp) => {
-            let s = render_expression(exp, variables)?;
+            // Lists and objects (captured with a JSONPath query for instance) are injected as
+            // JSON.
+            let value = eval_expression(exp, variables)?;
+            if let Value::List(_) | Value::Object(_) = value {
+                return Ok(to_json_string(&value));
+            }
+            let s = render_expression_value(exp, value)?;
 
-            // The String can only be null, a bool, a number
-            // It will be easier when your variables value have a type
+            // Other values are rendered, and the rendered string can only be null, a bool or a
+            // number.
             let mut reader = Reader::new(s.as_str());
             let start = reader.cursor();
             if parse_json_number(&mut reader).is_ok() {
@@ -89,6 +95,30 @@ pub fn eval_json_value(
     }
 }
 
+/// Serializes a `value` to a compact JSON string.
+///
+/// Object keys are serialized in the order of the value, and not sorted like a [`serde_json::Map`].
+/// Note that an object captured with a JSONPath query already has its keys sorted alphabetically.
+fn to_json_string(value: &Value) -> String {
+    match value {
+        Value::List(values) => {
+            let values = values.iter().map(to_json_string).collect::<Vec<_>>();
+            format!("[{}]", values.join(","))
+        }
+        Value::Object(key_values) => {
+            let key_values = key_values
+                .iter()
+                .map(|(key, value)| {
+                    let key = serde_json::Value::String(key.clone());
+                    format!("{key}:{}", to_json_string(value))
+                })
+                .collect::<Vec<_>>();
+            format!("{{{}}}", key_values.join(","))
+        }
+        _ => value.to_json().to_string(),
+    }
+}
+
 /// Evaluates a JSON list to a string given a set of `variables`.
 /// If `keep_whitespace` is true, whitespace is preserved from the JSonValue, otherwise
 /// it is trimmed.
@@ -182,6 +212,7 @@ mod tests {
 
     use super::super::error::RunnerErrorKind;
     use super::*;
+    use crate::runner::Number;
 
     pub fn json_hello_world_value() -> JsonValue {
         // "hello\u0020{{name}}!"
@@ -386,6 +417,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_expression_value() {
+        let expression = |name: &str| {
+            JsonValue::Expression(Expr {
+                space0: whitespace(),
+                variable: Variable {
+                    name: name.to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 7)),
+                },
+                space1: whitespace(),
+            })
+        };
+        let mut variables = HashMap::new();
+        variables.insert("age".to_string(), Value::Number(Number::Integer(42)));
+        variables.insert("strict".to_string(), Value::Bool(true));
+        variables.insert(
+            "ids".to_string(),
+            Value::List(vec![
+                Value::Number(Number::Integer(1)),
+                Value::String("two".to_string()),
+            ]),
+        );
+        variables.insert(
+            "user".to_string(),
+            Value::Object(vec![
+                ("name".to_string(), Value::String("Bob".to_string())),
+                ("admin".to_string(), Value::Bool(false)),
+            ]),
+        );
+        variables.insert("name".to_string(), Value::String("Bob".to_string()));
+
+        assert_eq!(
+            eval_json_value(&expression("age"), &variables, true).unwrap(),
+            "42"
+        );
+        assert_eq!(
+            eval_json_value(&expression("strict"), &variables, true).unwrap(),
+            "true"
+        );
+        assert_eq!(
+            eval_json_value(&expression("ids"), &variables, true).unwrap(),
+            r#"[1,"two"]"#
+        );
+        assert_eq!(
+            eval_json_value(&expression("user"), &variables, true).unwrap(),
+            r#"{"name":"Bob","admin":false}"#
+        );
+        let error = eval_json_value(&expression("name"), &variables, true)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::InvalidJson {
+                value: "Bob".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_escape_sequence() {
         let variables = HashMap::new();
@@ -19,11 +19,10 @@
 //! A runner for Hurl files. If you want to execute an Hurl file, this is the right place.
 
 pub use self::error::{RunnerError, RunnerErrorKind};
-#[doc(hidden)]
 pub use self::event::EventListener;
-pub use self::hurl_file::run;
 #[doc(hidden)]
 pub use self::hurl_file::run_entries;
+pub use self::hurl_file::{run, run_with_client, run_with_listener};
 pub use self::number::Number;
 pub use self::output::Output;
 pub use self::result::{AssertResult, CaptureResult, EntryResult, HurlResult};
@@ -40,11 +39,14 @@ mod error;
 mod event;
 mod expr;
 mod filter;
+mod function;
+mod grpc;
 mod hurl_file;
 mod json;
 mod multiline;
 mod multipart;
 mod number;
+mod oauth2;
 mod options;
 mod output;
 mod predicate;
@@ -57,4 +59,5 @@ mod result;
 mod runner_options;
 mod template;
 mod value;
+mod xml;
 mod xpath;
@@ -0,0 +1,166 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::time::{Duration, Instant};
+
+use crate::http::{ClientOptions, HttpClient, Method, Param, RequestSpec};
+use crate::runner::runner_options::RunnerOptions;
+use crate::runner::RunnerErrorKind;
+use crate::util::logger::Logger;
+
+/// An OAuth2 access token, obtained with the client credentials grant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token {
+    pub access_token: String,
+    /// Instant after which the token must be refreshed, `None` if the token doesn't expire.
+    pub expires_at: Option<Instant>,
+}
+
+impl Token {
+    /// Returns true if this token must be fetched again.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.map_or(false, |e| Instant::now() >= e)
+    }
+}
+
+/// Fetches an access token from `token_url` using the OAuth2 client credentials grant.
+///
+/// `client` has the format `CLIENT_ID:CLIENT_SECRET` and is sent with basic authentication (see
+/// <https://datatracker.ietf.org/doc/html/rfc6749#section-4.4>).
+pub fn fetch_token(
+    token_url: &str,
+    client: &str,
+    http_client: &mut dyn HttpClient,
+    runner_options: &RunnerOptions,
+    logger: &mut Logger,
+) -> Result<Token, RunnerErrorKind> {
+    // The client secret and the access token are redacted from the logs and reports.
+    if let Some((_, secret)) = client.split_once(':') {
+        logger.add_secret(secret);
+    }
+    let request_spec = RequestSpec {
+        method: Method("POST".to_string()),
+        url: token_url.to_string(),
+        form: vec![Param {
+            name: "grant_type".to_string(),
+            value: "client_credentials".to_string(),
+        }],
+        implicit_content_type: Some("application/x-www-form-urlencoded".to_string()),
+        ..Default::default()
+    };
+    // The token request is authenticated by the client credentials only.
+    let client_options = ClientOptions {
+        aws_sigv4: None,
+        digest: false,
+        ntlm: false,
+        oauth2_bearer: None,
+        user: Some(client.to_string()),
+        ..ClientOptions::from(runner_options, logger.verbosity)
+    };
+
+    logger.debug("");
+    logger.debug_important(&format!("Fetching OAuth2 token from {token_url}"));
+    let call = http_client
+        .execute(&request_spec, &client_options, logger)
+        .map_err(RunnerErrorKind::Http)?;
+    let response = &call.response;
+    if !(200..300).contains(&response.status) {
+        return Err(token_error(
+            token_url,
+            &format!("status code is {}", response.status),
+        ));
+    }
+    let body = response.text().map_err(RunnerErrorKind::Http)?;
+    let token =
+        parse_token(&body, Instant::now()).map_err(|message| token_error(token_url, &message))?;
+    logger.add_secret(&token.access_token);
+    Ok(token)
+}
+
+fn token_error(token_url: &str, message: &str) -> RunnerErrorKind {
+    RunnerErrorKind::OAuth2Token {
+        url: token_url.to_string(),
+        message: message.to_string(),
+    }
+}
+
+/// Parses a token endpoint JSON response `body`, received at `now`.
+fn parse_token(body: &str, now: Instant) -> Result<Token, String> {
+    let value = serde_json::from_str::<serde_json::Value>(body)
+        .map_err(|_| "response is not a valid JSON".to_string())?;
+    let Some(access_token) = value.get("access_token").and_then(|v| v.as_str()) else {
+        return Err("response has no access_token".to_string());
+    };
+    let expires_at = value
+        .get("expires_in")
+        .and_then(|v| v.as_u64())
+        .map(|secs| now + Duration::from_secs(secs));
+    Ok(Token {
+        access_token: access_token.to_string(),
+        expires_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_token() {
+        let now = Instant::now();
+        assert_eq!(
+            parse_token(
+                r#"{"access_token":"abc","token_type":"Bearer","expires_in":3600}"#,
+                now
+            )
+            .unwrap(),
+            Token {
+                access_token: "abc".to_string(),
+                expires_at: Some(now + Duration::from_secs(3600)),
+            }
+        );
+        assert_eq!(
+            parse_token(r#"{"access_token":"abc"}"#, now).unwrap(),
+            Token {
+                access_token: "abc".to_string(),
+                expires_at: None,
+            }
+        );
+        assert_eq!(
+            parse_token(r#"{"error":"invalid_client"}"#, now).unwrap_err(),
+            "response has no access_token"
+        );
+        assert_eq!(
+            parse_token("xxx", now).unwrap_err(),
+            "response is not a valid JSON"
+        );
+    }
+
+    #[test]
+    fn test_token_expired() {
+        let token = Token {
+            access_token: "abc".to_string(),
+            expires_at: None,
+        };
+        assert!(!token.is_expired());
+        let token = Token {
+            access_token: "abc".to_string(),
+            expires_at: Some(Instant::now()),
+        };
+        assert!(token.is_expired());
+    }
+}
@@ -18,12 +18,14 @@
 use std::collections::HashMap;
 
 use hurl_core::ast::{
-    BooleanOption, CountOption, DurationOption, Entry, EntryOption, Float, Number as AstNumber,
-    OptionKind, SectionValue, VariableDefinition, VariableValue,
+    BooleanOption, Condition, CountOption, DurationOption, Entry, EntryOption, Float,
+    Number as AstNumber, OptionKind, SectionValue, VariableDefinition, VariableValue, XmlNamespace,
 };
 use hurl_core::typing::{Count, DurationUnit};
 
 use crate::http::{IpResolve, RequestedHttpVersion};
+use crate::runner::grpc;
+use crate::runner::predicate::eval_predicate;
 use crate::runner::template::{eval_expression, eval_template};
 use crate::runner::{Number, Output, RunnerError, RunnerErrorKind, RunnerOptions, Value};
 use crate::util::logger::{Logger, Verbosity};
@@ -76,6 +78,10 @@ pub fn get_entry_options(
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.compressed = value;
                     }
+                    OptionKind::ConnectTimeout(value) => {
+                        let value = eval_duration_option(value, variables, DurationUnit::Second)?;
+                        entry_options.connect_timeout = value;
+                    }
                     OptionKind::ConnectTo(value) => {
                         let value = eval_template(value, variables)?;
                         entry_options.connects_to.push(value);
@@ -85,6 +91,10 @@ pub fn get_entry_options(
                             eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
                         entry_options.delay = value;
                     }
+                    OptionKind::Digest(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.digest = value;
+                    }
                     // HTTP version options (such as http1.0, http1.1, http2 etc...) are activated
                     // through a flag. In an `[Options]` section, the signification of such a flag is:
                     //
@@ -174,6 +184,10 @@ pub fn get_entry_options(
                         let value = eval_count_option(value, variables)?;
                         entry_options.max_redirect = value;
                     }
+                    OptionKind::MaxTime(value) => {
+                        let value = eval_duration_option(value, variables, DurationUnit::Second)?;
+                        entry_options.timeout = value;
+                    }
                     OptionKind::NetRc(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.netrc = value;
@@ -186,6 +200,16 @@ pub fn get_entry_options(
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.netrc_optional = value;
                     }
+                    OptionKind::Ntlm(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.ntlm = value;
+                    }
+                    OptionKind::OnlyIf(condition) => {
+                        let value = eval_condition(condition, variables, &entry_options)?;
+                        if !value {
+                            entry_options.skip = true;
+                        }
+                    }
                     OptionKind::Output(output) => {
                         let filename = eval_template(output, variables)?;
                         let output = Output::new(&filename);
@@ -195,6 +219,11 @@ pub fn get_entry_options(
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.path_as_is = value;
                     }
+                    OptionKind::Proto(filename) => {
+                        let proto =
+                            grpc::eval_proto(filename, variables, &entry_options.context_dir)?;
+                        entry_options.proto = Some(proto);
+                    }
                     OptionKind::Proxy(value) => {
                         let value = eval_template(value, variables)?;
                         entry_options.proxy = Some(value);
@@ -220,6 +249,15 @@ pub fn get_entry_options(
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.skip = value;
                     }
+                    OptionKind::StreamCount(value) => {
+                        let value = eval_count_option(value, variables)?;
+                        entry_options.stream_count = value;
+                    }
+                    OptionKind::StreamTimeout(value) => {
+                        let value =
+                            eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
+                        entry_options.stream_timeout = Some(value);
+                    }
                     OptionKind::UnixSocket(value) => {
                         let value = eval_template(value, variables)?;
                         entry_options.unix_socket = Some(value);
@@ -241,6 +279,10 @@ pub fn get_entry_options(
                     OptionKind::VeryVerbose(value) => {
                         eval_boolean_option(value, variables)?;
                     }
+                    OptionKind::XmlNamespace(XmlNamespace { prefix, uri, .. }) => {
+                        let uri = eval_template(uri, variables)?;
+                        entry_options.xml_namespaces.push((prefix.clone(), uri));
+                    }
                 }
                 log_option(option, logger);
             }
@@ -321,6 +363,33 @@ fn eval_boolean_option(
     }
 }
 
+/// Evaluates a `condition` with `variables`: the condition is true if its predicate is satisfied
+/// by the value of its expression. An undefined variable has no value, so `{{name}} exists` can
+/// be used to test if a variable is defined.
+fn eval_condition(
+    condition: &Condition,
+    variables: &HashMap<String, Value>,
+    runner_options: &RunnerOptions,
+) -> Result<bool, RunnerError> {
+    let value = match eval_expression(&condition.expr, variables) {
+        Ok(value) => Some(value),
+        Err(RunnerError {
+            kind: RunnerErrorKind::TemplateVariableNotDefined { .. },
+            ..
+        }) => None,
+        Err(error) => return Err(error),
+    };
+    let context_dir = &runner_options.context_dir;
+    match eval_predicate(&condition.predicate, variables, &value, context_dir) {
+        Ok(_) => Ok(true),
+        Err(RunnerError {
+            kind: RunnerErrorKind::AssertFailure { .. },
+            ..
+        }) => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
 fn eval_count_option(
     count_value: &CountOption,
     variables: &HashMap<String, Value>,
@@ -17,9 +17,11 @@
  */
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 use hurl_core::ast::*;
 use hurl_core::reader::Pos;
+use lazy_static::lazy_static;
 
 use crate::runner::error::RunnerError;
 use crate::runner::predicate_value::{eval_predicate_value, eval_predicate_value_template};
@@ -229,6 +231,7 @@ fn expected_no_value(
             let expected = eval_predicate_value_template(expected, variables)?;
             Ok(format!("matches regex <{expected}>"))
         }
+        PredicateFuncValue::MatchSchema { .. } => Ok("matches JSON schema".to_string()),
         PredicateFuncValue::IsInteger => Ok("integer".to_string()),
         PredicateFuncValue::IsFloat => Ok("float".to_string()),
         PredicateFuncValue::IsBoolean => Ok("boolean".to_string()),
@@ -298,6 +301,15 @@ fn eval_predicate_func(
         PredicateFuncValue::Match {
             value: expected, ..
         } => eval_match(expected, predicate_func.source_info, variables, value),
+        PredicateFuncValue::MatchSchema {
+            value: expected, ..
+        } => eval_match_schema(
+            expected,
+            predicate_func.source_info,
+            variables,
+            value,
+            context_dir,
+        ),
         PredicateFuncValue::IsInteger => eval_is_integer(value),
         PredicateFuncValue::IsFloat => eval_is_float(value),
         PredicateFuncValue::IsBoolean => eval_is_boolean(value),
@@ -530,6 +542,103 @@ fn eval_match(
     }
 }
 
+/// Evaluates if an `actual` value is valid against an `expected` JSON schema (draft 2020-12).
+///
+/// The schema can be inlined in a string or read from a file. If `actual` is a string or bytes, it
+/// is parsed as a JSON document (typically with the `body` query); a string or bytes that is not a
+/// JSON document is a type mismatch.
+fn eval_match_schema(
+    expected: &PredicateValue,
+    source_info: SourceInfo,
+    variables: &HashMap<String, Value>,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<AssertResult, RunnerError> {
+    let schema_error = |message: String| {
+        RunnerError::new(
+            source_info,
+            RunnerErrorKind::InvalidJsonSchema { message },
+            false,
+        )
+    };
+    let schema = match eval_predicate_value(expected, variables, context_dir)? {
+        Value::String(s) => serde_json::from_str::<serde_json::Value>(&s),
+        Value::Bytes(b) => serde_json::from_slice::<serde_json::Value>(&b),
+        _ => panic!("expect a string or file predicate value"), // should have failed in parsing
+    };
+    let schema = schema.map_err(|e| schema_error(e.to_string()))?;
+    let schema = compile_schema(&schema).map_err(schema_error)?;
+
+    let expected_display = "matches JSON schema".to_string();
+    let instance = match actual {
+        Value::String(s) => serde_json::from_str::<serde_json::Value>(s).ok(),
+        Value::Bytes(b) => serde_json::from_slice::<serde_json::Value>(b).ok(),
+        Value::Bool(_) | Value::List(_) | Value::Null | Value::Number(_) | Value::Object(_) => {
+            Some(actual.to_json())
+        }
+        _ => None,
+    };
+    let Some(instance) = instance else {
+        return Ok(AssertResult {
+            success: false,
+            actual: actual.display(),
+            expected: expected_display,
+            type_mismatch: true,
+        });
+    };
+
+    let violations = match schema.validate(&instance) {
+        Ok(_) => vec![],
+        Err(errors) => errors
+            .map(|e| {
+                let path = e.instance_path.to_string();
+                let path = if path.is_empty() { "(root)" } else { &path };
+                format!("{path}: {e}")
+            })
+            .collect::<Vec<_>>(),
+    };
+    let success = violations.is_empty();
+    let actual_display = if success {
+        "valid JSON".to_string()
+    } else {
+        // Each violation is aligned with the actual value in the error message ("   actual:   ").
+        let indent = format!("\n{}", " ".repeat(13));
+        format!(
+            "JSON with schema violations:{indent}{}",
+            violations.join(&indent)
+        )
+    };
+    Ok(AssertResult {
+        success,
+        actual: actual_display,
+        expected: expected_display,
+        type_mismatch: false,
+    })
+}
+
+lazy_static! {
+    /// Compiled JSON schemas, indexed by their JSON source, so a schema used by several asserts,
+    /// retries or repeated runs is only compiled once.
+    static ref JSON_SCHEMAS: Mutex<HashMap<String, Arc<jsonschema::JSONSchema>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Compiles a JSON `schema` (draft 2020-12), reusing a previous compilation of the same schema.
+fn compile_schema(schema: &serde_json::Value) -> Result<Arc<jsonschema::JSONSchema>, String> {
+    let key = schema.to_string();
+    let mut schemas = JSON_SCHEMAS.lock().unwrap();
+    if let Some(compiled) = schemas.get(&key) {
+        return Ok(Arc::clone(compiled));
+    }
+    let compiled = jsonschema::JSONSchema::options()
+        .with_draft(jsonschema::Draft::Draft202012)
+        .compile(schema)
+        .map_err(|e| e.to_string())?;
+    let compiled = Arc::new(compiled);
+    schemas.insert(key, Arc::clone(&compiled));
+    Ok(compiled)
+}
+
 /// Evaluates if an `actual` value is an integer.
 fn eval_is_integer(actual: &Value) -> Result<AssertResult, RunnerError> {
     Ok(AssertResult {
@@ -896,6 +1005,11 @@ fn compare_values(actual: &Value, expected: &Value) -> Option<Ordering> {
     match (actual, expected) {
         (Value::Number(number1), Value::Number(number2)) => Some(number1.cmp_value(number2)),
         (Value::String(s1), Value::String(s2)) => Some(s1.cmp(s2)),
+        (Value::Date(date1), Value::Date(date2)) => Some(date1.cmp(date2)),
+        // A date can be compared to a RFC 3339 string, like `2024-01-31T12:00:00Z`.
+        (Value::Date(date1), Value::String(s2)) => chrono::DateTime::parse_from_rfc3339(s2)
+            .ok()
+            .map(|date2| date1.cmp(&date2.with_timezone(&chrono::Utc))),
         _ => None,
     }
 }
@@ -1353,6 +1467,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_assert_value_greater_date() {
+        let date = Value::Date(
+            chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2024, 6, 16, 10, 10, 10).unwrap(),
+        );
+        let other_date = Value::Date(
+            chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2024, 1, 1, 0, 0, 0).unwrap(),
+        );
+        assert_eq!(
+            assert_values_greater(&date, &other_date),
+            AssertResult {
+                success: true,
+                type_mismatch: false,
+                actual: "date <2024-06-16 10:10:10 UTC>".to_string(),
+                expected: "greater than date <2024-01-01 00:00:00 UTC>".to_string(),
+            }
+        );
+        assert!(!assert_values_less(&date, &other_date).success);
+        assert!(assert_values_greater_or_equal(&date, &date).success);
+
+        // Dates can be compared to RFC 3339 strings, with any time zone offset.
+        let result = assert_values_less(
+            &date,
+            &Value::String("2024-06-16T12:10:11+02:00".to_string()),
+        );
+        assert!(result.success);
+        assert!(!result.type_mismatch);
+        let result =
+            assert_values_less_or_equal(&date, &Value::String("2024-06-16T10:10:09Z".to_string()));
+        assert!(!result.success);
+        assert!(!result.type_mismatch);
+
+        let result = assert_values_greater(&date, &Value::String("yesterday".to_string()));
+        assert!(!result.success);
+        assert!(result.type_mismatch);
+    }
+
     #[test]
     fn test_predicate_is_empty_are_false() {
         // predicate: `isEmpty`
@@ -1577,6 +1728,83 @@ mod tests {
         assert_eq!(assert_result.expected, "matches regex <a{3}>");
     }
 
+    #[test]
+    fn test_predicate_match_schema() {
+        let variables = HashMap::new();
+        let context_dir = ContextDir::default();
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let schema = r#"{
+            "type": "object",
+            "properties": { "id": { "type": "integer" }, "name": { "type": "string" } },
+            "required": ["id"]
+        }"#;
+        let expected = PredicateValue::String(Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: schema.to_string(),
+                encoded: schema.to_string(),
+            }],
+            source_info,
+        });
+
+        let value = Value::String(r#"{"id": 1, "name": "Bob"}"#.to_string());
+        let res =
+            eval_match_schema(&expected, source_info, &variables, &value, &context_dir).unwrap();
+        assert!(res.success);
+        assert!(!res.type_mismatch);
+        assert_eq!(res.actual, "valid JSON");
+        assert_eq!(res.expected, "matches JSON schema");
+
+        let value = Value::String(r#"{"id": "a", "name": 2}"#.to_string());
+        let res =
+            eval_match_schema(&expected, source_info, &variables, &value, &context_dir).unwrap();
+        assert!(!res.success);
+        assert!(!res.type_mismatch);
+        assert!(res.actual.contains(r#"/id: "a" is not of type "integer""#));
+        assert!(res.actual.contains(r#"/name: 2 is not of type "string""#));
+
+        let value = Value::Null;
+        let res =
+            eval_match_schema(&expected, source_info, &variables, &value, &context_dir).unwrap();
+        assert!(!res.success);
+        assert_eq!(
+            res.actual,
+            "JSON with schema violations:\n             (root): null is not of type \"object\""
+        );
+
+        let value = Value::Nodeset(1);
+        let res =
+            eval_match_schema(&expected, source_info, &variables, &value, &context_dir).unwrap();
+        assert!(res.type_mismatch);
+
+        let value = Value::String("not json".to_string());
+        let res =
+            eval_match_schema(&expected, source_info, &variables, &value, &context_dir).unwrap();
+        assert!(!res.success);
+        assert!(res.type_mismatch);
+
+        let value = Value::Bytes(b"not json".to_vec());
+        let res =
+            eval_match_schema(&expected, source_info, &variables, &value, &context_dir).unwrap();
+        assert!(!res.success);
+        assert!(res.type_mismatch);
+
+        let expected = PredicateValue::String(Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: "{".to_string(),
+                encoded: "{".to_string(),
+            }],
+            source_info,
+        });
+        let error = eval_match_schema(&expected, source_info, &variables, &value, &context_dir)
+            .unwrap_err();
+        assert!(matches!(
+            error.kind,
+            RunnerErrorKind::InvalidJsonSchema { .. }
+        ));
+    }
+
     #[test]
     fn test_predicate_is_iso_date() {
         let value = Value::String("2020-03-09T22:18:26.625Z".to_string());
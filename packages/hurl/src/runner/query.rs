@@ -31,15 +31,21 @@ use crate::runner::{filter, Number, Value};
 pub type QueryResult = Result<Option<Value>, RunnerError>;
 
 /// Evaluates this `query` and returns a [`QueryResult`], using the HTTP `response` and `variables`.
+///
+/// `redirects` is the list of URLs that have been followed before getting this `response`, and
+/// `timings` the timings of the exchange.
 pub fn eval_query(
     query: &Query,
     variables: &HashMap<String, Value>,
     response: &http::Response,
+    redirects: &[http::Url],
+    timings: &http::Timings,
     cache: &mut BodyCache,
 ) -> QueryResult {
     match &query.value {
         QueryValue::Status => eval_query_status(response),
         QueryValue::Url => eval_query_url(response),
+        QueryValue::Redirects => eval_query_redirects(redirects),
         QueryValue::Header { name, .. } => eval_query_header(response, name, variables),
         QueryValue::Cookie {
             expr: CookiePath { name, attribute },
@@ -57,9 +63,13 @@ pub fn eval_query(
         }
         QueryValue::Variable { name, .. } => eval_query_variable(name, variables),
         QueryValue::Duration => eval_query_duration(response),
+        QueryValue::Timing { attribute_name, .. } => eval_query_timing(timings, *attribute_name),
         QueryValue::Bytes => eval_query_bytes(response, query.source_info),
         QueryValue::Sha256 => eval_query_sha256(response, query.source_info),
+        QueryValue::Sha1 => eval_query_sha1(response, query.source_info),
         QueryValue::Md5 => eval_query_md5(response, query.source_info),
+        QueryValue::Frames => eval_query_frames(response),
+        QueryValue::Events => eval_query_events(response),
         QueryValue::Certificate {
             attribute_name: field,
             ..
@@ -79,6 +89,50 @@ fn eval_query_url(response: &http::Response) -> QueryResult {
     Ok(Some(Value::String(response.url.to_string())))
 }
 
+/// Evaluates the list of URLs followed by redirection, from the first to the last.
+fn eval_query_redirects(redirects: &[http::Url]) -> QueryResult {
+    let values = redirects
+        .iter()
+        .map(|url| Value::String(url.to_string()))
+        .collect();
+    Ok(Some(Value::List(values)))
+}
+
+/// Evaluates the frames received from a WebSocket: text frames are strings and binary frames are
+/// bytes.
+fn eval_query_frames(response: &http::Response) -> QueryResult {
+    let values = response
+        .frames
+        .iter()
+        .map(|frame| match frame {
+            http::Frame::Text(text) => Value::String(text.clone()),
+            http::Frame::Binary(bytes) => Value::Bytes(bytes.clone()),
+        })
+        .collect();
+    Ok(Some(Value::List(values)))
+}
+
+/// Evaluates the server-sent events received in the `response`, as a list of objects with
+/// `event`, `data` and `id` fields.
+fn eval_query_events(response: &http::Response) -> QueryResult {
+    let values = response
+        .events
+        .iter()
+        .map(|event| {
+            let id = match &event.id {
+                Some(id) => Value::String(id.clone()),
+                None => Value::Null,
+            };
+            Value::Object(vec![
+                ("event".to_string(), Value::String(event.event.clone())),
+                ("data".to_string(), Value::String(event.data.clone())),
+                ("id".to_string(), id),
+            ])
+        })
+        .collect();
+    Ok(Some(Value::List(values)))
+}
+
 /// Evaluates a response query header `name`, on the HTTP `response` given a set of `variables`.
 fn eval_query_header(
     response: &http::Response,
@@ -179,13 +233,16 @@ fn parse_cache_xml<'cache>(
     } else {
         Format::Xml
     };
-    let Ok(doc) = Document::parse(&text, format) else {
+    let Ok(mut doc) = Document::parse(&text, format) else {
         return Err(RunnerError::new(
             query_source_info,
             RunnerErrorKind::QueryInvalidXml,
             false,
         ));
     };
+    for (prefix, uri) in cache.xml_namespaces() {
+        doc.add_namespace(prefix, uri);
+    }
     // Everything is ok, we can put the response in the cache
     cache.set_xml(doc);
     Ok(cache.xml().unwrap())
@@ -304,6 +361,21 @@ fn eval_query_duration(response: &http::Response) -> QueryResult {
     ))))
 }
 
+/// Evaluates the timing `attribute_name` of the exchange, in milliseconds.
+fn eval_query_timing(timings: &http::Timings, attribute_name: TimingAttributeName) -> QueryResult {
+    let duration = match attribute_name {
+        TimingAttributeName::NameLookup => timings.name_lookup,
+        TimingAttributeName::Connect => timings.connect,
+        TimingAttributeName::AppConnect => timings.app_connect,
+        TimingAttributeName::PreTransfer => timings.pre_transfer,
+        TimingAttributeName::StartTransfer => timings.start_transfer,
+        TimingAttributeName::Total => timings.total,
+    };
+    Ok(Some(Value::Number(Number::Integer(
+        duration.as_millis() as i64
+    ))))
+}
+
 /// Evaluates the HTTP `response` body as bytes.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -339,6 +411,27 @@ fn eval_query_sha256(response: &http::Response, query_source_info: SourceInfo) -
     Ok(Some(bytes))
 }
 
+/// Evaluates the SHA-1 hash of the HTTP `response` body bytes.
+///
+/// `query_source_info` is the source position of the query, used if an error is returned.
+fn eval_query_sha1(response: &http::Response, query_source_info: SourceInfo) -> QueryResult {
+    let bytes = match response.uncompress_body() {
+        Ok(s) => s,
+        Err(inner) => {
+            return Err(RunnerError::new(
+                query_source_info,
+                RunnerErrorKind::Http(inner),
+                false,
+            ));
+        }
+    };
+    let mut hasher = sha1::Sha1::new();
+    hasher.update(bytes);
+    let result = hasher.finalize();
+    let bytes = Value::Bytes(result[..].to_vec());
+    Ok(Some(bytes))
+}
+
 /// Evaluates the MD-5 hash of the HTTP `response` body bytes.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -465,6 +558,9 @@ pub mod tests {
             duration: Default::default(),
             url: "http://localhost".parse().unwrap(),
             certificate: None,
+            frames: vec![],
+            events: vec![],
+            decoded_body: Default::default(),
         }
     }
 
@@ -678,6 +774,8 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache,
             )
             .unwrap()
@@ -686,6 +784,97 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_redirects() {
+        let variables = HashMap::new();
+        let mut cache = BodyCache::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::Redirects,
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &http::hello_http_response(),
+                &[],
+                &http::Timings::default(),
+                &mut cache,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![])
+        );
+
+        let redirects = vec![
+            "http://localhost:8000/redirect-relative".parse().unwrap(),
+            "http://localhost:8000/hello".parse().unwrap(),
+        ];
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &http::hello_http_response(),
+                &redirects,
+                &http::Timings::default(),
+                &mut cache,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::String("http://localhost:8000/redirect-relative".to_string()),
+                Value::String("http://localhost:8000/hello".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_query_timing() {
+        let variables = HashMap::new();
+        let mut cache = BodyCache::new();
+        let timings = http::Timings {
+            name_lookup: std::time::Duration::from_micros(1_500),
+            start_transfer: std::time::Duration::from_millis(120),
+            ..Default::default()
+        };
+        let query = |attribute_name| Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::Timing {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                attribute_name,
+            },
+        };
+        assert_eq!(
+            eval_query(
+                &query(TimingAttributeName::NameLookup),
+                &variables,
+                &http::hello_http_response(),
+                &[],
+                &timings,
+                &mut cache,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(1))
+        );
+        assert_eq!(
+            eval_query(
+                &query(TimingAttributeName::StartTransfer),
+                &variables,
+                &http::hello_http_response(),
+                &[],
+                &timings,
+                &mut cache,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(120))
+        );
+    }
+
     #[test]
     fn test_header_not_found() {
         let variables = HashMap::new();
@@ -717,6 +906,8 @@ pub mod tests {
                 &query_header,
                 &variables,
                 &http::hello_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache
             )
             .unwrap(),
@@ -752,6 +943,8 @@ pub mod tests {
                 &query_header,
                 &variables,
                 &http::hello_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache
             )
             .unwrap()
@@ -796,9 +989,16 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &query,
+                &variables,
+                &response,
+                &[],
+                &http::Timings::default(),
+                &mut cache
+            )
+            .unwrap()
+            .unwrap(),
             Value::String("DQAAAKEaem_vYg".to_string())
         );
 
@@ -825,9 +1025,16 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &query,
+                &variables,
+                &response,
+                &[],
+                &http::Timings::default(),
+                &mut cache
+            )
+            .unwrap()
+            .unwrap(),
             Value::String("/accounts".to_string())
         );
 
@@ -854,9 +1061,16 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &query,
+                &variables,
+                &response,
+                &[],
+                &http::Timings::default(),
+                &mut cache
+            )
+            .unwrap()
+            .unwrap(),
             Value::Unit
         );
 
@@ -883,7 +1097,15 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache).unwrap(),
+            eval_query(
+                &query,
+                &variables,
+                &response,
+                &[],
+                &http::Timings::default(),
+                &mut cache
+            )
+            .unwrap(),
             None
         );
     }
@@ -983,6 +1205,8 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache,
             )
             .unwrap()
@@ -996,6 +1220,8 @@ pub mod tests {
             },
             &variables,
             &http::bytes_http_response(),
+            &[],
+            &http::Timings::default(),
             &mut cache,
         )
         .err()
@@ -1021,9 +1247,16 @@ pub mod tests {
             body: vec![200],
             ..default_response()
         };
-        let error = eval_query(&xpath_users(), &variables, &http_response, &mut cache)
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &xpath_users(),
+            &variables,
+            &http_response,
+            &[],
+            &http::Timings::default(),
+            &mut cache,
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
         assert_eq!(
             error.kind,
@@ -1060,6 +1293,8 @@ pub mod tests {
             &query,
             &variables,
             &http::xml_two_users_http_response(),
+            &[],
+            &http::Timings::default(),
             &mut cache,
         )
         .unwrap_err();
@@ -1077,6 +1312,8 @@ pub mod tests {
                 &xpath_users(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache,
             )
             .unwrap()
@@ -1088,6 +1325,8 @@ pub mod tests {
                 &xpath_count_user_query(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache,
             )
             .unwrap()
@@ -1129,6 +1368,8 @@ pub mod tests {
                 &xpath_html_charset(),
                 &variables,
                 &http::html_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache,
             )
             .unwrap()
@@ -1165,6 +1406,8 @@ pub mod tests {
             &jsonpath_query,
             &variables,
             &http::json_http_response(),
+            &[],
+            &http::Timings::default(),
             &mut cache,
         )
         .unwrap_err();
@@ -1191,9 +1434,16 @@ pub mod tests {
             body: String::into_bytes(String::from("xxx")),
             ..default_response()
         };
-        let error = eval_query(&jsonpath_success(), &variables, &http_response, &mut cache)
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &jsonpath_success(),
+            &variables,
+            &http_response,
+            &[],
+            &http::Timings::default(),
+            &mut cache,
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
         assert_eq!(error.kind, RunnerErrorKind::QueryInvalidJson);
     }
@@ -1208,7 +1458,15 @@ pub mod tests {
             ..default_response()
         };
         assert_eq!(
-            eval_query(&jsonpath_success(), &variables, &http_response, &mut cache).unwrap(),
+            eval_query(
+                &jsonpath_success(),
+                &variables,
+                &http_response,
+                &[],
+                &http::Timings::default(),
+                &mut cache
+            )
+            .unwrap(),
             None
         );
     }
@@ -1223,6 +1481,8 @@ pub mod tests {
                 &jsonpath_success(),
                 &variables,
                 &http::json_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache
             )
             .unwrap()
@@ -1234,6 +1494,8 @@ pub mod tests {
                 &jsonpath_errors(),
                 &variables,
                 &http::json_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache
             )
             .unwrap()
@@ -1261,6 +1523,8 @@ pub mod tests {
                 &regex_name(),
                 &variables,
                 &http::hello_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache
             )
             .unwrap()
@@ -1272,6 +1536,8 @@ pub mod tests {
             &regex_invalid(),
             &variables,
             &http::hello_http_response(),
+            &[],
+            &http::Timings::default(),
             &mut cache,
         )
         .err()
@@ -1296,6 +1562,8 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache,
             )
             .unwrap()
@@ -1320,6 +1588,8 @@ pub mod tests {
                     body: vec![0xff],
                     ..default_response()
                 },
+                &[],
+                &http::Timings::default(),
                 &mut cache,
             )
             .unwrap()
@@ -1330,6 +1600,52 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_sha1() {
+        let variables = HashMap::new();
+        let mut cache = BodyCache::new();
+
+        assert_eq!(
+            eval_query(
+                &Query {
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    value: QueryValue::Sha1,
+                },
+                &variables,
+                &http::Response {
+                    body: vec![0xff],
+                    ..default_response()
+                },
+                &[],
+                &http::Timings::default(),
+                &mut cache,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(hex!("85e53271e14006f0265921d02d4d736cdc580b0b").to_vec())
+        );
+    }
+
+    #[test]
+    fn test_query_events() {
+        let response = http::Response {
+            events: vec![http::Event {
+                event: "message".to_string(),
+                data: "Hello".to_string(),
+                id: None,
+            }],
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query_events(&response).unwrap().unwrap(),
+            Value::List(vec![Value::Object(vec![
+                ("event".to_string(), Value::String("message".to_string())),
+                ("data".to_string(), Value::String("Hello".to_string())),
+                ("id".to_string(), Value::Null),
+            ])])
+        );
+    }
+
     #[test]
     fn test_query_certificate() {
         assert!(eval_query_certificate(
@@ -25,7 +25,7 @@ use crate::http;
 use crate::http::{HeaderVec, AUTHORIZATION};
 use crate::runner::error::RunnerError;
 use crate::runner::value::Value;
-use crate::runner::{body, multipart, template};
+use crate::runner::{body, multipart, template, RunnerErrorKind};
 use crate::util::path::ContextDir;
 
 /// Transforms an AST `request` to a spec request given a set of `variables`.
@@ -143,6 +143,29 @@ pub fn eval_request(
     })
 }
 
+/// Evaluates the frames sent over the WebSocket opened by a `request`, given a set of `variables`.
+pub fn eval_frames(
+    request: &Request,
+    variables: &HashMap<String, Value>,
+) -> Result<Vec<http::Frame>, RunnerError> {
+    let mut frames = vec![];
+    for frame in &request.frames() {
+        let value = template::eval_template(&frame.value, variables)?;
+        let frame = match frame.kind {
+            FrameKind::Text => http::Frame::Text(value),
+            FrameKind::Binary => match hex::decode(value.trim()) {
+                Ok(bytes) => http::Frame::Binary(bytes),
+                Err(_) => {
+                    let kind = RunnerErrorKind::InvalidHexFrame;
+                    return Err(RunnerError::new(frame.value.source_info, kind, false));
+                }
+            },
+        };
+        frames.push(frame);
+    }
+    Ok(frames)
+}
+
 /// Experimental feature
 /// @cookie_storage_add
 pub fn cookie_storage_set(request: &Request) -> Option<String> {
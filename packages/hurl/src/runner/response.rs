@@ -21,8 +21,10 @@ use hurl_core::ast::*;
 
 use crate::http;
 use crate::runner::cache::BodyCache;
+use crate::runner::diff::diff;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
 use crate::runner::result::{AssertResult, CaptureResult};
+use crate::runner::xml::normalize_xml;
 use crate::runner::{assert, body, capture, json, multiline, template, Value};
 use crate::util::path::ContextDir;
 
@@ -42,17 +44,18 @@ pub fn eval_version_status_asserts(
     });
 
     let status = &response.status;
-    if let StatusValue::Specific(v) = status.value {
+    if status.value != StatusValue::Any {
         asserts.push(AssertResult::Status {
             actual: http_response.status as u64,
-            expected: v,
+            expected: status.value.clone(),
             source_info: status.source_info,
         });
     }
     asserts
 }
 
-/// Returns a list of assert results, given a set of `variables`, an actual `http_response` and a spec `response`.
+/// Returns a list of assert results, given a set of `variables`, an actual `http_response`, the URLs
+/// followed by redirection `redirects` and a spec `response`.
 ///
 /// Asserts on status and version and not run in this function, there are run with `eval_version_status_asserts`
 /// as they're semantically stronger.
@@ -63,6 +66,8 @@ pub fn eval_asserts(
     response: &Response,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    redirects: &[http::Url],
+    timings: &http::Timings,
     cache: &mut BodyCache,
     context_dir: &ContextDir,
 ) -> Vec<AssertResult> {
@@ -149,8 +154,15 @@ pub fn eval_asserts(
 
     // Then, checks all the explicit asserts.
     for assert in response.asserts() {
-        let assert_result =
-            assert::eval_explicit_assert(assert, variables, http_response, cache, context_dir);
+        let assert_result = assert::eval_explicit_assert(
+            assert,
+            variables,
+            http_response,
+            redirects,
+            timings,
+            cache,
+            context_dir,
+        );
         asserts.push(assert_result);
     }
     asserts
@@ -183,6 +195,7 @@ fn eval_implicit_body_asserts(
                     ))
                 }
             };
+            let (actual, expected) = eval_json_body(actual, expected, spec_body.space0.source_info);
             AssertResult::Body {
                 actual,
                 expected,
@@ -205,6 +218,7 @@ fn eval_implicit_body_asserts(
                     ))
                 }
             };
+            let (actual, expected) = eval_xml_body(actual, expected, spec_body.space0.source_info);
             AssertResult::Body {
                 actual,
                 expected,
@@ -255,6 +269,15 @@ fn eval_implicit_body_asserts(
                     ))
                 }
             };
+            let (actual, expected) = match multi.kind {
+                MultilineStringKind::Json(_) => {
+                    eval_json_body(actual, expected, multi.value().source_info)
+                }
+                MultilineStringKind::Xml(_) => {
+                    eval_xml_body(actual, expected, multi.value().source_info)
+                }
+                _ => (actual, expected),
+            };
             AssertResult::Body {
                 actual,
                 expected,
@@ -349,16 +372,142 @@ fn eval_implicit_body_asserts(
     }
 }
 
-/// Evaluates captures from this HTTP `http_response`, given a set of `variables`.
+/// Compares a XML `actual` body to the `expected` one, ignoring insignificant whitespaces.
+///
+/// Returns the `actual` and `expected` values to compare: if both bodies are valid XML and their
+/// normalized representations are equal (see [`normalize_xml`]), `actual` is returned as both
+/// values. If they differ, `actual` is an error with the diff of these representations.
+/// Otherwise, the bodies are returned unchanged and compared as text.
+fn eval_xml_body(
+    actual: Result<Value, RunnerError>,
+    expected: Result<Value, RunnerError>,
+    source_info: SourceInfo,
+) -> (Result<Value, RunnerError>, Result<Value, RunnerError>) {
+    let (Ok(Value::String(actual_text)), Ok(Value::String(expected_text))) = (&actual, &expected)
+    else {
+        return (actual, expected);
+    };
+    let (Some(actual_normalized), Some(expected_normalized)) =
+        (normalize_xml(actual_text), normalize_xml(expected_text))
+    else {
+        return (actual, expected);
+    };
+    if actual_normalized == expected_normalized {
+        return (actual.clone(), actual);
+    }
+    let hunks = diff(&expected_normalized, &actual_normalized);
+    let kind = RunnerErrorKind::AssertBodyXmlDiff { hunks };
+    (Err(RunnerError::new(source_info, kind, true)), expected)
+}
+
+/// Marker that matches any value in an expected JSON body.
+const JSON_WILDCARD: &str = "@any";
+
+/// Compares a JSON `actual` body to the `expected` one, ignoring whitespaces, keys order and
+/// numbers representation (`1.0` is equal to `1`, `1e2` to `100`).
+///
+/// Returns the `actual` and `expected` values to compare: if both bodies are valid JSON and are
+/// structurally equal, `actual` is returned as both values. If they differ, `actual` is an error
+/// with the diff of the normalized bodies. Otherwise, the bodies are returned unchanged and
+/// compared as text. Values of `expected` equal to [`JSON_WILDCARD`] match any actual value.
+fn eval_json_body(
+    actual: Result<Value, RunnerError>,
+    expected: Result<Value, RunnerError>,
+    source_info: SourceInfo,
+) -> (Result<Value, RunnerError>, Result<Value, RunnerError>) {
+    let (Ok(Value::String(actual_text)), Ok(Value::String(expected_text))) = (&actual, &expected)
+    else {
+        return (actual, expected);
+    };
+    let (Ok(actual_json), Ok(expected_json)) = (
+        serde_json::from_str::<serde_json::Value>(actual_text),
+        serde_json::from_str::<serde_json::Value>(expected_text),
+    ) else {
+        return (actual, expected);
+    };
+    let expected_json = resolve_json_equivalents(&expected_json, &actual_json);
+    if actual_json == expected_json {
+        return (actual.clone(), actual);
+    }
+    // Objects keys are sorted when serialized, so the diff only shows significant changes.
+    let expected_normalized = format!("{expected_json:#}\n");
+    let actual_normalized = format!("{actual_json:#}\n");
+    let hunks = diff(&expected_normalized, &actual_normalized);
+    let kind = RunnerErrorKind::AssertBodyJsonDiff { hunks };
+    (Err(RunnerError::new(source_info, kind, true)), expected)
+}
+
+/// Returns `expected` where each [`JSON_WILDCARD`], and each number numerically equal to the
+/// number at the same path in `actual`, is replaced by the value of `actual`, so they are equal
+/// and don't show up in diffs.
+fn resolve_json_equivalents(
+    expected: &serde_json::Value,
+    actual: &serde_json::Value,
+) -> serde_json::Value {
+    match (expected, actual) {
+        (serde_json::Value::String(s), _) if s == JSON_WILDCARD => actual.clone(),
+        (serde_json::Value::Number(e), serde_json::Value::Number(a)) if json_numbers_eq(e, a) => {
+            actual.clone()
+        }
+        (serde_json::Value::Object(expected), serde_json::Value::Object(actual)) => {
+            let object = expected
+                .iter()
+                .map(|(key, e)| {
+                    let value = match actual.get(key) {
+                        Some(a) => resolve_json_equivalents(e, a),
+                        None => e.clone(),
+                    };
+                    (key.clone(), value)
+                })
+                .collect();
+            serde_json::Value::Object(object)
+        }
+        (serde_json::Value::Array(expected), serde_json::Value::Array(actual)) => {
+            let array = expected
+                .iter()
+                .enumerate()
+                .map(|(i, e)| match actual.get(i) {
+                    Some(a) => resolve_json_equivalents(e, a),
+                    None => e.clone(),
+                })
+                .collect();
+            serde_json::Value::Array(array)
+        }
+        _ => expected.clone(),
+    }
+}
+
+/// Returns true if the JSON numbers `a` and `b` have the same value.
+///
+/// JSON numbers keep their textual representation, so integers are compared exactly and other
+/// numbers as floats.
+fn json_numbers_eq(a: &serde_json::Number, b: &serde_json::Number) -> bool {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        return a == b;
+    }
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        return a == b;
+    }
+    match (a.as_f64(), b.as_f64()) {
+        (Some(a), Some(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Evaluates captures from this HTTP `http_response` and the URLs followed by redirection
+/// `redirects`, given a set of `variables`.
 pub fn eval_captures(
     response: &Response,
     http_response: &http::Response,
+    redirects: &[http::Url],
+    timings: &http::Timings,
     cache: &mut BodyCache,
     variables: &mut HashMap<String, Value>,
 ) -> Result<Vec<CaptureResult>, RunnerError> {
     let mut captures = vec![];
     for capture in response.captures() {
-        let capture_result = capture::eval_capture(capture, variables, http_response, cache)?;
+        let capture_result =
+            capture::eval_capture(capture, variables, http_response, redirects, timings, cache)?;
         // Update variables now so the captures set is ready in case
         // the next captures reference this new variable.
         variables.insert(capture_result.name.clone(), capture_result.value.clone());
@@ -432,6 +581,8 @@ mod tests {
                 &user_response(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache,
                 &context_dir,
             ),
@@ -463,7 +614,7 @@ mod tests {
                 },
                 AssertResult::Status {
                     actual: 200,
-                    expected: 200,
+                    expected: StatusValue::Specific(200),
                     source_info: SourceInfo::new(Pos::new(2, 10), Pos::new(2, 13)),
                 },
             ]
@@ -479,6 +630,8 @@ mod tests {
             eval_captures(
                 &user_response(),
                 &http::xml_two_users_http_response(),
+                &[],
+                &http::Timings::default(),
                 &mut cache,
                 &mut variables,
             )
@@ -489,4 +642,109 @@ mod tests {
             }]
         );
     }
+
+    #[test]
+    fn test_eval_json_body() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let expected = || {
+            Ok(Value::String(
+                r#"{"id": 1, "tags": ["a", "b"]}"#.to_string(),
+            ))
+        };
+
+        // Whitespaces and keys order are not significant, the actual body is kept.
+        let actual = Ok(Value::String(
+            "{\n  \"tags\": [\"a\",\"b\"],\n  \"id\": 1\n}".to_string(),
+        ));
+        assert_eq!(
+            eval_json_body(actual.clone(), expected(), source_info),
+            (actual.clone(), actual)
+        );
+
+        // Numbers are compared by value.
+        let actual = Ok(Value::String(
+            r#"{"id": 1.0, "tags": ["a", "b"]}"#.to_string(),
+        ));
+        assert_eq!(
+            eval_json_body(actual.clone(), expected(), source_info),
+            (actual.clone(), actual)
+        );
+        let actual = Ok(Value::String(r#"[1e2, 0.5]"#.to_string()));
+        let expected_numbers = Ok(Value::String(r#"[100, 5e-1]"#.to_string()));
+        assert_eq!(
+            eval_json_body(actual.clone(), expected_numbers, source_info),
+            (actual.clone(), actual)
+        );
+        let actual = Ok(Value::String(
+            r#"{"id": 2, "tags": ["a", "b"]}"#.to_string(),
+        ));
+        assert!(eval_json_body(actual, expected(), source_info).0.is_err());
+
+        // Arrays order is significant.
+        let actual = Ok(Value::String(
+            r#"{"id": 1, "tags": ["b", "a"]}"#.to_string(),
+        ));
+        let (actual, _) = eval_json_body(actual, expected(), source_info);
+        let error = actual.unwrap_err();
+        let RunnerErrorKind::AssertBodyJsonDiff { hunks } = error.kind else {
+            panic!("unexpected error {error:?}");
+        };
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].source_line, 3);
+
+        // Wildcards match any value, but the key must exist.
+        let expected = || {
+            Ok(Value::String(
+                r#"{"id": "@any", "tags": ["a", "@any"]}"#.to_string(),
+            ))
+        };
+        let actual = Ok(Value::String(
+            r#"{"id": 42, "tags": ["a", {"b": 1}]}"#.to_string(),
+        ));
+        assert_eq!(
+            eval_json_body(actual.clone(), expected(), source_info),
+            (actual.clone(), actual)
+        );
+        let actual = Ok(Value::String(r#"{"tags": ["a", "b"]}"#.to_string()));
+        assert!(eval_json_body(actual, expected(), source_info).0.is_err());
+
+        // Non JSON actual bodies are compared as text.
+        let actual = Ok(Value::String("<html></html>".to_string()));
+        assert_eq!(
+            eval_json_body(actual.clone(), expected(), source_info),
+            (actual, expected())
+        );
+    }
+
+    #[test]
+    fn test_eval_xml_body() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let expected = Ok(Value::String(
+            "<a xmlns=\"urn:a\">\n  <b id=\"1\" c=\"2\">x</b>\n</a>".to_string(),
+        ));
+
+        let actual = Ok(Value::String(
+            r#"<p:a xmlns:p="urn:a"><p:b c="2" id="1">x</p:b></p:a>"#.to_string(),
+        ));
+        assert_eq!(
+            eval_xml_body(actual.clone(), expected.clone(), source_info),
+            (actual.clone(), actual)
+        );
+
+        let actual = Ok(Value::String(
+            r#"<a xmlns="urn:b"><b id="1" c="2">x</b></a>"#.to_string(),
+        ));
+        let (actual, _) = eval_xml_body(actual, expected.clone(), source_info);
+        let error = actual.unwrap_err();
+        assert!(matches!(
+            error.kind,
+            RunnerErrorKind::AssertBodyXmlDiff { .. }
+        ));
+
+        let actual = Ok(Value::String("not xml".to_string()));
+        assert_eq!(
+            eval_xml_body(actual.clone(), expected.clone(), source_info),
+            (actual, expected)
+        );
+    }
 }
@@ -17,7 +17,7 @@
  */
 use std::time::Duration;
 
-use hurl_core::ast::SourceInfo;
+use hurl_core::ast::{SourceInfo, StatusValue};
 use hurl_core::reader::Pos;
 
 use crate::http::{Call, Cookie};
@@ -41,6 +41,9 @@ pub struct HurlResult {
     pub cookies: Vec<Cookie>,
     /// Start of the run (in "UNIX timestamp").
     pub timestamp: i64,
+    /// The secret values of the run, redacted from logs and reports: `--secret` values and
+    /// values captured with `redact`.
+    pub secrets: Vec<String>,
 }
 
 impl HurlResult {
@@ -86,6 +89,9 @@ pub struct EntryResult {
     pub asserts: Vec<AssertResult>,
     /// List of errors.
     pub errors: Vec<RunnerError>,
+    /// The equivalent curl command line of this entry request, empty if the request has not
+    /// been evaluated.
+    pub curl_cmd: String,
 
     /// Effective duration of all the HTTP transfers, excluding asserts and captures processing.
     pub transfer_duration: Duration,
@@ -93,6 +99,9 @@ pub struct EntryResult {
     /// server is requested to send compressed response, and the response should be uncompressed
     /// when outputted on stdout.
     pub compressed: bool,
+    /// The entry has been skipped because it uses a variable that a failed entry should have
+    /// captured (see `--continue-on-error`).
+    pub skipped: bool,
 }
 
 impl Default for EntryResult {
@@ -104,8 +113,10 @@ impl Default for EntryResult {
             captures: vec![],
             asserts: vec![],
             errors: vec![],
+            curl_cmd: String::new(),
             transfer_duration: Duration::from_millis(0),
             compressed: false,
+            skipped: false,
         }
     }
 }
@@ -141,7 +152,7 @@ pub enum AssertResult {
     /// Implicit HTTP status code assert.
     Status {
         actual: u64,
-        expected: u64,
+        expected: StatusValue,
         source_info: SourceInfo,
     },
     /// Implicit HTTP response header assert.
@@ -20,6 +20,7 @@ use std::time::Duration;
 use hurl_core::ast::Entry;
 use hurl_core::typing::Count;
 
+use crate::grpc::Proto;
 use crate::http::{IpResolve, RequestedHttpVersion};
 use crate::runner::Output;
 use crate::util::path::ContextDir;
@@ -36,6 +37,8 @@ pub struct RunnerOptionsBuilder {
     continue_on_error: bool,
     cookie_input_file: Option<String>,
     delay: Duration,
+    digest: bool,
+    doh_url: Option<String>,
     follow_location: bool,
     follow_location_trusted: bool,
     from_entry: Option<usize>,
@@ -43,12 +46,16 @@ pub struct RunnerOptionsBuilder {
     ignore_asserts: bool,
     insecure: bool,
     ip_resolve: IpResolve,
+    max_decompressed_size: Option<u64>,
     max_filesize: Option<u64>,
     max_redirect: Count,
     netrc: bool,
     netrc_file: Option<String>,
     netrc_optional: bool,
     no_proxy: Option<String>,
+    ntlm: bool,
+    oauth2_client: Option<String>,
+    oauth2_token_url: Option<String>,
     output: Option<Output>,
     path_as_is: bool,
     post_entry: Option<fn() -> bool>,
@@ -60,6 +67,8 @@ pub struct RunnerOptionsBuilder {
     retry_interval: Duration,
     skip: bool,
     ssl_no_revoke: bool,
+    stream_count: Count,
+    stream_timeout: Option<Duration>,
     timeout: Duration,
     to_entry: Option<usize>,
     unix_socket: Option<String>,
@@ -81,6 +90,8 @@ impl Default for RunnerOptionsBuilder {
             continue_on_error: false,
             cookie_input_file: None,
             delay: Duration::from_millis(0),
+            digest: false,
+            doh_url: None,
             follow_location: false,
             follow_location_trusted: false,
             from_entry: None,
@@ -88,12 +99,16 @@ impl Default for RunnerOptionsBuilder {
             ignore_asserts: false,
             insecure: false,
             ip_resolve: IpResolve::default(),
+            max_decompressed_size: None,
             max_filesize: None,
             max_redirect: Count::Finite(50),
             netrc: false,
             netrc_file: None,
             netrc_optional: false,
             no_proxy: None,
+            ntlm: false,
+            oauth2_client: None,
+            oauth2_token_url: None,
             output: None,
             path_as_is: false,
             post_entry: None,
@@ -105,6 +120,8 @@ impl Default for RunnerOptionsBuilder {
             retry_interval: Duration::from_millis(1000),
             skip: false,
             ssl_no_revoke: false,
+            stream_count: Count::Infinite,
+            stream_timeout: None,
             timeout: Duration::from_secs(300),
             to_entry: None,
             unix_socket: None,
@@ -177,6 +194,18 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Uses HTTP Digest authentication with the user credentials.
+    pub fn digest(&mut self, digest: bool) -> &mut Self {
+        self.digest = digest;
+        self
+    }
+
+    /// Resolves host names over DNS-over-HTTPS with the server at `doh_url`.
+    pub fn doh_url(&mut self, doh_url: Option<String>) -> &mut Self {
+        self.doh_url = doh_url;
+        self
+    }
+
     /// Sets root file system to import files in Hurl.
     ///
     /// This is used for both files in multipart form data and request body.
@@ -293,6 +322,27 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Uses NTLM authentication with the user credentials.
+    pub fn ntlm(&mut self, ntlm: bool) -> &mut Self {
+        self.ntlm = ntlm;
+        self
+    }
+
+    /// Sets the OAuth2 client credentials, with the format CLIENT_ID:CLIENT_SECRET.
+    pub fn oauth2_client(&mut self, oauth2_client: Option<String>) -> &mut Self {
+        self.oauth2_client = oauth2_client;
+        self
+    }
+
+    /// Sets the OAuth2 token endpoint.
+    ///
+    /// An access token is fetched from this URL with the client credentials grant, and is sent
+    /// as a bearer token with each request.
+    pub fn oauth2_token_url(&mut self, oauth2_token_url: Option<String>) -> &mut Self {
+        self.oauth2_token_url = oauth2_token_url;
+        self
+    }
+
     /// Specifies the file to output the HTTP response instead of stdout.
     pub fn output(&mut self, output: Option<Output>) -> &mut Self {
         self.output = output;
@@ -354,6 +404,25 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum number of frames received from a WebSocket, or events received from a
+    /// `text/event-stream` response.
+    ///
+    /// Default is infinite: frames and events are received until the server closes the connection
+    /// or the stream timeout is reached.
+    pub fn stream_count(&mut self, stream_count: Count) -> &mut Self {
+        self.stream_count = stream_count;
+        self
+    }
+
+    /// Sets the maximum time to wait for frames from a WebSocket, or events from a
+    /// `text/event-stream` response.
+    ///
+    /// Default is 10 seconds.
+    pub fn stream_timeout(&mut self, stream_timeout: Duration) -> &mut Self {
+        self.stream_timeout = Some(stream_timeout);
+        self
+    }
+
     /// Sets maximum time allowed for the transfer.
     ///
     /// Default 300 seconds.
@@ -386,6 +455,15 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum size of a decompressed response body.
+    ///
+    /// Responses whose decompressed body is larger than this limit are reported as errors,
+    /// before any capture or assert is evaluated. By default, there is no limit.
+    pub fn max_decompressed_size(&mut self, max_decompressed_size: Option<u64>) -> &mut Self {
+        self.max_decompressed_size = max_decompressed_size;
+        self
+    }
+
     /// Set the file size limit
     pub fn max_filesize(&mut self, max_filesize: Option<u64>) -> &mut Self {
         self.max_filesize = max_filesize;
@@ -403,6 +481,8 @@ impl RunnerOptionsBuilder {
             connect_timeout: self.connect_timeout,
             connects_to: self.connects_to.clone(),
             delay: self.delay,
+            digest: self.digest,
+            doh_url: self.doh_url.clone(),
             context_dir: self.context_dir.clone(),
             continue_on_error: self.continue_on_error,
             cookie_input_file: self.cookie_input_file.clone(),
@@ -413,16 +493,22 @@ impl RunnerOptionsBuilder {
             ignore_asserts: self.ignore_asserts,
             insecure: self.insecure,
             ip_resolve: self.ip_resolve,
+            max_decompressed_size: self.max_decompressed_size,
             max_filesize: self.max_filesize,
             max_redirect: self.max_redirect,
             netrc: self.netrc,
             netrc_file: self.netrc_file.clone(),
             netrc_optional: self.netrc_optional,
             no_proxy: self.no_proxy.clone(),
+            ntlm: self.ntlm,
+            oauth2_bearer: None,
+            oauth2_client: self.oauth2_client.clone(),
+            oauth2_token_url: self.oauth2_token_url.clone(),
             output: self.output.clone(),
             path_as_is: self.path_as_is,
             post_entry: self.post_entry,
             pre_entry: self.pre_entry,
+            proto: None,
             proxy: self.proxy.clone(),
             repeat: self.repeat,
             resolves: self.resolves.clone(),
@@ -430,11 +516,14 @@ impl RunnerOptionsBuilder {
             retry_interval: self.retry_interval,
             skip: self.skip,
             ssl_no_revoke: self.ssl_no_revoke,
+            stream_count: self.stream_count,
+            stream_timeout: self.stream_timeout,
             timeout: self.timeout,
             to_entry: self.to_entry,
             unix_socket: self.unix_socket.clone(),
             user: self.user.clone(),
             user_agent: self.user_agent.clone(),
+            xml_namespaces: vec![],
         }
     }
 }
@@ -449,6 +538,8 @@ pub struct RunnerOptions {
     pub(crate) connect_timeout: Duration,
     pub(crate) connects_to: Vec<String>,
     pub(crate) delay: Duration,
+    pub(crate) digest: bool,
+    pub(crate) doh_url: Option<String>,
     pub(crate) context_dir: ContextDir,
     pub(crate) continue_on_error: bool,
     pub(crate) cookie_input_file: Option<String>,
@@ -459,16 +550,24 @@ pub struct RunnerOptions {
     pub(crate) ignore_asserts: bool,
     pub(crate) ip_resolve: IpResolve,
     pub(crate) insecure: bool,
+    pub(crate) max_decompressed_size: Option<u64>,
     pub(crate) max_filesize: Option<u64>,
     pub(crate) max_redirect: Count,
     pub(crate) netrc: bool,
     pub(crate) netrc_file: Option<String>,
     pub(crate) netrc_optional: bool,
     pub(crate) no_proxy: Option<String>,
+    pub(crate) ntlm: bool,
+    /// OAuth2 access token, fetched by the runner when `oauth2_token_url` is set.
+    pub(crate) oauth2_bearer: Option<String>,
+    pub(crate) oauth2_client: Option<String>,
+    pub(crate) oauth2_token_url: Option<String>,
     pub(crate) output: Option<Output>,
     pub(crate) path_as_is: bool,
     pub(crate) post_entry: Option<fn() -> bool>,
     pub(crate) pre_entry: Option<fn(&Entry) -> bool>,
+    /// The messages and methods of gRPC calls, defined by the `proto` entry option.
+    pub(crate) proto: Option<Proto>,
     pub(crate) proxy: Option<String>,
     pub(crate) repeat: Option<Count>,
     pub(crate) resolves: Vec<String>,
@@ -476,11 +575,15 @@ pub struct RunnerOptions {
     pub(crate) retry_interval: Duration,
     pub(crate) skip: bool,
     pub(crate) ssl_no_revoke: bool,
+    pub(crate) stream_count: Count,
+    pub(crate) stream_timeout: Option<Duration>,
     pub(crate) timeout: Duration,
     pub(crate) to_entry: Option<usize>,
     pub(crate) unix_socket: Option<String>,
     pub(crate) user: Option<String>,
     pub(crate) user_agent: Option<String>,
+    /// XML namespaces (prefix and URI) of XPath queries, defined by the `xmlns` entry options.
+    pub(crate) xml_namespaces: Vec<(String, String)>,
 }
 
 impl Default for RunnerOptions {
@@ -17,9 +17,11 @@
  */
 use std::collections::HashMap;
 
+use chrono::SecondsFormat;
 use hurl_core::ast::*;
 
 use crate::runner::error::{RunnerError, RunnerErrorKind};
+use crate::runner::expr::eval_expr;
 use crate::runner::Value;
 
 /// Renders to string a `template` given a map of variables.
@@ -52,10 +54,18 @@ pub fn render_expression(
     expr: &Expr,
     variables: &HashMap<String, Value>,
 ) -> Result<String, RunnerError> {
+    let value = eval_expression(expr, variables)?;
+    render_expression_value(expr, value)
+}
+
+/// Renders the `value` of an already evaluated expression `expr`.
+pub fn render_expression_value(expr: &Expr, value: Value) -> Result<String, RunnerError> {
     let source_info = expr.variable.source_info;
     let name = &expr.variable.name;
-    let value = eval_expression(expr, variables)?;
-    if value.is_renderable() {
+    if let Value::Date(date) = value {
+        // Dates are rendered with RFC 3339 format, for instance `2024-09-15T08:02:32.306974Z`.
+        Ok(date.to_rfc3339_opts(SecondsFormat::Micros, true))
+    } else if value.is_renderable() {
         Ok(value.to_string())
     } else {
         let kind = RunnerErrorKind::UnrenderableVariable {
@@ -70,15 +80,7 @@ pub fn eval_expression(
     expr: &Expr,
     variables: &HashMap<String, Value>,
 ) -> Result<Value, RunnerError> {
-    let source_info = expr.variable.source_info;
-    let name = &expr.variable.name;
-    match variables.get(name.as_str()) {
-        Some(value) => Ok(value.clone()),
-        _ => {
-            let kind = RunnerErrorKind::TemplateVariableNotDefined { name: name.clone() };
-            Err(RunnerError::new(source_info, kind, false))
-        }
-    }
+    eval_expr(expr, variables)
 }
 
 impl Value {
@@ -189,4 +191,37 @@ mod tests {
         );
         assert_eq!(render_expression(&expr, &variables).unwrap(), "true");
     }
+
+    #[test]
+    fn test_render_function() {
+        let expr = |name: &str| Expr {
+            space0: Whitespace {
+                value: String::new(),
+                source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 3)),
+            },
+            variable: Variable {
+                name: name.to_string(),
+                source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 10)),
+            },
+            space1: Whitespace {
+                value: String::new(),
+                source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 10)),
+            },
+        };
+        let mut variables = HashMap::new();
+        let uuid = render_expression(&expr("newUuid"), &variables).unwrap();
+        assert_eq!(uuid.len(), 36);
+
+        // Dates are rendered with RFC 3339 format.
+        let date = render_expression(&expr("newDate"), &variables).unwrap();
+        assert!(chrono::DateTime::parse_from_rfc3339(&date).is_ok());
+        assert!(date.ends_with('Z'));
+
+        // Variables take precedence over functions.
+        variables.insert("newUuid".to_string(), Value::String("foo".to_string()));
+        assert_eq!(
+            render_expression(&expr("newUuid"), &variables).unwrap(),
+            "foo"
+        );
+    }
 }
@@ -0,0 +1,140 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use xml::name::OwnedName;
+use xml::reader::{ParserConfig, XmlEvent};
+
+/// Returns a normalized representation of the XML document `text`, with one node per line, or
+/// `None` if `text` is not a valid XML document.
+///
+/// Two documents with the same normalized representation are considered equal:
+/// - text nodes made only of whitespaces are not significant, other text nodes are kept as is,
+/// - comments and processing instructions are ignored, CDATA sections are read as text,
+/// - attributes are sorted, elements and attributes are identified by their namespace URI and
+///   local name (the namespace prefix is not significant), attributes values are escaped.
+pub fn normalize_xml(text: &str) -> Option<String> {
+    let reader = ParserConfig::new()
+        .trim_whitespace(false)
+        .whitespace_to_characters(false)
+        .cdata_to_characters(true)
+        .ignore_comments(true)
+        .coalesce_characters(true)
+        .create_reader(text.as_bytes());
+
+    let mut lines = vec![];
+    let mut depth = 0;
+    for event in reader {
+        let indent = "  ".repeat(depth);
+        match event.ok()? {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => {
+                let mut attributes = attributes
+                    .iter()
+                    .map(|a| {
+                        let value = escape_attribute(&a.value);
+                        format!(" {}=\"{value}\"", qualified_name(&a.name))
+                    })
+                    .collect::<Vec<_>>();
+                attributes.sort();
+                let attributes = attributes.join("");
+                lines.push(format!("{indent}<{}{attributes}>", qualified_name(&name)));
+                depth += 1;
+            }
+            XmlEvent::EndElement { name } => {
+                depth -= 1;
+                let indent = "  ".repeat(depth);
+                lines.push(format!("{indent}</{}>", qualified_name(&name)));
+            }
+            XmlEvent::Characters(s) => lines.push(format!("{indent}{s}")),
+            _ => {}
+        }
+    }
+    let mut normalized = lines.join("\n");
+    normalized.push('\n');
+    Some(normalized)
+}
+
+/// Escapes an attribute `value`, so it can't be confused with the end of the attribute.
+fn escape_attribute(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;")
+}
+
+/// Returns the name of an element or an attribute in Clark notation (`{namespace}local`).
+fn qualified_name(name: &OwnedName) -> String {
+    match &name.namespace {
+        Some(namespace) => format!("{{{namespace}}}{}", name.local_name),
+        None => name.local_name.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_xml() {
+        let text = r#"<?xml version="1.0"?>
+<!-- A catalog -->
+<c:catalog xmlns:c="urn:catalog">
+    <c:book id="bk101" lang="en">
+        <title>XML Developer's Guide</title>
+        <description><![CDATA[An in-depth look]]></description>
+    </c:book>
+</c:catalog>"#;
+        assert_eq!(
+            normalize_xml(text).unwrap(),
+            r#"<{urn:catalog}catalog>
+  <{urn:catalog}book id="bk101" lang="en">
+    <title>
+      XML Developer's Guide
+    </title>
+    <description>
+      An in-depth look
+    </description>
+  </{urn:catalog}book>
+</{urn:catalog}catalog>
+"#
+        );
+
+        // Namespace prefix, attributes order and whitespaces are not significant.
+        let other = r#"<catalog xmlns="urn:catalog"><book lang="en" id="bk101"><title>XML Developer's Guide</title><description>An in-depth look</description></book></catalog>"#;
+        let other = other.replace("<title>", "<title xmlns=\"\">");
+        let other = other.replace("<description>", "<description xmlns=\"\">");
+        assert_eq!(normalize_xml(text), normalize_xml(&other));
+
+        // Text nodes are significant, except if they are only made of whitespaces.
+        assert_ne!(normalize_xml("<a> x </a>"), normalize_xml("<a>x</a>"));
+        assert_eq!(
+            normalize_xml("<a>\n  <b/>\n</a>"),
+            normalize_xml("<a><b/></a>")
+        );
+
+        // Attributes values are escaped.
+        assert_eq!(
+            normalize_xml(r#"<a b="x&quot; c=&quot;y" d="&amp;"/>"#).unwrap(),
+            "<a b=\"x&quot; c=&quot;y\" d=\"&amp;\">\n</a>\n"
+        );
+        assert_ne!(
+            normalize_xml(r#"<a b="x&quot; c=&quot;y"/>"#),
+            normalize_xml(r#"<a b="x" c="y"/>"#)
+        );
+
+        assert_eq!(normalize_xml("<a><b></a>"), None);
+        assert_eq!(normalize_xml("not xml"), None);
+    }
+}
@@ -38,6 +38,8 @@ pub struct Document {
     inner: libxml::tree::Document,
     /// Format use for parsing: HTML or XML
     format: Format,
+    /// Namespaces (prefix and URI) registered in addition to the document ones
+    namespaces: Vec<(String, String)>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -64,17 +66,28 @@ impl Document {
             return Err("no root element".to_string());
         }
 
-        let doc = Document { inner: doc, format };
+        let doc = Document {
+            inner: doc,
+            format,
+            namespaces: vec![],
+        };
         Ok(doc)
     }
 
+    /// Registers a namespace `prefix` for `uri`, usable in XPath expressions.
+    ///
+    /// This prefix takes precedence over the prefixes declared in the XML document.
+    pub fn add_namespace(&mut self, prefix: &str, uri: &str) {
+        self.namespaces.push((prefix.to_string(), uri.to_string()));
+    }
+
     /// Evaluates a XPath 1.0 expression `expr` against a document.
     pub fn eval_xpath(&self, expr: &str) -> Result<Value, XPathError> {
         let support_ns = match self.format {
             Format::Html => false,
             Format::Xml => true,
         };
-        libxml_eval_xpath(&self.inner, expr, support_ns)
+        libxml_eval_xpath(&self.inner, expr, support_ns, &self.namespaces)
     }
 }
 
@@ -159,10 +172,13 @@ fn register_namespaces(doc: &libxml::tree::Document, context: &libxml::xpath::Co
 }
 
 /// Evaluates a XPath 1.0 expression `expr` against an libxml2 document `doc`, optionally using namespace.
+///
+/// With namespaces support, the `namespaces` prefixes are registered after the document ones.
 fn libxml_eval_xpath(
     doc: &libxml::tree::Document,
     expr: &str,
     support_ns: bool,
+    namespaces: &[(String, String)],
 ) -> Result<Value, XPathError> {
     let context = libxml::xpath::Context::new(doc).expect("error setting context in xpath module");
 
@@ -173,6 +189,9 @@ fn libxml_eval_xpath(
 
     if support_ns {
         register_namespaces(doc, &context);
+        for (prefix, uri) in namespaces {
+            context.register_namespace(prefix, uri).unwrap();
+        }
     }
 
     let result = match context.evaluate(expr) {
@@ -506,4 +525,42 @@ mod tests {
         let expr = "//_:book/_:notes/*[local-name()='p']";
         assert_eq!(doc.eval_xpath(expr).unwrap(), Value::Nodeset(1));
     }
+
+    #[test]
+    fn test_added_namespaces() {
+        let xml = r#"<?xml version="1.0"?>
+<book xmlns='urn:loc.gov:books'>
+    <title>Cheaper by the Dozen</title>
+    <notes>
+      <p xmlns='http://www.w3.org/1999/xhtml'>This is a funny book!</p>
+    </notes>
+</book>
+        "#;
+
+        let mut doc = Document::parse(xml, Format::Xml).unwrap();
+        doc.add_namespace("b", "urn:loc.gov:books");
+        doc.add_namespace("h", "http://www.w3.org/1999/xhtml");
+
+        // The second default namespace can only be used with an added prefix.
+        let expr = "string(//b:book/b:notes/h:p)";
+        assert_eq!(
+            doc.eval_xpath(expr).unwrap(),
+            Value::String("This is a funny book!".to_string())
+        );
+
+        // The first default namespace is still registered.
+        let expr = "string(//_:book/b:title)";
+        assert_eq!(
+            doc.eval_xpath(expr).unwrap(),
+            Value::String("Cheaper by the Dozen".to_string())
+        );
+
+        // An added prefix takes precedence over the prefixes of the document.
+        doc.add_namespace("_", "http://www.w3.org/1999/xhtml");
+        let expr = "string(//b:book/b:notes/_:p)";
+        assert_eq!(
+            doc.eval_xpath(expr).unwrap(),
+            Value::String("This is a funny book!".to_string())
+        );
+    }
 }
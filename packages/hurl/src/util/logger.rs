@@ -53,6 +53,7 @@ pub struct Logger {
     pub(crate) color: bool,
     pub(crate) error_format: ErrorFormat,
     pub(crate) verbosity: Option<Verbosity>,
+    pub(crate) warn_asserts: bool,
     pub(crate) stderr: Stderr,
 }
 
@@ -60,13 +61,17 @@ pub struct Logger {
 pub struct LoggerOptions {
     pub(crate) color: bool,
     pub(crate) error_format: ErrorFormat,
+    pub(crate) secrets: Vec<String>,
     pub(crate) verbosity: Option<Verbosity>,
+    pub(crate) warn_asserts: bool,
 }
 
 pub struct LoggerOptionsBuilder {
     color: bool,
     error_format: ErrorFormat,
+    secrets: Vec<String>,
     verbosity: Option<Verbosity>,
+    warn_asserts: bool,
 }
 
 impl LoggerOptionsBuilder {
@@ -89,18 +94,32 @@ impl LoggerOptionsBuilder {
         self
     }
 
+    /// Sets the secret values, redacted from the logs.
+    pub fn secrets(&mut self, secrets: &[String]) -> &mut Self {
+        self.secrets = secrets.to_vec();
+        self
+    }
+
     /// Sets verbose logger.
     pub fn verbosity(&mut self, verbosity: Option<Verbosity>) -> &mut Self {
         self.verbosity = verbosity;
         self
     }
 
+    /// Logs assert errors as warnings.
+    pub fn warn_asserts(&mut self, warn_asserts: bool) -> &mut Self {
+        self.warn_asserts = warn_asserts;
+        self
+    }
+
     /// Creates a new logger.
     pub fn build(&self) -> LoggerOptions {
         LoggerOptions {
             color: self.color,
             error_format: self.error_format,
+            secrets: self.secrets.clone(),
             verbosity: self.verbosity,
+            warn_asserts: self.warn_asserts,
         }
     }
 }
@@ -110,7 +129,9 @@ impl Default for LoggerOptionsBuilder {
         LoggerOptionsBuilder {
             color: false,
             error_format: ErrorFormat::Short,
+            secrets: vec![],
             verbosity: None,
+            warn_asserts: false,
         }
     }
 }
@@ -118,14 +139,27 @@ impl Default for LoggerOptionsBuilder {
 impl Logger {
     /// Creates a new instance.
     pub fn new(options: &LoggerOptions, term: Stderr) -> Self {
+        let mut stderr = term;
+        stderr.set_secrets(&options.secrets);
         Logger {
             color: options.color,
             error_format: options.error_format,
             verbosity: options.verbosity,
-            stderr: term,
+            warn_asserts: options.warn_asserts,
+            stderr,
         }
     }
 
+    /// Adds a `secret` value (captured or computed during the run), redacted from the logs.
+    pub fn add_secret(&mut self, secret: &str) {
+        self.stderr.add_secret(secret);
+    }
+
+    /// Returns the secret values redacted from the logs.
+    pub fn secrets(&self) -> &[String] {
+        self.stderr.secrets()
+    }
+
     fn format(&self) -> Format {
         if self.color {
             Format::Ansi
@@ -293,6 +327,33 @@ impl Logger {
         self.error_rich(&message);
     }
 
+    pub fn warning_runtime_rich<E: DisplaySourceError>(
+        &mut self,
+        content: &str,
+        filename: Option<&Input>,
+        error: &E,
+        entry_src_info: SourceInfo,
+    ) {
+        let filename = filename.map_or(String::new(), |f| f.to_string());
+        let message = error.to_string(
+            &filename,
+            content,
+            Some(entry_src_info),
+            OutputFormat::Terminal(self.color),
+        );
+        self.warning_rich(&message);
+    }
+
+    fn warning_rich(&mut self, message: &str) {
+        let fmt = self.format();
+        let mut s = StyledString::new();
+        s.push_with("warning", Style::new().yellow().bold());
+        s.push(": ");
+        s.push(message);
+        s.push("\n");
+        self.stderr.eprintln(&s.to_string(fmt));
+    }
+
     fn error_rich(&mut self, message: &str) {
         let fmt = self.format();
         let mut s = StyledString::new();
@@ -18,4 +18,5 @@
 //! Common utilities like log, path helpers and standard output/error wrapper.
 pub mod logger;
 pub mod path;
+pub mod redacted;
 pub mod term;
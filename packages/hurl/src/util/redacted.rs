@@ -0,0 +1,202 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Redaction of secret values in logs and reports.
+use base64::engine::general_purpose;
+use base64::Engine;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// The string displayed in place of a secret value.
+const REDACTED: &str = "***";
+
+lazy_static! {
+    /// Base64 credentials of a `Basic` authorization.
+    static ref BASIC_CREDENTIALS: Regex = Regex::new(r"Basic ([A-Za-z0-9+/]+=*)").unwrap();
+}
+
+/// Returns `s` with every occurrence of the `secrets` values replaced by `***`.
+///
+/// The secrets pattern is built on each call: to redact many strings with the same secrets,
+/// use a [`Redactor`].
+pub fn redact(s: &str, secrets: &[String]) -> String {
+    Redactor::new(secrets).redact(s)
+}
+
+/// Redacts a list of secret values, compiled once into a single regex.
+#[derive(Clone, Debug, Default)]
+pub struct Redactor {
+    /// Regex matching any secret value, `None` if there are no secrets.
+    secrets: Option<Regex>,
+}
+
+impl Redactor {
+    /// Creates a new redactor for the `secrets` values.
+    pub fn new(secrets: &[String]) -> Self {
+        let mut secrets = secrets
+            .iter()
+            .filter(|secret| !secret.is_empty())
+            .collect::<Vec<_>>();
+        if secrets.is_empty() {
+            return Redactor { secrets: None };
+        }
+        secrets.sort_by_key(|secret| std::cmp::Reverse(secret.len()));
+        let pattern = secrets
+            .iter()
+            .map(|secret| secret_pattern(secret))
+            .collect::<Vec<_>>()
+            .join("|");
+        let re = Regex::new(&pattern).unwrap();
+        Redactor { secrets: Some(re) }
+    }
+
+    /// Returns `s` with every occurrence of the secret values replaced by `***`.
+    ///
+    /// Secrets are also redacted when they're written HTML/XML escaped (`&amp;`, `&quot;` etc...),
+    /// URL encoded (in query strings or form bodies), and in the base64 credentials of a
+    /// `Basic` authorization. Longer secrets are redacted first, so a secret containing another
+    /// secret is fully redacted.
+    pub fn redact(&self, s: &str) -> String {
+        let Some(re) = &self.secrets else {
+            return s.to_string();
+        };
+        let s = BASIC_CREDENTIALS.replace_all(s, |caps: &regex::Captures| {
+            let credentials = general_purpose::STANDARD
+                .decode(&caps[1])
+                .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+                .unwrap_or_default();
+            if re.is_match(&credentials) {
+                format!("Basic {REDACTED}")
+            } else {
+                caps[0].to_string()
+            }
+        });
+        re.replace_all(&s, REDACTED).to_string()
+    }
+
+    /// Redacts the secret values from every string of a JSON `value`, object keys included.
+    pub fn redact_json(&self, value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::String(s) => *s = self.redact(s),
+            serde_json::Value::Array(values) => {
+                values.iter_mut().for_each(|v| self.redact_json(v));
+            }
+            serde_json::Value::Object(map) => {
+                let entries = std::mem::take(map);
+                for (key, mut value) in entries {
+                    self.redact_json(&mut value);
+                    map.insert(self.redact(&key), value);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns a regex pattern matching a `secret`, each of its characters being matched as is,
+/// HTML/XML escaped or URL encoded.
+fn secret_pattern(secret: &str) -> String {
+    let mut pattern = String::new();
+    for c in secret.chars() {
+        let mut forms = vec![regex::escape(&c.to_string())];
+        let entities: &[&str] = match c {
+            '&' => &["&amp;", "&#38;", "&#x26;"],
+            '<' => &["&lt;", "&#60;", "&#x3c;", "&#x3C;"],
+            '>' => &["&gt;", "&#62;", "&#x3e;", "&#x3E;"],
+            '"' => &["&quot;", "&#34;", "&#x22;"],
+            '\'' => &["&apos;", "&#39;", "&#x27;"],
+            ' ' => &["+"],
+            _ => &[],
+        };
+        forms.extend(entities.iter().map(|e| regex::escape(e)));
+        let mut buf = [0; 4];
+        let mut encoded = String::new();
+        for b in c.encode_utf8(&mut buf).bytes() {
+            encoded.push_str(&format!("%{b:02x}"));
+        }
+        forms.push(format!("(?i:{encoded})"));
+        pattern.push_str(&format!("(?:{})", forms.join("|")));
+    }
+    pattern
+}
+
+/// Redacts the `secrets` values from every string of a JSON `value`, object keys included.
+///
+/// Redacting the JSON value, rather than its serialized string, takes care of secrets with
+/// characters escaped in JSON strings.
+pub fn redact_json(value: &mut serde_json::Value, secrets: &[String]) {
+    Redactor::new(secrets).redact_json(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        let secrets = vec!["abc".to_string(), "abcdef".to_string(), String::new()];
+        assert_eq!(
+            redact("Authorization: Bearer abcdef, token=abc", &secrets),
+            "Authorization: Bearer ***, token=***"
+        );
+        assert_eq!(redact("Hello", &[]), "Hello");
+    }
+
+    #[test]
+    fn test_redactor() {
+        let redactor = Redactor::new(&["abc".to_string()]);
+        assert_eq!(redactor.redact("token=abc"), "token=***");
+        assert_eq!(redactor.redact("abc, abcd"), "***, ***d");
+        assert_eq!(Redactor::default().redact("token=abc"), "token=abc");
+    }
+
+    #[test]
+    fn test_redact_encoded() {
+        let secrets = vec!["p&s\"w rd".to_string()];
+        assert_eq!(
+            redact("<td>p&amp;s&quot;w rd</td>", &secrets),
+            "<td>***</td>"
+        );
+        assert_eq!(redact("token=p%26s%22w+rd&a=1", &secrets), "token=***&a=1");
+        assert_eq!(redact("token=p%26s%22w%20rd", &secrets), "token=***");
+        assert_eq!(
+            redact("> Authorization: Basic Ym9iOnAmcyJ3IHJk", &secrets),
+            "> Authorization: Basic ***"
+        );
+        assert_eq!(
+            redact("> Authorization: Basic Ym9iOnNlY3JldA==", &secrets),
+            "> Authorization: Basic Ym9iOnNlY3JldA=="
+        );
+    }
+
+    #[test]
+    fn test_redact_json() {
+        let secrets = vec!["pass\"word".to_string()];
+        let mut value = serde_json::json!({
+            "headers": [{"name": "X-Token", "value": "pass\"word"}],
+            "pass\"word": 1
+        });
+        redact_json(&mut value, &secrets);
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "headers": [{"name": "X-Token", "value": "***"}],
+                "***": 1
+            })
+        );
+    }
+}
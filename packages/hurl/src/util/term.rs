@@ -21,6 +21,8 @@ use std::io;
 use std::io::IsTerminal;
 use std::io::Write;
 
+use crate::util::redacted::Redactor;
+
 /// The way to write on standard output and error: either immediate like `println!` macro,
 /// or buffered in an internal buffer.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -106,6 +108,10 @@ pub struct Stderr {
     buffer: String,
     /// Progress bar: when not empty, it is always displayed at the end of the terminal.
     progress_bar: String,
+    /// Secret values, redacted from the messages.
+    secrets: Vec<String>,
+    /// Redactor of the secret values, rebuilt only when secrets change.
+    redactor: Redactor,
 }
 
 impl Stderr {
@@ -115,11 +121,33 @@ impl Stderr {
             mode,
             buffer: String::new(),
             progress_bar: String::new(),
+            secrets: vec![],
+            redactor: Redactor::default(),
+        }
+    }
+
+    /// Sets the `secrets` values to redact from the messages printed to this standard error.
+    pub fn set_secrets(&mut self, secrets: &[String]) {
+        self.secrets = secrets.to_vec();
+        self.redactor = Redactor::new(&self.secrets);
+    }
+
+    /// Adds a `secret` value to redact from the messages printed to this standard error.
+    pub fn add_secret(&mut self, secret: &str) {
+        if !self.secrets.iter().any(|s| s == secret) {
+            self.secrets.push(secret.to_string());
+            self.redactor = Redactor::new(&self.secrets);
         }
     }
 
+    /// Returns the secret values redacted from the messages printed to this standard error.
+    pub fn secrets(&self) -> &[String] {
+        &self.secrets
+    }
+
     /// Prints to the standard error, with a newline.
     pub fn eprintln(&mut self, message: &str) {
+        let message = &self.redactor.redact(message);
         match self.mode {
             WriteMode::Immediate => {
                 let has_progress = !self.progress_bar.is_empty();
@@ -140,6 +168,7 @@ impl Stderr {
 
     /// Prints to the standard error.
     pub fn eprint(&mut self, message: &str) {
+        let message = &self.redactor.redact(message);
         match self.mode {
             WriteMode::Immediate => {
                 let has_progress = !self.progress_bar.is_empty();
@@ -228,4 +257,13 @@ mod tests {
 
         assert_eq!(stderr.buffer(), "toto\ntutu\n");
     }
+
+    #[test]
+    fn redacted_stderr() {
+        let mut stderr = Stderr::new(WriteMode::Buffered);
+        stderr.set_secrets(&["s3cr3t".to_string()]);
+        stderr.eprintln("> Authorization: Bearer s3cr3t");
+        stderr.eprint("token=s3cr3t");
+        assert_eq!(stderr.buffer(), "> Authorization: Bearer ***\ntoken=***");
+    }
 }
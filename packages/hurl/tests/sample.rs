@@ -15,14 +15,18 @@
  * limitations under the License.
  *
  */
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::time::Duration;
 
-use hurl::http::{Call, HttpVersion, Request, Response, Url};
+use hurl::http::{
+    Call, ClientOptions, Cookie, Header, HeaderVec, HttpClient, HttpError, HttpVersion, Request,
+    RequestSpec, Response, Timings, Url,
+};
 use hurl::runner;
-use hurl::runner::{EntryResult, HurlResult, RunnerOptionsBuilder};
-use hurl::util::logger::LoggerOptionsBuilder;
+use hurl::runner::{AssertResult, EntryResult, EventListener, HurlResult, RunnerOptionsBuilder};
+use hurl::util::logger::{Logger, LoggerOptionsBuilder};
 use hurl::util::path::ContextDir;
 use hurl_core::input::Input;
 use hurl_core::typing::Count;
@@ -158,3 +162,131 @@ fn simple_sample() {
     let response = &call.response;
     check_response(response);
 }
+
+#[test]
+fn mock_client_sample() {
+    /// An HTTP client that answers every request with a JSON greeting, without any network access.
+    struct MockClient {
+        requests: Vec<RequestSpec>,
+    }
+
+    impl HttpClient for MockClient {
+        fn execute(
+            &mut self,
+            request_spec: &RequestSpec,
+            _options: &ClientOptions,
+            _logger: &mut Logger,
+        ) -> Result<Call, HttpError> {
+            self.requests.push(request_spec.clone());
+            let url = Url::from_str(&request_spec.url).unwrap();
+            let request = Request::new(
+                &request_spec.method.0,
+                url.clone(),
+                HeaderVec::new(),
+                vec![],
+            );
+            let mut headers = HeaderVec::new();
+            headers.push(Header::new("Content-Type", "application/json"));
+            let body = br#"{"greeting":"Hello World!"}"#.to_vec();
+            let response = Response::new(
+                HttpVersion::Http11,
+                200,
+                headers,
+                body,
+                Duration::default(),
+                url,
+                None,
+            );
+            Ok(Call {
+                request,
+                response,
+                timings: Timings::default(),
+            })
+        }
+
+        fn cookie_storage(&mut self) -> Vec<Cookie> {
+            vec![]
+        }
+
+        fn add_cookie(&mut self, _cookie: &Cookie, _options: &ClientOptions) {}
+
+        fn clear_cookie_storage(&mut self, _options: &ClientOptions) {}
+    }
+
+    /// A listener that records the run events.
+    struct Recorder {
+        events: RefCell<Vec<String>>,
+    }
+
+    impl EventListener for Recorder {
+        fn on_entry_started(&self, entry_index: usize, entry_count: usize) {
+            let event = format!("started {entry_index}/{entry_count}");
+            self.events.borrow_mut().push(event);
+        }
+
+        fn on_request_sent(&self, entry_index: usize, request_spec: &RequestSpec) {
+            let event = format!("request {entry_index} {}", request_spec.url);
+            self.events.borrow_mut().push(event);
+        }
+
+        fn on_response_received(&self, entry_index: usize, call: &Call) {
+            let event = format!("response {entry_index} {}", call.response.status);
+            self.events.borrow_mut().push(event);
+        }
+
+        fn on_assert_evaluated(&self, entry_index: usize, assert: &AssertResult) {
+            let status = if assert.error().is_none() { "ok" } else { "ko" };
+            let event = format!("assert {entry_index} {status}");
+            self.events.borrow_mut().push(event);
+        }
+
+        fn on_entry_finished(&self, entry_index: usize, result: &EntryResult) {
+            let event = format!("finished {entry_index} {}", result.errors.len());
+            self.events.borrow_mut().push(event);
+        }
+    }
+
+    let content = r#"
+    GET http://example.org/hello
+    HTTP 200
+    Content-Type: application/json
+    [Asserts]
+    jsonpath "$.greeting" == "Hello World!"
+    "#;
+
+    let mut http_client = MockClient { requests: vec![] };
+    let recorder = Recorder {
+        events: RefCell::new(vec![]),
+    };
+    let runner_opts = RunnerOptionsBuilder::new().build();
+    let logger_opts = LoggerOptionsBuilder::new().build();
+    let variables = HashMap::default();
+
+    let result = runner::run_with_client(
+        content,
+        None,
+        &mut http_client,
+        &runner_opts,
+        &variables,
+        Some(&recorder),
+        &logger_opts,
+    )
+    .unwrap();
+    assert!(result.success);
+    assert_eq!(result.entries.len(), 1);
+    assert_eq!(http_client.requests.len(), 1);
+    assert_eq!(http_client.requests[0].url, "http://example.org/hello");
+    assert_eq!(
+        recorder.events.into_inner(),
+        vec![
+            "started 0/1",
+            "request 0 http://example.org/hello",
+            "response 0 200",
+            "assert 0 ok", // HTTP version
+            "assert 0 ok", // Status code
+            "assert 0 ok", // Content-Type header
+            "assert 0 ok", // JSONPath assert
+            "finished 0 0",
+        ]
+    );
+}
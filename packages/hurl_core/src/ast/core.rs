@@ -90,6 +90,15 @@ impl Request {
         vec![]
     }
 
+    pub fn frames(&self) -> Vec<Frame> {
+        for section in &self.sections {
+            if let SectionValue::WebSocket(frames) = &section.value {
+                return frames.clone();
+            }
+        }
+        vec![]
+    }
+
     pub fn basic_auth(&self) -> Option<KeyValue> {
         for section in &self.sections {
             if let SectionValue::BasicAuth(kv) = &section.value {
@@ -174,6 +183,9 @@ pub struct Status {
 pub enum StatusValue {
     Any,
     Specific(u64),
+    /// A class of status codes, `2` for `2xx`.
+    Class(u64),
+    List(Vec<u64>),
 }
 
 pub type Header = KeyValue;
@@ -210,6 +222,7 @@ impl Section {
             SectionValue::Captures(_) => "Captures",
             SectionValue::MultipartFormData(_) => "MultipartFormData",
             SectionValue::Options(_) => "Options",
+            SectionValue::WebSocket(_) => "WebSocket",
         }
     }
 }
@@ -225,6 +238,7 @@ pub enum SectionValue {
     Captures(Vec<Capture>),
     Asserts(Vec<Assert>),
     Options(Vec<EntryOption>),
+    WebSocket(Vec<Frame>),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -238,6 +252,35 @@ pub struct Cookie {
     pub line_terminator0: LineTerminator,
 }
 
+/// A frame sent over a WebSocket, once the connection has been opened.
+///
+/// The value of a binary frame is written in hexadecimal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub kind: FrameKind,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    pub value: Template,
+    pub line_terminator0: LineTerminator,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FrameKind {
+    Text,
+    Binary,
+}
+
+impl FrameKind {
+    pub fn value(&self) -> &'static str {
+        match self {
+            FrameKind::Text => "text",
+            FrameKind::Binary => "binary",
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct KeyValue {
     pub line_terminators: Vec<LineTerminator>,
@@ -284,6 +327,8 @@ pub struct Capture {
     pub space2: Whitespace,
     pub query: Query,
     pub filters: Vec<(Whitespace, Filter)>,
+    pub space3: Whitespace,
+    pub redacted: bool,
     pub line_terminator0: LineTerminator,
 }
 
@@ -309,6 +354,7 @@ pub struct Query {
 pub enum QueryValue {
     Status,
     Url,
+    Redirects,
     Header {
         space0: Whitespace,
         name: Template,
@@ -335,9 +381,16 @@ pub enum QueryValue {
         name: Template,
     },
     Duration,
+    Timing {
+        space0: Whitespace,
+        attribute_name: TimingAttributeName,
+    },
     Bytes,
     Sha256,
+    Sha1,
     Md5,
+    Frames,
+    Events,
     Certificate {
         space0: Whitespace,
         attribute_name: CertificateAttributeName,
@@ -399,6 +452,29 @@ pub enum CertificateAttributeName {
     SerialNumber,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimingAttributeName {
+    NameLookup,
+    Connect,
+    AppConnect,
+    PreTransfer,
+    StartTransfer,
+    Total,
+}
+
+impl TimingAttributeName {
+    pub fn value(&self) -> &'static str {
+        match self {
+            TimingAttributeName::NameLookup => "name_lookup",
+            TimingAttributeName::Connect => "connect",
+            TimingAttributeName::AppConnect => "app_connect",
+            TimingAttributeName::PreTransfer => "pre_transfer",
+            TimingAttributeName::StartTransfer => "start_transfer",
+            TimingAttributeName::Total => "total",
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Predicate {
     pub not: bool,
@@ -486,6 +562,10 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    MatchSchema {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
     IsInteger,
     IsFloat,
     IsBoolean,
@@ -717,8 +797,10 @@ pub enum OptionKind {
     ClientCert(Template),
     ClientKey(Template),
     Compressed(BooleanOption),
+    ConnectTimeout(DurationOption),
     ConnectTo(Template),
     Delay(DurationOption),
+    Digest(BooleanOption),
     Http10(BooleanOption),
     Http11(BooleanOption),
     Http2(BooleanOption),
@@ -729,22 +811,29 @@ pub enum OptionKind {
     FollowLocation(BooleanOption),
     FollowLocationTrusted(BooleanOption),
     MaxRedirect(CountOption),
+    MaxTime(DurationOption),
     NetRc(BooleanOption),
     NetRcFile(Template),
     NetRcOptional(BooleanOption),
+    Ntlm(BooleanOption),
+    OnlyIf(Condition),
     Output(Template),
     PathAsIs(BooleanOption),
+    Proto(Template),
     Proxy(Template),
     Repeat(CountOption),
     Resolve(Template),
     Retry(CountOption),
     RetryInterval(DurationOption),
     Skip(BooleanOption),
+    StreamCount(CountOption),
+    StreamTimeout(DurationOption),
     UnixSocket(Template),
     User(Template),
     Variable(VariableDefinition),
     Verbose(BooleanOption),
     VeryVerbose(BooleanOption),
+    XmlNamespace(XmlNamespace),
 }
 
 impl OptionKind {
@@ -755,8 +844,10 @@ impl OptionKind {
             OptionKind::ClientCert(_) => "cert",
             OptionKind::ClientKey(_) => "key",
             OptionKind::Compressed(_) => "compressed",
+            OptionKind::ConnectTimeout(_) => "connect-timeout",
             OptionKind::ConnectTo(_) => "connect-to",
             OptionKind::Delay(_) => "delay",
+            OptionKind::Digest(_) => "digest",
             OptionKind::FollowLocation(_) => "location",
             OptionKind::FollowLocationTrusted(_) => "location-trusted",
             OptionKind::Http10(_) => "http1.0",
@@ -767,22 +858,29 @@ impl OptionKind {
             OptionKind::IpV4(_) => "ipv4",
             OptionKind::IpV6(_) => "ipv6",
             OptionKind::MaxRedirect(_) => "max-redirs",
+            OptionKind::MaxTime(_) => "max-time",
             OptionKind::NetRc(_) => "netrc",
             OptionKind::NetRcFile(_) => "netrc-file",
             OptionKind::NetRcOptional(_) => "netrc-optional",
+            OptionKind::Ntlm(_) => "ntlm",
+            OptionKind::OnlyIf(_) => "only-if",
             OptionKind::Output(_) => "output",
             OptionKind::PathAsIs(_) => "path-as-is",
+            OptionKind::Proto(_) => "proto",
             OptionKind::Proxy(_) => "proxy",
             OptionKind::Repeat(_) => "repeat",
             OptionKind::Resolve(_) => "resolve",
             OptionKind::Retry(_) => "retry",
             OptionKind::RetryInterval(_) => "retry-interval",
             OptionKind::Skip(_) => "skip",
+            OptionKind::StreamCount(_) => "stream-count",
+            OptionKind::StreamTimeout(_) => "stream-timeout",
             OptionKind::UnixSocket(_) => "unix-socket",
             OptionKind::User(_) => "user",
             OptionKind::Variable(_) => "variable",
             OptionKind::Verbose(_) => "verbose",
             OptionKind::VeryVerbose(_) => "very-verbose",
+            OptionKind::XmlNamespace(_) => "xmlns",
         }
     }
 
@@ -793,8 +891,10 @@ impl OptionKind {
             OptionKind::ClientCert(filename) => filename.to_string(),
             OptionKind::ClientKey(filename) => filename.to_string(),
             OptionKind::Compressed(value) => value.to_string(),
+            OptionKind::ConnectTimeout(value) => value.to_string(),
             OptionKind::ConnectTo(value) => value.to_string(),
             OptionKind::Delay(value) => value.to_string(),
+            OptionKind::Digest(value) => value.to_string(),
             OptionKind::FollowLocation(value) => value.to_string(),
             OptionKind::FollowLocationTrusted(value) => value.to_string(),
             OptionKind::Http10(value) => value.to_string(),
@@ -805,17 +905,23 @@ impl OptionKind {
             OptionKind::IpV4(value) => value.to_string(),
             OptionKind::IpV6(value) => value.to_string(),
             OptionKind::MaxRedirect(value) => value.to_string(),
+            OptionKind::MaxTime(value) => value.to_string(),
             OptionKind::NetRc(value) => value.to_string(),
             OptionKind::NetRcFile(filename) => filename.to_string(),
             OptionKind::NetRcOptional(value) => value.to_string(),
+            OptionKind::Ntlm(value) => value.to_string(),
+            OptionKind::OnlyIf(value) => value.to_string(),
             OptionKind::Output(filename) => filename.to_string(),
             OptionKind::PathAsIs(value) => value.to_string(),
+            OptionKind::Proto(filename) => filename.to_string(),
             OptionKind::Proxy(value) => value.to_string(),
             OptionKind::Repeat(value) => value.to_string(),
             OptionKind::Resolve(value) => value.to_string(),
             OptionKind::Retry(value) => value.to_string(),
             OptionKind::RetryInterval(value) => value.to_string(),
             OptionKind::Skip(value) => value.to_string(),
+            OptionKind::StreamCount(value) => value.to_string(),
+            OptionKind::StreamTimeout(value) => value.to_string(),
             OptionKind::UnixSocket(value) => value.to_string(),
             OptionKind::User(value) => value.to_string(),
             OptionKind::Variable(VariableDefinition { name, value, .. }) => {
@@ -823,6 +929,9 @@ impl OptionKind {
             }
             OptionKind::Verbose(value) => value.to_string(),
             OptionKind::VeryVerbose(value) => value.to_string(),
+            OptionKind::XmlNamespace(XmlNamespace { prefix, uri, .. }) => {
+                format!("{prefix}={uri}")
+            }
         }
     }
 }
@@ -851,6 +960,14 @@ pub enum DurationOption {
     Expression(Expr),
 }
 
+/// A condition on a variable, like `{{env}} == "staging"`, tested before running an entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Condition {
+    pub expr: Expr,
+    pub space0: Whitespace,
+    pub predicate: Predicate,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct VariableDefinition {
     pub name: String,
@@ -859,6 +976,16 @@ pub struct VariableDefinition {
     pub value: VariableValue,
 }
 
+/// A XML namespace declaration, like `soap=http://schemas.xmlsoap.org/soap/envelope/`, whose
+/// prefix can be used in XPath queries.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XmlNamespace {
+    pub prefix: String,
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub uri: Template,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum VariableValue {
     Null,
@@ -875,6 +1002,8 @@ pub struct Filter {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum FilterValue {
+    Base64Decode,
+    Base64Encode,
     Count,
     DaysAfterNow,
     DaysBeforeNow,
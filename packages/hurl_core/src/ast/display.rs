@@ -56,6 +56,11 @@ impl fmt::Display for StatusValue {
         match self {
             StatusValue::Any => write!(f, "*"),
             StatusValue::Specific(v) => write!(f, "{v}"),
+            StatusValue::Class(v) => write!(f, "{v}xx"),
+            StatusValue::List(values) => {
+                let values = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+                write!(f, "{}", values.join(","))
+            }
         }
     }
 }
@@ -219,6 +224,12 @@ impl fmt::Display for VariableDefinition {
     }
 }
 
+impl fmt::Display for XmlNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.prefix, self.uri)
+    }
+}
+
 impl fmt::Display for VariableValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
@@ -231,6 +242,107 @@ impl fmt::Display for VariableValue {
     }
 }
 
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{{{}}}}} {}", self.expr, self.predicate)
+    }
+}
+
+impl fmt::Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.not {
+            write!(f, "not ")?;
+        }
+        let value = &self.predicate_func.value;
+        write!(f, "{}", value.name())?;
+        match value {
+            PredicateFuncValue::Equal { value, .. }
+            | PredicateFuncValue::NotEqual { value, .. }
+            | PredicateFuncValue::GreaterThan { value, .. }
+            | PredicateFuncValue::GreaterThanOrEqual { value, .. }
+            | PredicateFuncValue::LessThan { value, .. }
+            | PredicateFuncValue::LessThanOrEqual { value, .. }
+            | PredicateFuncValue::StartWith { value, .. }
+            | PredicateFuncValue::EndWith { value, .. }
+            | PredicateFuncValue::Contain { value, .. }
+            | PredicateFuncValue::Include { value, .. }
+            | PredicateFuncValue::Match { value, .. }
+            | PredicateFuncValue::MatchSchema { value, .. } => write!(f, " {value}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for PredicateValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PredicateValue::Base64(value) => write!(f, "base64,{};", value.encoded),
+            PredicateValue::Bool(value) => write!(f, "{value}"),
+            PredicateValue::Expression(value) => write!(f, "{{{{{value}}}}}"),
+            PredicateValue::File(value) => write!(f, "file,{};", encoded(&value.filename)),
+            PredicateValue::Hex(value) => write!(f, "{value}"),
+            PredicateValue::MultilineString(value) => write!(f, "{}", encoded_multiline(value)),
+            PredicateValue::Null => write!(f, "null"),
+            PredicateValue::Number(value) => write!(f, "{value}"),
+            PredicateValue::Regex(value) => {
+                write!(f, "/{}/", value.inner.as_str().replace('/', "\\/"))
+            }
+            PredicateValue::String(value) => {
+                let delimiter = value.delimiter.unwrap_or('"');
+                write!(f, "{delimiter}{}{delimiter}", encoded(value))
+            }
+        }
+    }
+}
+
+/// Returns the source form of a `template`, with its escaped characters, as written by hurlfmt.
+fn encoded(template: &Template) -> String {
+    let mut s = String::new();
+    for element in template.elements.iter() {
+        match element {
+            TemplateElement::String { encoded, .. } => s.push_str(encoded),
+            TemplateElement::Expression(expr) => s.push_str(&format!(
+                "{{{{{}{}{}}}}}",
+                expr.space0.value, expr.variable.name, expr.space1.value
+            )),
+        }
+    }
+    s
+}
+
+/// Returns the source form of a `multiline` string, fences included, as written by hurlfmt.
+fn encoded_multiline(multiline: &MultilineString) -> String {
+    let mut s = format!("```{}", multiline.lang());
+    for (i, attribute) in multiline.attributes.iter().enumerate() {
+        if i > 0 || !multiline.lang().is_empty() {
+            s.push(',');
+        }
+        s.push_str(&attribute.to_string());
+    }
+    match &multiline.kind {
+        MultilineStringKind::Text(text)
+        | MultilineStringKind::Json(text)
+        | MultilineStringKind::Xml(text) => {
+            s.push_str(&text.space.value);
+            s.push_str(&text.newline.value);
+            s.push_str(&encoded(&text.value));
+        }
+        MultilineStringKind::GraphQl(graphql) => {
+            s.push_str(&graphql.space.value);
+            s.push_str(&graphql.newline.value);
+            s.push_str(&encoded(&graphql.value));
+            if let Some(var) = &graphql.variables {
+                s.push_str(&format!(
+                    "variables{}{}{}",
+                    var.space.value, var.value, var.whitespace.value
+                ));
+            }
+        }
+    }
+    s.push_str("```");
+    s
+}
+
 impl PredicateFuncValue {
     pub fn name(&self) -> String {
         match self {
@@ -281,6 +393,7 @@ impl PredicateFuncValue {
             PredicateFuncValue::Contain { .. } => "contains".to_string(),
             PredicateFuncValue::Include { .. } => "includes".to_string(),
             PredicateFuncValue::Match { .. } => "matches".to_string(),
+            PredicateFuncValue::MatchSchema { .. } => "matchesSchema".to_string(),
             PredicateFuncValue::IsInteger => "isInteger".to_string(),
             PredicateFuncValue::IsFloat => "isFloat".to_string(),
             PredicateFuncValue::IsBoolean => "isBoolean".to_string(),
@@ -372,6 +485,33 @@ mod tests {
         );
     }
 
+    /// Returns the `only-if` condition of the first entry of `content`.
+    fn condition(content: &str) -> Condition {
+        let hurl_file = crate::parser::parse_hurl_file(content).unwrap();
+        let options = hurl_file.entries[0].request.options();
+        let OptionKind::OnlyIf(condition) = &options[0].kind else {
+            panic!("only-if option expected");
+        };
+        condition.clone()
+    }
+
+    #[test]
+    fn test_condition_round_trip() {
+        for source in [
+            r#"{{env}} == "staging""#,
+            r#"{{env}} == "say \"hi\"""#,
+            r#"{{path}} startsWith "C:\\Users\\{{ user }}""#,
+            r#"{{url}} matches /^https:\/\/.*$/"#,
+            r#"{{env}} not contains "\u{2022}""#,
+            "{{body}} == ```\nline 1\nline \\2\n```",
+            "{{body}} == ```json,escape\n{\"a\": \\u{2022}}\n```",
+            "{{data}} == file,data\\ 1.bin;",
+        ] {
+            let content = format!("GET http://localhost:8000\n[Options]\nonly-if: {source}\n");
+            assert_eq!(condition(&content).to_string(), source);
+        }
+    }
+
     #[test]
     fn test_template() {
         assert_eq!(hello_template().to_string(), "Hello {{name}}!");
@@ -24,3 +24,4 @@ pub use self::json::{
 mod core;
 mod display;
 mod json;
+pub mod visit;
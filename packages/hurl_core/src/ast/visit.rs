@@ -0,0 +1,425 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Walks the nodes of a Hurl AST.
+//!
+//! A [`Visitor`] implements the `visit_*` methods of the nodes it is interested in, the default
+//! implementations call the `walk_*` functions that visit the children of each node.
+use crate::ast::*;
+
+/// A visitor of the nodes of a Hurl AST, used to look for expressions and templates.
+///
+/// The default implementation of each method walks the children of the visited node. Overriding
+/// a method without calling the matching `walk_*` function stops the walk of this node.
+pub trait Visitor {
+    fn visit_hurl_file(&mut self, hurl_file: &HurlFile) {
+        walk_hurl_file(self, hurl_file);
+    }
+
+    fn visit_entry(&mut self, entry: &Entry) {
+        walk_entry(self, entry);
+    }
+
+    fn visit_section(&mut self, section: &Section) {
+        walk_section(self, section);
+    }
+
+    fn visit_entry_option(&mut self, option: &EntryOption) {
+        walk_entry_option(self, option);
+    }
+
+    fn visit_capture(&mut self, capture: &Capture) {
+        walk_capture(self, capture);
+    }
+
+    fn visit_assert(&mut self, assert: &Assert) {
+        walk_assert(self, assert);
+    }
+
+    fn visit_template(&mut self, template: &Template) {
+        walk_template(self, template);
+    }
+
+    fn visit_expr(&mut self, _expr: &Expr) {}
+}
+
+pub fn walk_hurl_file<V: Visitor + ?Sized>(visitor: &mut V, hurl_file: &HurlFile) {
+    for entry in &hurl_file.entries {
+        visitor.visit_entry(entry);
+    }
+}
+
+pub fn walk_entry<V: Visitor + ?Sized>(visitor: &mut V, entry: &Entry) {
+    let request = &entry.request;
+    visitor.visit_template(&request.url);
+    for header in &request.headers {
+        walk_key_value(visitor, header);
+    }
+    for section in &request.sections {
+        visitor.visit_section(section);
+    }
+    if let Some(body) = &request.body {
+        walk_bytes(visitor, &body.value);
+    }
+    if let Some(response) = &entry.response {
+        for header in &response.headers {
+            walk_key_value(visitor, header);
+        }
+        for section in &response.sections {
+            visitor.visit_section(section);
+        }
+        if let Some(body) = &response.body {
+            walk_bytes(visitor, &body.value);
+        }
+    }
+}
+
+pub fn walk_section<V: Visitor + ?Sized>(visitor: &mut V, section: &Section) {
+    match &section.value {
+        SectionValue::QueryParams(params) | SectionValue::FormParams(params) => {
+            for param in params {
+                walk_key_value(visitor, param);
+            }
+        }
+        SectionValue::BasicAuth(Some(param)) => walk_key_value(visitor, param),
+        SectionValue::BasicAuth(None) => {}
+        SectionValue::MultipartFormData(params) => {
+            for param in params {
+                match param {
+                    MultipartParam::Param(param) => walk_key_value(visitor, param),
+                    MultipartParam::FileParam(param) => {
+                        visitor.visit_template(&param.key);
+                        visitor.visit_template(&param.value.filename);
+                    }
+                }
+            }
+        }
+        SectionValue::Cookies(cookies) => {
+            for cookie in cookies {
+                visitor.visit_template(&cookie.name);
+                visitor.visit_template(&cookie.value);
+            }
+        }
+        SectionValue::Captures(captures) => {
+            for capture in captures {
+                visitor.visit_capture(capture);
+            }
+        }
+        SectionValue::Asserts(asserts) => {
+            for assert in asserts {
+                visitor.visit_assert(assert);
+            }
+        }
+        SectionValue::Options(options) => {
+            for option in options {
+                visitor.visit_entry_option(option);
+            }
+        }
+        SectionValue::WebSocket(frames) => {
+            for frame in frames {
+                visitor.visit_template(&frame.value);
+            }
+        }
+    }
+}
+
+pub fn walk_entry_option<V: Visitor + ?Sized>(visitor: &mut V, option: &EntryOption) {
+    match &option.kind {
+        OptionKind::AwsSigV4(value)
+        | OptionKind::CaCertificate(value)
+        | OptionKind::ClientCert(value)
+        | OptionKind::ClientKey(value)
+        | OptionKind::ConnectTo(value)
+        | OptionKind::NetRcFile(value)
+        | OptionKind::Output(value)
+        | OptionKind::Proto(value)
+        | OptionKind::Proxy(value)
+        | OptionKind::Resolve(value)
+        | OptionKind::UnixSocket(value)
+        | OptionKind::User(value) => visitor.visit_template(value),
+        OptionKind::Compressed(value)
+        | OptionKind::Digest(value)
+        | OptionKind::Http10(value)
+        | OptionKind::Http11(value)
+        | OptionKind::Http2(value)
+        | OptionKind::Http3(value)
+        | OptionKind::Insecure(value)
+        | OptionKind::IpV4(value)
+        | OptionKind::IpV6(value)
+        | OptionKind::FollowLocation(value)
+        | OptionKind::FollowLocationTrusted(value)
+        | OptionKind::NetRc(value)
+        | OptionKind::NetRcOptional(value)
+        | OptionKind::Ntlm(value)
+        | OptionKind::PathAsIs(value)
+        | OptionKind::Skip(value)
+        | OptionKind::Verbose(value)
+        | OptionKind::VeryVerbose(value) => {
+            if let BooleanOption::Expression(expr) = value {
+                visitor.visit_expr(expr);
+            }
+        }
+        OptionKind::ConnectTimeout(value)
+        | OptionKind::Delay(value)
+        | OptionKind::MaxTime(value)
+        | OptionKind::RetryInterval(value)
+        | OptionKind::StreamTimeout(value) => {
+            if let DurationOption::Expression(expr) = value {
+                visitor.visit_expr(expr);
+            }
+        }
+        OptionKind::MaxRedirect(value)
+        | OptionKind::Repeat(value)
+        | OptionKind::Retry(value)
+        | OptionKind::StreamCount(value) => {
+            if let CountOption::Expression(expr) = value {
+                visitor.visit_expr(expr);
+            }
+        }
+        OptionKind::OnlyIf(condition) => {
+            visitor.visit_expr(&condition.expr);
+            walk_predicate(visitor, &condition.predicate);
+        }
+        OptionKind::Variable(definition) => {
+            if let VariableValue::String(value) = &definition.value {
+                visitor.visit_template(value);
+            }
+        }
+        OptionKind::XmlNamespace(namespace) => visitor.visit_template(&namespace.uri),
+    }
+}
+
+pub fn walk_capture<V: Visitor + ?Sized>(visitor: &mut V, capture: &Capture) {
+    visitor.visit_template(&capture.name);
+    walk_query(visitor, &capture.query);
+    for (_, filter) in &capture.filters {
+        walk_filter(visitor, filter);
+    }
+}
+
+pub fn walk_assert<V: Visitor + ?Sized>(visitor: &mut V, assert: &Assert) {
+    walk_query(visitor, &assert.query);
+    for (_, filter) in &assert.filters {
+        walk_filter(visitor, filter);
+    }
+    walk_predicate(visitor, &assert.predicate);
+}
+
+pub fn walk_template<V: Visitor + ?Sized>(visitor: &mut V, template: &Template) {
+    for element in &template.elements {
+        if let TemplateElement::Expression(expr) = element {
+            visitor.visit_expr(expr);
+        }
+    }
+}
+
+fn walk_key_value<V: Visitor + ?Sized>(visitor: &mut V, key_value: &KeyValue) {
+    visitor.visit_template(&key_value.key);
+    visitor.visit_template(&key_value.value);
+}
+
+fn walk_bytes<V: Visitor + ?Sized>(visitor: &mut V, bytes: &Bytes) {
+    match bytes {
+        Bytes::Json(value) => walk_json_value(visitor, value),
+        Bytes::MultilineString(value) => walk_multiline_string(visitor, value),
+        Bytes::OnelineString(value) => visitor.visit_template(value),
+        Bytes::File(file) => visitor.visit_template(&file.filename),
+        Bytes::Xml(_) | Bytes::Base64(_) | Bytes::Hex(_) => {}
+    }
+}
+
+fn walk_multiline_string<V: Visitor + ?Sized>(visitor: &mut V, multiline: &MultilineString) {
+    match &multiline.kind {
+        MultilineStringKind::Text(text)
+        | MultilineStringKind::Json(text)
+        | MultilineStringKind::Xml(text) => visitor.visit_template(&text.value),
+        MultilineStringKind::GraphQl(graphql) => {
+            visitor.visit_template(&graphql.value);
+            if let Some(variables) = &graphql.variables {
+                walk_json_value(visitor, &variables.value);
+            }
+        }
+    }
+}
+
+fn walk_json_value<V: Visitor + ?Sized>(visitor: &mut V, value: &JsonValue) {
+    match value {
+        JsonValue::Expression(expr) => visitor.visit_expr(expr),
+        JsonValue::String(template) => visitor.visit_template(template),
+        JsonValue::List { elements, .. } => {
+            for element in elements {
+                walk_json_value(visitor, &element.value);
+            }
+        }
+        JsonValue::Object { elements, .. } => {
+            for element in elements {
+                visitor.visit_template(&element.name);
+                walk_json_value(visitor, &element.value);
+            }
+        }
+        JsonValue::Number(_) | JsonValue::Boolean(_) | JsonValue::Null => {}
+    }
+}
+
+fn walk_query<V: Visitor + ?Sized>(visitor: &mut V, query: &Query) {
+    match &query.value {
+        QueryValue::Header { name, .. } | QueryValue::Variable { name, .. } => {
+            visitor.visit_template(name);
+        }
+        QueryValue::Cookie { expr, .. } => visitor.visit_template(&expr.name),
+        QueryValue::Xpath { expr, .. } | QueryValue::Jsonpath { expr, .. } => {
+            visitor.visit_template(expr);
+        }
+        QueryValue::Regex { value, .. } => walk_regex_value(visitor, value),
+        QueryValue::Status
+        | QueryValue::Url
+        | QueryValue::Redirects
+        | QueryValue::Body
+        | QueryValue::Duration
+        | QueryValue::Timing { .. }
+        | QueryValue::Bytes
+        | QueryValue::Sha256
+        | QueryValue::Sha1
+        | QueryValue::Md5
+        | QueryValue::Frames
+        | QueryValue::Events
+        | QueryValue::Certificate { .. } => {}
+    }
+}
+
+fn walk_filter<V: Visitor + ?Sized>(visitor: &mut V, filter: &Filter) {
+    match &filter.value {
+        FilterValue::Decode {
+            encoding: value, ..
+        }
+        | FilterValue::Format { fmt: value, .. }
+        | FilterValue::JsonPath { expr: value, .. }
+        | FilterValue::Split { sep: value, .. }
+        | FilterValue::ToDate { fmt: value, .. }
+        | FilterValue::XPath { expr: value, .. } => visitor.visit_template(value),
+        FilterValue::Regex { value, .. } => walk_regex_value(visitor, value),
+        FilterValue::Replace {
+            old_value,
+            new_value,
+            ..
+        } => {
+            walk_regex_value(visitor, old_value);
+            visitor.visit_template(new_value);
+        }
+        FilterValue::Base64Decode
+        | FilterValue::Base64Encode
+        | FilterValue::Count
+        | FilterValue::DaysAfterNow
+        | FilterValue::DaysBeforeNow
+        | FilterValue::HtmlEscape
+        | FilterValue::HtmlUnescape
+        | FilterValue::Nth { .. }
+        | FilterValue::ToFloat
+        | FilterValue::ToInt
+        | FilterValue::UrlDecode
+        | FilterValue::UrlEncode => {}
+    }
+}
+
+fn walk_regex_value<V: Visitor + ?Sized>(visitor: &mut V, value: &RegexValue) {
+    if let RegexValue::Template(template) = value {
+        visitor.visit_template(template);
+    }
+}
+
+fn walk_predicate<V: Visitor + ?Sized>(visitor: &mut V, predicate: &Predicate) {
+    let value = match &predicate.predicate_func.value {
+        PredicateFuncValue::Equal { value, .. }
+        | PredicateFuncValue::NotEqual { value, .. }
+        | PredicateFuncValue::GreaterThan { value, .. }
+        | PredicateFuncValue::GreaterThanOrEqual { value, .. }
+        | PredicateFuncValue::LessThan { value, .. }
+        | PredicateFuncValue::LessThanOrEqual { value, .. }
+        | PredicateFuncValue::StartWith { value, .. }
+        | PredicateFuncValue::EndWith { value, .. }
+        | PredicateFuncValue::Contain { value, .. }
+        | PredicateFuncValue::Include { value, .. }
+        | PredicateFuncValue::Match { value, .. }
+        | PredicateFuncValue::MatchSchema { value, .. } => value,
+        PredicateFuncValue::IsInteger
+        | PredicateFuncValue::IsFloat
+        | PredicateFuncValue::IsBoolean
+        | PredicateFuncValue::IsString
+        | PredicateFuncValue::IsCollection
+        | PredicateFuncValue::IsDate
+        | PredicateFuncValue::IsIsoDate
+        | PredicateFuncValue::Exist
+        | PredicateFuncValue::IsEmpty
+        | PredicateFuncValue::IsNumber => return,
+    };
+    match value {
+        PredicateValue::Expression(expr) => visitor.visit_expr(expr),
+        PredicateValue::File(file) => visitor.visit_template(&file.filename),
+        PredicateValue::MultilineString(value) => walk_multiline_string(visitor, value),
+        PredicateValue::String(template) => visitor.visit_template(template),
+        PredicateValue::Base64(_)
+        | PredicateValue::Bool(_)
+        | PredicateValue::Hex(_)
+        | PredicateValue::Null
+        | PredicateValue::Number(_)
+        | PredicateValue::Regex(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    /// Collects the names of all the expressions of an AST.
+    #[derive(Default)]
+    struct Names(Vec<String>);
+
+    impl Visitor for Names {
+        fn visit_expr(&mut self, expr: &Expr) {
+            self.0.push(expr.variable.name.clone());
+        }
+    }
+
+    #[test]
+    fn test_visit_expressions() {
+        let content = r#"# A {{comment}} is not an expression
+POST http://localhost:8000/{{a}}
+x-b: {{b}}
+[Options]
+delay: {{c}}
+only-if: {{d}} exists
+```json
+{"{{e}}": "{{f}}", "g": {{g}} }
+```
+HTTP 200
+[Captures]
+h: jsonpath "$.{{i}}" split "{{j}}"
+[Asserts]
+body == ```
+{{k}}
+```
+"#;
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        let mut names = Names::default();
+        names.visit_hurl_file(&hurl_file);
+        assert_eq!(
+            names.0,
+            vec!["a", "b", "c", "d", "e", "f", "g", "i", "j", "k"]
+        );
+    }
+}
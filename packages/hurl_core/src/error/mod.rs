@@ -36,7 +36,7 @@ pub trait DisplaySourceError {
     ///
     /// ```text
     /// GET abc
-    ///     ^ expecting http://, https:// or {{
+    ///     ^ expecting http://, https://, ws://, wss:// or {{
     /// ```
     ///
     /// ```text
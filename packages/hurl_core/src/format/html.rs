@@ -190,6 +190,7 @@ impl HtmlFormatter {
             SectionValue::Options(items) => {
                 items.iter().for_each(|item| self.fmt_entry_option(item));
             }
+            SectionValue::WebSocket(items) => items.iter().for_each(|item| self.fmt_frame(item)),
         }
     }
 
@@ -220,8 +221,10 @@ impl HtmlFormatter {
             OptionKind::ClientCert(filename) => self.fmt_filename(filename),
             OptionKind::ClientKey(filename) => self.fmt_filename(filename),
             OptionKind::Compressed(value) => self.fmt_bool_option(value),
+            OptionKind::ConnectTimeout(value) => self.fmt_duration_option(value),
             OptionKind::ConnectTo(value) => self.fmt_template(value),
             OptionKind::Delay(value) => self.fmt_duration_option(value),
+            OptionKind::Digest(value) => self.fmt_bool_option(value),
             OptionKind::FollowLocation(value) => self.fmt_bool_option(value),
             OptionKind::FollowLocationTrusted(value) => self.fmt_bool_option(value),
             OptionKind::Http10(value) => self.fmt_bool_option(value),
@@ -232,22 +235,29 @@ impl HtmlFormatter {
             OptionKind::IpV4(value) => self.fmt_bool_option(value),
             OptionKind::IpV6(value) => self.fmt_bool_option(value),
             OptionKind::MaxRedirect(value) => self.fmt_count_option(value),
+            OptionKind::MaxTime(value) => self.fmt_duration_option(value),
             OptionKind::NetRc(value) => self.fmt_bool_option(value),
             OptionKind::NetRcFile(filename) => self.fmt_filename(filename),
             OptionKind::NetRcOptional(value) => self.fmt_bool_option(value),
+            OptionKind::Ntlm(value) => self.fmt_bool_option(value),
+            OptionKind::OnlyIf(value) => self.fmt_condition(value),
             OptionKind::Output(filename) => self.fmt_filename(filename),
             OptionKind::PathAsIs(value) => self.fmt_bool_option(value),
+            OptionKind::Proto(filename) => self.fmt_filename(filename),
             OptionKind::Proxy(value) => self.fmt_template(value),
             OptionKind::Repeat(value) => self.fmt_count_option(value),
             OptionKind::Resolve(value) => self.fmt_template(value),
             OptionKind::Retry(value) => self.fmt_count_option(value),
             OptionKind::RetryInterval(value) => self.fmt_duration_option(value),
             OptionKind::Skip(value) => self.fmt_bool_option(value),
+            OptionKind::StreamCount(value) => self.fmt_count_option(value),
+            OptionKind::StreamTimeout(value) => self.fmt_duration_option(value),
             OptionKind::UnixSocket(value) => self.fmt_template(value),
             OptionKind::User(value) => self.fmt_template(value),
             OptionKind::Variable(value) => self.fmt_variable_definition(value),
             OptionKind::Verbose(value) => self.fmt_bool_option(value),
             OptionKind::VeryVerbose(value) => self.fmt_bool_option(value),
+            OptionKind::XmlNamespace(value) => self.fmt_xml_namespace(value),
         };
         self.fmt_span_close();
         self.fmt_lt(&option.line_terminator0);
@@ -274,6 +284,14 @@ impl HtmlFormatter {
         self.fmt_variable_value(&option.value);
     }
 
+    fn fmt_xml_namespace(&mut self, option: &XmlNamespace) {
+        self.buffer.push_str(option.prefix.as_str());
+        self.fmt_space(&option.space0);
+        self.buffer.push('=');
+        self.fmt_space(&option.space1);
+        self.fmt_template(&option.uri);
+    }
+
     fn fmt_variable_value(&mut self, option: &VariableValue) {
         match option {
             VariableValue::Null => self.fmt_span("null", "null"),
@@ -335,6 +353,19 @@ impl HtmlFormatter {
         self.fmt_lt(&cookie.line_terminator0);
     }
 
+    fn fmt_frame(&mut self, frame: &Frame) {
+        self.fmt_lts(&frame.line_terminators);
+        self.fmt_span_open("line");
+        self.fmt_space(&frame.space0);
+        self.fmt_span("string", frame.kind.value());
+        self.fmt_space(&frame.space1);
+        self.buffer.push(':');
+        self.fmt_space(&frame.space2);
+        self.fmt_template(&frame.value);
+        self.fmt_span_close();
+        self.fmt_lt(&frame.line_terminator0);
+    }
+
     fn fmt_capture(&mut self, capture: &Capture) {
         self.fmt_lts(&capture.line_terminators);
         self.fmt_span_open("line");
@@ -348,6 +379,10 @@ impl HtmlFormatter {
             self.fmt_space(space);
             self.fmt_filter(filter);
         }
+        if capture.redacted {
+            self.fmt_space(&capture.space3);
+            self.fmt_span("keyword", "redact");
+        }
         self.fmt_span_close();
         self.fmt_lt(&capture.line_terminator0);
     }
@@ -360,6 +395,7 @@ impl HtmlFormatter {
         match query_value {
             QueryValue::Status => self.fmt_span("query-type", "status"),
             QueryValue::Url => self.fmt_span("query-type", "url"),
+            QueryValue::Redirects => self.fmt_span("query-type", "redirects"),
             QueryValue::Header { space0, name } => {
                 self.fmt_span("query-type", "header");
                 self.fmt_space(space0);
@@ -392,9 +428,20 @@ impl HtmlFormatter {
                 self.fmt_template(name);
             }
             QueryValue::Duration => self.fmt_span("query-type", "duration"),
+            QueryValue::Timing {
+                space0,
+                attribute_name,
+            } => {
+                self.fmt_span("query-type", "duration");
+                self.fmt_space(space0);
+                self.fmt_timing_attribute_name(attribute_name);
+            }
             QueryValue::Bytes => self.fmt_span("query-type", "bytes"),
             QueryValue::Sha256 => self.fmt_span("query-type", "sha256"),
+            QueryValue::Sha1 => self.fmt_span("query-type", "sha1"),
             QueryValue::Md5 => self.fmt_span("query-type", "md5"),
+            QueryValue::Frames => self.fmt_span("query-type", "frames"),
+            QueryValue::Events => self.fmt_span("query-type", "events"),
             QueryValue::Certificate {
                 space0,
                 attribute_name: field,
@@ -448,6 +495,14 @@ impl HtmlFormatter {
         self.fmt_span_close();
     }
 
+    fn fmt_timing_attribute_name(&mut self, name: &TimingAttributeName) {
+        self.fmt_span_open("string");
+        self.buffer.push('"');
+        self.buffer.push_str(name.value());
+        self.buffer.push('"');
+        self.fmt_span_close();
+    }
+
     fn fmt_assert(&mut self, assert: &Assert) {
         self.fmt_lts(&assert.line_terminators);
         self.fmt_span_open("line");
@@ -525,6 +580,10 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
             }
+            PredicateFuncValue::MatchSchema { space0, value } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+            }
             PredicateFuncValue::IsInteger => {}
             PredicateFuncValue::IsFloat => {}
             PredicateFuncValue::IsBoolean => {}
@@ -687,6 +746,12 @@ impl HtmlFormatter {
         }
     }
 
+    fn fmt_condition(&mut self, condition: &Condition) {
+        self.fmt_expr(&condition.expr);
+        self.fmt_space(&condition.space0);
+        self.fmt_predicate(&condition.predicate);
+    }
+
     fn fmt_duration_option(&mut self, value: &DurationOption) {
         match value {
             DurationOption::Literal(value) => {
@@ -779,6 +844,8 @@ impl HtmlFormatter {
 
     fn fmt_filter_value(&mut self, filter_value: &FilterValue) {
         match filter_value {
+            FilterValue::Base64Decode => self.fmt_span("filter-type", "base64Decode"),
+            FilterValue::Base64Encode => self.fmt_span("filter-type", "base64Encode"),
             FilterValue::Count => self.fmt_span("filter-type", "count"),
             FilterValue::DaysAfterNow => self.fmt_span("filter-type", "daysAfterNow"),
             FilterValue::DaysBeforeNow => self.fmt_span("filter-type", "daysBeforeNow"),
@@ -158,6 +158,7 @@ impl DisplaySourceError for ParseError {
                     "cacert",
                     "cert",
                     "compressed",
+                    "connect-timeout",
                     "connect-to",
                     "delay",
                     "insecure",
@@ -170,17 +171,23 @@ impl DisplaySourceError for ParseError {
                     "key",
                     "location",
                     "max-redirs",
+                    "max-time",
+                    "only-if",
                     "output",
                     "path-as-is",
+                    "proto",
                     "proxy",
                     "resolve",
                     "retry",
                     "retry-interval",
                     "skip",
+                    "stream-count",
+                    "stream-timeout",
                     "unix-socket",
                     "variable",
                     "verbose",
                     "very-verbose",
+                    "xmlns",
                 ];
                 let default = format!("Valid values are {}", valid_values.join(", "));
                 let did_you_mean = did_you_mean(&valid_values, name.as_str(), &default);
@@ -222,6 +229,7 @@ impl DisplaySourceError for ParseError {
                     "MultipartFormData",
                     "Cookies",
                     "Options",
+                    "WebSocket",
                 ];
                 let default = format!("Valid values are {}", valid_values.join(", "));
                 let did_you_mean = did_you_mean(&valid_values, name.as_str(), &default);
@@ -241,7 +249,9 @@ impl DisplaySourceError for ParseError {
             ParseErrorKind::TemplateVariable => "expecting a variable".to_string(),
             ParseErrorKind::Unicode => "Invalid unicode literal".to_string(),
             ParseErrorKind::UrlIllegalCharacter(c) => format!("illegal character <{c}>"),
-            ParseErrorKind::UrlInvalidStart => "expecting http://, https:// or {{".to_string(),
+            ParseErrorKind::UrlInvalidStart => {
+                "expecting http://, https://, ws://, wss:// or {{".to_string()
+            }
             ParseErrorKind::Version => {
                 "HTTP version must be HTTP, HTTP/1.0, HTTP/1.1 or HTTP/2".to_string()
             }
@@ -370,7 +380,7 @@ mod tests {
   --> test.hurl:1:5
    |
  1 | GET abc
-   |     ^ expecting http://, https:// or {{
+   |     ^ expecting http://, https://, ws://, wss:// or {{
    |"#
         );
     }
@@ -22,6 +22,17 @@ pub fn parse_hurl_file(s: &str) -> ParseResult<HurlFile> {
     parsers::hurl_file(&mut reader)
 }
 
+/// Parses a Hurl file `s`, recovering from errors.
+///
+/// Contrary to [`parse_hurl_file`], parsing doesn't stop at the first error: entries that can not be
+/// parsed are skipped. Returns a partial Hurl file, with the entries that have been successfully
+/// parsed, and the list of all the parsing errors. This is useful for tools working on files being
+/// edited.
+pub fn parse_hurl_file_with_recovery(s: &str) -> (HurlFile, Vec<ParseError>) {
+    let mut reader = Reader::new(s);
+    parsers::hurl_file_with_recovery(&mut reader)
+}
+
 pub use self::error::{JsonErrorVariant, ParseError, ParseErrorKind};
 pub use self::json::{
     boolean_value as parse_json_boolean, null_value as parse_json_null,
@@ -54,4 +65,9 @@ mod sections;
 mod string;
 mod template;
 mod url;
+#[cfg(feature = "libxml")]
 mod xml;
+#[cfg(any(test, not(feature = "libxml")))]
+mod xml_fallback;
+#[cfg(not(feature = "libxml"))]
+use self::xml_fallback as xml;
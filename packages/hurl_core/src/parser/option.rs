@@ -20,6 +20,7 @@ use crate::combinator::{choice, non_recover};
 use crate::parser::duration::duration;
 use crate::parser::error::*;
 use crate::parser::number::{integer, number};
+use crate::parser::predicate::predicate;
 use crate::parser::primitives::*;
 use crate::parser::string::*;
 use crate::parser::{expr, filename, filename_password, ParseResult};
@@ -44,8 +45,10 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
         "cacert" => option_cacert(reader)?,
         "cert" => option_cert(reader)?,
         "compressed" => option_compressed(reader)?,
+        "connect-timeout" => option_connect_timeout(reader)?,
         "connect-to" => option_connect_to(reader)?,
         "delay" => option_delay(reader)?,
+        "digest" => option_digest(reader)?,
         "insecure" => option_insecure(reader)?,
         "http1.0" => option_http_10(reader)?,
         "http1.1" => option_http_11(reader)?,
@@ -57,22 +60,29 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
         "location" => option_follow_location(reader)?,
         "location-trusted" => option_follow_location_trusted(reader)?,
         "max-redirs" => option_max_redirect(reader)?,
+        "max-time" => option_max_time(reader)?,
         "netrc" => option_netrc(reader)?,
         "netrc-file" => option_netrc_file(reader)?,
         "netrc-optional" => option_netrc_optional(reader)?,
+        "ntlm" => option_ntlm(reader)?,
+        "only-if" => option_only_if(reader)?,
         "output" => option_output(reader)?,
         "path-as-is" => option_path_as_is(reader)?,
+        "proto" => option_proto(reader)?,
         "proxy" => option_proxy(reader)?,
         "repeat" => option_repeat(reader)?,
         "resolve" => option_resolve(reader)?,
         "retry" => option_retry(reader)?,
         "retry-interval" => option_retry_interval(reader)?,
         "skip" => option_skip(reader)?,
+        "stream-count" => option_stream_count(reader)?,
+        "stream-timeout" => option_stream_timeout(reader)?,
         "unix-socket" => option_unix_socket(reader)?,
         "user" => option_user(reader)?,
         "variable" => option_variable(reader)?,
         "verbose" => option_verbose(reader)?,
         "very-verbose" => option_very_verbose(reader)?,
+        "xmlns" => option_xmlns(reader)?,
         _ => {
             return Err(ParseError::new(
                 start.pos,
@@ -113,6 +123,11 @@ fn option_compressed(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Compressed(value))
 }
 
+fn option_connect_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = duration_option(reader)?;
+    Ok(OptionKind::ConnectTimeout(value))
+}
+
 fn option_connect_to(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::ConnectTo(value))
@@ -123,6 +138,11 @@ fn option_delay(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Delay(value))
 }
 
+fn option_digest(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::Digest(value))
+}
+
 fn option_follow_location(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::FollowLocation(value))
@@ -178,6 +198,11 @@ fn option_max_redirect(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::MaxRedirect(value))
 }
 
+fn option_max_time(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = duration_option(reader)?;
+    Ok(OptionKind::MaxTime(value))
+}
+
 fn option_netrc(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::NetRc(value))
@@ -193,6 +218,22 @@ fn option_netrc_optional(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::NetRcOptional(value))
 }
 
+fn option_ntlm(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::Ntlm(value))
+}
+
+fn option_only_if(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let expr = non_recover(expr::parse, reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let predicate = non_recover(predicate, reader)?;
+    Ok(OptionKind::OnlyIf(Condition {
+        expr,
+        space0,
+        predicate,
+    }))
+}
+
 fn option_output(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = filename::parse(reader)?;
     Ok(OptionKind::Output(value))
@@ -203,6 +244,11 @@ fn option_path_as_is(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::PathAsIs(value))
 }
 
+fn option_proto(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = filename::parse(reader)?;
+    Ok(OptionKind::Proto(value))
+}
+
 fn option_proxy(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::Proxy(value))
@@ -233,6 +279,16 @@ fn option_skip(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Skip(value))
 }
 
+fn option_stream_count(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(count_option, reader)?;
+    Ok(OptionKind::StreamCount(value))
+}
+
+fn option_stream_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(duration_option, reader)?;
+    Ok(OptionKind::StreamTimeout(value))
+}
+
 fn option_user(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::User(value))
@@ -258,6 +314,20 @@ fn option_very_verbose(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::VeryVerbose(value))
 }
 
+fn option_xmlns(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let prefix = xmlns_prefix(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    literal("=", reader)?;
+    let space1 = zero_or_more_spaces(reader)?;
+    let uri = unquoted_template(reader)?;
+    Ok(OptionKind::XmlNamespace(XmlNamespace {
+        prefix,
+        space0,
+        space1,
+        uri,
+    }))
+}
+
 fn count(reader: &mut Reader) -> ParseResult<Count> {
     let start = reader.cursor();
     let value = non_recover(integer, reader)?;
@@ -342,6 +412,18 @@ fn variable_definition(reader: &mut Reader) -> ParseResult<VariableDefinition> {
     })
 }
 
+fn xmlns_prefix(reader: &mut Reader) -> ParseResult<String> {
+    let start = reader.cursor();
+    let prefix = reader.read_while(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == '.');
+    if prefix.is_empty() {
+        let kind = ParseErrorKind::Expecting {
+            value: "namespace prefix".to_string(),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    }
+    Ok(prefix)
+}
+
 fn variable_name(reader: &mut Reader) -> ParseResult<String> {
     let start = reader.cursor();
     let name = reader.read_while(|c| c.is_alphanumeric() || c == '_' || c == '-');
@@ -566,6 +648,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_option_only_if() {
+        let mut reader = Reader::new("only-if: {{env}} == \"staging\"");
+        let option = parse(&mut reader).unwrap();
+        let OptionKind::OnlyIf(condition) = &option.kind else {
+            panic!("only-if option expected");
+        };
+        assert_eq!(condition.expr.variable.name, "env");
+        assert!(!condition.predicate.not);
+        assert_eq!(condition.to_string(), "{{env}} == \"staging\"");
+
+        let mut reader = Reader::new("only-if: {{ token }} not exists");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(option.kind.value_as_str(), "{{token}} not exists");
+    }
+
+    #[test]
+    fn test_option_only_if_error() {
+        let mut reader = Reader::new("only-if: staging");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+
+        let mut reader = Reader::new("only-if: {{env}} staging");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_variable_definition() {
         let mut reader = Reader::new("a=1");
@@ -592,6 +701,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_option_xmlns() {
+        let mut reader = Reader::new("soap = urn:example");
+        assert_eq!(
+            option_xmlns(&mut reader).unwrap(),
+            OptionKind::XmlNamespace(XmlNamespace {
+                prefix: "soap".to_string(),
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo {
+                        start: Pos { line: 1, column: 5 },
+                        end: Pos { line: 1, column: 6 },
+                    },
+                },
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo {
+                        start: Pos { line: 1, column: 7 },
+                        end: Pos { line: 1, column: 8 },
+                    },
+                },
+                uri: Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "urn:example".to_string(),
+                        encoded: "urn:example".to_string(),
+                    }],
+                    source_info: SourceInfo {
+                        start: Pos { line: 1, column: 8 },
+                        end: Pos {
+                            line: 1,
+                            column: 19
+                        },
+                    },
+                },
+            })
+        );
+
+        let mut reader = Reader::new("=urn:example");
+        let error = option_xmlns(&mut reader).unwrap_err();
+        assert_eq!(error.pos, Pos { line: 1, column: 1 });
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "namespace prefix".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_variable_value() {
         let mut reader = Reader::new("null");
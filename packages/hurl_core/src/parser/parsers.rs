@@ -25,7 +25,7 @@ use crate::parser::primitives::*;
 use crate::parser::sections::*;
 use crate::parser::url::url;
 use crate::parser::ParseResult;
-use crate::reader::Reader;
+use crate::reader::{Cursor, Reader};
 
 pub fn hurl_file(reader: &mut Reader) -> ParseResult<HurlFile> {
     let entries = zero_or_more(entry, reader)?;
@@ -37,6 +37,73 @@ pub fn hurl_file(reader: &mut Reader) -> ParseResult<HurlFile> {
     })
 }
 
+/// Parses a Hurl file, recovering from errors.
+///
+/// When an entry can not be parsed, its error is recorded and parsing resumes at the next line
+/// beginning with a request method. Returns the successfully parsed entries, and the errors in the
+/// order of the file. The first error is the one returned by [`hurl_file`].
+pub fn hurl_file_with_recovery(reader: &mut Reader) -> (HurlFile, Vec<ParseError>) {
+    let mut entries = vec![];
+    let mut errors = vec![];
+    loop {
+        let start = reader.cursor();
+        match entry(reader) {
+            Ok(entry) => {
+                entries.push(entry);
+                continue;
+            }
+            Err(e) if e.recoverable => reader.seek(start),
+            Err(e) => {
+                skip_to_next_entry(reader, start, e.pos.line);
+                errors.push(e);
+                continue;
+            }
+        }
+        // There is no more entry, only line terminators are expected until the end of file.
+        let line_terminators = optional_line_terminators(reader).and_then(|line_terminators| {
+            eof(reader)?;
+            Ok(line_terminators)
+        });
+        match line_terminators {
+            Ok(line_terminators) => {
+                let hurl_file = HurlFile {
+                    entries,
+                    line_terminators,
+                };
+                return (hurl_file, errors);
+            }
+            Err(e) => {
+                skip_to_next_entry(reader, start, e.pos.line);
+                errors.push(e);
+            }
+        }
+    }
+}
+
+/// Moves the `reader` from the cursor `from` to the first line after `error_line` beginning with a
+/// request method, or to the end of file.
+fn skip_to_next_entry(reader: &mut Reader, from: Cursor, error_line: usize) {
+    reader.seek(from);
+    loop {
+        reader.read_while(|c| c != '\n');
+        reader.read();
+        if reader.is_eof() {
+            return;
+        }
+        if reader.cursor().pos.line <= error_line {
+            continue;
+        }
+        let start = reader.cursor();
+        let is_request_line = zero_or_more_spaces(reader).is_ok()
+            && method(reader).is_ok_and(|m| m.0 != "HTTP")
+            && matches!(reader.peek(), None | Some(' ' | '\t' | '\r' | '\n'));
+        reader.seek(start);
+        if is_request_line {
+            return;
+        }
+    }
+}
+
 fn entry(reader: &mut Reader) -> ParseResult<Entry> {
     let req = request(reader)?;
     let resp = optional(response, reader)?;
@@ -167,6 +234,21 @@ fn status(reader: &mut Reader) -> ParseResult<Status> {
     let value = match try_literal("*", reader) {
         Ok(_) => StatusValue::Any,
         Err(_) => match natural(reader) {
+            Ok(value) if (1..=5).contains(&value) && try_literal("xx", reader).is_ok() => {
+                StatusValue::Class(value)
+            }
+            Ok(value) if reader.peek() == Some(',') => {
+                let mut values = vec![value];
+                while try_literal(",", reader).is_ok() {
+                    match natural(reader) {
+                        Ok(value) => values.push(value),
+                        Err(_) => {
+                            return Err(ParseError::new(start.pos, false, ParseErrorKind::Status))
+                        }
+                    }
+                }
+                StatusValue::List(values)
+            }
             Ok(value) => StatusValue::Specific(value),
             Err(_) => return Err(ParseError::new(start.pos, false, ParseErrorKind::Status)),
         },
@@ -204,6 +286,47 @@ mod tests {
         assert_eq!(hurl_file.entries.len(), 1);
     }
 
+    #[test]
+    fn test_hurl_file_with_recovery() {
+        let content = r#"GET http://localhost:8000/a
+HTTP 200
+[Asserts]
+foo == 1
+jsonpath "$.id" == 1
+
+GET http://localhost:8000/b
+
+get http://localhost:8000/c
+HTTP 200
+GET
+POST http://localhost:8000/d
+"#;
+        let mut reader = Reader::new(content);
+        let (file, errors) = hurl_file_with_recovery(&mut reader);
+        assert_eq!(
+            file.entries
+                .iter()
+                .map(|e| e.request.url.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "http://localhost:8000/a",
+                "http://localhost:8000/b",
+                "http://localhost:8000/d"
+            ]
+        );
+        assert_eq!(
+            errors.iter().map(|e| e.pos).collect::<Vec<_>>(),
+            vec![Pos::new(4, 1), Pos::new(9, 1), Pos::new(11, 4)]
+        );
+        let mut reader = Reader::new(content);
+        assert_eq!(hurl_file(&mut reader).unwrap_err(), errors[0]);
+
+        let mut reader = Reader::new("GET http://localhost:8000/a\n\n");
+        let (file, errors) = hurl_file_with_recovery(&mut reader);
+        assert_eq!(file.entries.len(), 1);
+        assert!(errors.is_empty());
+    }
+
     #[test]
     fn test_entry() {
         let mut reader = Reader::new("GET http://google.fr");
@@ -516,9 +639,23 @@ mod tests {
         let s = status(&mut reader).unwrap();
         assert_eq!(s.value, StatusValue::Specific(200));
 
+        let mut reader = Reader::new("2xx");
+        let s = status(&mut reader).unwrap();
+        assert_eq!(s.value, StatusValue::Class(2));
+        assert_eq!(reader.cursor().index, 3);
+
+        let mut reader = Reader::new("200,201,204");
+        let s = status(&mut reader).unwrap();
+        assert_eq!(s.value, StatusValue::List(vec![200, 201, 204]));
+        assert_eq!(reader.cursor().index, 11);
+
         let mut reader = Reader::new("xxx");
         let result = status(&mut reader);
         assert!(result.is_err());
+
+        let mut reader = Reader::new("200,");
+        let result = status(&mut reader);
+        assert!(result.is_err());
     }
 
     #[test]
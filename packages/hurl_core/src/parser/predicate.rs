@@ -80,6 +80,7 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             end_with_predicate,
             contain_predicate,
             include_predicate,
+            match_schema_predicate,
             match_predicate,
             integer_predicate,
             float_predicate,
@@ -329,6 +330,21 @@ fn match_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::Match { space0, value })
 }
 
+fn match_schema_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("matchesSchema", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !matches!(value, PredicateValue::String(_)) && !matches!(value, PredicateValue::File(_)) {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::MatchSchema { space0, value })
+}
+
 fn integer_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("isInteger", reader)?;
     Ok(PredicateFuncValue::IsInteger)
@@ -448,6 +464,33 @@ mod tests {
         assert_eq!(error.kind, ParseErrorKind::Predicate);
     }
 
+    #[test]
+    fn test_match_schema_predicate() {
+        let mut reader = Reader::new("matchesSchema file,user.json;");
+        let value = match_schema_predicate(&mut reader).unwrap();
+        assert!(matches!(
+            value,
+            PredicateFuncValue::MatchSchema {
+                value: PredicateValue::File(_),
+                ..
+            }
+        ));
+        assert_eq!(reader.cursor().index, 29);
+
+        // `matches` predicate is not shadowed.
+        let mut reader = Reader::new("matches /a/");
+        assert!(matches!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::Match { .. }
+        ));
+
+        let mut reader = Reader::new("matchesSchema 1");
+        let error = match_schema_predicate(&mut reader).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 15));
+        assert!(!error.recoverable);
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
+
     #[test]
     fn test_equal_predicate() {
         let mut reader = Reader::new("==  true");
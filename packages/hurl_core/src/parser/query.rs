@@ -38,6 +38,7 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
         &[
             status_query,
             url_query,
+            redirects_query,
             header_query,
             cookie_query,
             body_query,
@@ -48,7 +49,10 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
             duration_query,
             bytes_query,
             sha256_query,
+            sha1_query,
             md5_query,
+            frames_query,
+            events_query,
             certificate_query,
         ],
         reader,
@@ -65,6 +69,11 @@ fn url_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Url)
 }
 
+fn redirects_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("redirects", reader)?;
+    Ok(QueryValue::Redirects)
+}
+
 fn header_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("header", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -161,9 +170,43 @@ fn variable_query(reader: &mut Reader) -> ParseResult<QueryValue> {
 
 fn duration_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("duration", reader)?;
+    // The duration query can be followed by a timing name, such as `duration "start_transfer"`.
+    let save = reader.cursor();
+    if let Ok(space0) = one_or_more_spaces(reader) {
+        if reader.peek() == Some('"') {
+            let attribute_name = timing_field(reader)?;
+            return Ok(QueryValue::Timing {
+                space0,
+                attribute_name,
+            });
+        }
+    }
+    reader.seek(save);
     Ok(QueryValue::Duration)
 }
 
+fn timing_field(reader: &mut Reader) -> ParseResult<TimingAttributeName> {
+    literal("\"", reader)?;
+    if try_literal(r#"name_lookup""#, reader).is_ok() {
+        Ok(TimingAttributeName::NameLookup)
+    } else if try_literal(r#"connect""#, reader).is_ok() {
+        Ok(TimingAttributeName::Connect)
+    } else if try_literal(r#"app_connect""#, reader).is_ok() {
+        Ok(TimingAttributeName::AppConnect)
+    } else if try_literal(r#"pre_transfer""#, reader).is_ok() {
+        Ok(TimingAttributeName::PreTransfer)
+    } else if try_literal(r#"start_transfer""#, reader).is_ok() {
+        Ok(TimingAttributeName::StartTransfer)
+    } else if try_literal(r#"total""#, reader).is_ok() {
+        Ok(TimingAttributeName::Total)
+    } else {
+        let value = "Timing <name_lookup>, <connect>, <app_connect>, <pre_transfer>, <start_transfer> or <total>".to_string();
+        let kind = ParseErrorKind::Expecting { value };
+        let cur = reader.cursor();
+        Err(ParseError::new(cur.pos, false, kind))
+    }
+}
+
 fn bytes_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("bytes", reader)?;
     Ok(QueryValue::Bytes)
@@ -174,11 +217,26 @@ fn sha256_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Sha256)
 }
 
+fn sha1_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("sha1", reader)?;
+    Ok(QueryValue::Sha1)
+}
+
 fn md5_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("md5", reader)?;
     Ok(QueryValue::Md5)
 }
 
+fn frames_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("frames", reader)?;
+    Ok(QueryValue::Frames)
+}
+
+fn events_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("events", reader)?;
+    Ok(QueryValue::Events)
+}
+
 fn certificate_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("certificate", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -240,6 +298,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_redirects_query() {
+        let mut reader = Reader::new("redirects");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+                value: QueryValue::Redirects,
+            }
+        );
+    }
+
+    #[test]
+    fn test_duration_query() {
+        let mut reader = Reader::new("duration < 1000");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+                value: QueryValue::Duration,
+            }
+        );
+        assert_eq!(reader.cursor().index, 8);
+
+        let mut reader = Reader::new("duration \"start_transfer\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 26)),
+                value: QueryValue::Timing {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 10)),
+                    },
+                    attribute_name: TimingAttributeName::StartTransfer,
+                },
+            }
+        );
+
+        let mut reader = Reader::new("duration \"ttfb\"");
+        let error = query(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_header_query() {
         let mut reader = Reader::new("header \"Foo\"");
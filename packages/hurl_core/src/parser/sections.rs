@@ -51,6 +51,7 @@ fn request_section(reader: &mut Reader) -> ParseResult<Section> {
         "MultipartFormData" => section_value_multipart_form_data(reader)?,
         "Cookies" => section_value_cookies(reader)?,
         "Options" => section_value_options(reader)?,
+        "WebSocket" => section_value_websocket(reader)?,
         _ => {
             let kind = ParseErrorKind::RequestSectionName { name: name.clone() };
             let pos = Pos::new(start.pos.line, start.pos.column + 1);
@@ -150,6 +151,46 @@ fn section_value_options(reader: &mut Reader) -> ParseResult<SectionValue> {
     Ok(SectionValue::Options(options))
 }
 
+fn section_value_websocket(reader: &mut Reader) -> ParseResult<SectionValue> {
+    let frames = zero_or_more(frame, reader)?;
+    Ok(SectionValue::WebSocket(frames))
+}
+
+fn frame(reader: &mut Reader) -> ParseResult<Frame> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let kind = frame_kind(reader)?;
+    let space1 = zero_or_more_spaces(reader)?;
+    recover(|p1| literal(":", p1), reader)?;
+    let space2 = zero_or_more_spaces(reader)?;
+    let value = unquoted_template(reader)?;
+    let line_terminator0 = line_terminator(reader)?;
+    Ok(Frame {
+        line_terminators,
+        space0,
+        kind,
+        space1,
+        space2,
+        value,
+        line_terminator0,
+    })
+}
+
+fn frame_kind(reader: &mut Reader) -> ParseResult<FrameKind> {
+    let pos = reader.cursor().pos;
+    let name = reader.read_while(|c| c.is_alphanumeric());
+    match name.as_str() {
+        "text" => Ok(FrameKind::Text),
+        "binary" => Ok(FrameKind::Binary),
+        _ => {
+            let kind = ParseErrorKind::Expecting {
+                value: "text or binary".to_string(),
+            };
+            Err(ParseError::new(pos, true, kind))
+        }
+    }
+}
+
 fn cookie(reader: &mut Reader) -> ParseResult<Cookie> {
     // let start = reader.state.clone();
     let line_terminators = optional_line_terminators(reader)?;
@@ -281,6 +322,7 @@ fn capture(reader: &mut Reader) -> ParseResult<Capture> {
     let space2 = zero_or_more_spaces(reader)?;
     let q = query(reader)?;
     let filters = filters(reader)?;
+    let (space3, redacted) = redact(reader)?;
     let line_terminator0 = line_terminator(reader)?;
     Ok(Capture {
         line_terminators,
@@ -290,10 +332,27 @@ fn capture(reader: &mut Reader) -> ParseResult<Capture> {
         space2,
         query: q,
         filters,
+        space3,
+        redacted,
         line_terminator0,
     })
 }
 
+/// Parses the optional `redact` keyword of a capture, marking the captured value as secret.
+fn redact(reader: &mut Reader) -> ParseResult<(Whitespace, bool)> {
+    let save = reader.cursor();
+    let space = zero_or_more_spaces(reader)?;
+    if !space.value.is_empty() && try_literal("redact", reader).is_ok() {
+        return Ok((space, true));
+    }
+    reader.seek(save);
+    let space = Whitespace {
+        value: String::new(),
+        source_info: SourceInfo::new(save.pos, save.pos),
+    };
+    Ok((space, false))
+}
+
 fn assert(reader: &mut Reader) -> ParseResult<Assert> {
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
@@ -483,6 +542,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_websocket_section() {
+        let mut reader = Reader::new("[WebSocket]\ntext: hello\nbinary: 0102\nHTTP 101");
+        let section = request_section(&mut reader).unwrap();
+        assert_eq!(section.name(), "WebSocket");
+        let SectionValue::WebSocket(frames) = section.value else {
+            panic!("expecting a WebSocket section");
+        };
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].kind, FrameKind::Text);
+        assert_eq!(frames[0].value.to_string(), "hello");
+        assert_eq!(frames[1].kind, FrameKind::Binary);
+        assert_eq!(frames[1].value.to_string(), "0102");
+        assert_eq!(reader.cursor().pos, Pos::new(4, 1));
+    }
+
+    #[test]
+    fn test_frame_error() {
+        let mut reader = Reader::new("json: hello");
+        let error = frame(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 1));
+        assert!(error.recoverable);
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "text or binary".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_file_value() {
         let mut reader = Reader::new("file,hello.txt;");
@@ -631,6 +720,26 @@ mod tests {
         assert_eq!(reader.cursor().index, 43);
     }
 
+    #[test]
+    fn test_capture_redact() {
+        let mut reader = Reader::new("token: header \"X-Token\" count redact\n");
+        let capture0 = capture(&mut reader).unwrap();
+        assert_eq!(capture0.filters.len(), 1);
+        assert_eq!(
+            capture0.space3,
+            Whitespace {
+                value: String::from(" "),
+                source_info: SourceInfo::new(Pos::new(1, 30), Pos::new(1, 31)),
+            }
+        );
+        assert!(capture0.redacted);
+
+        let mut reader = Reader::new("token: header \"X-Token\" \n");
+        let capture0 = capture(&mut reader).unwrap();
+        assert!(!capture0.redacted);
+        assert_eq!(capture0.line_terminator0.space0.value, " ");
+    }
+
     #[test]
     fn test_capture_with_filter_error() {
         let mut reader = Reader::new("token: header \"Location\" regex ");
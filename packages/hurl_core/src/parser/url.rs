@@ -111,9 +111,9 @@ pub fn url(reader: &mut Reader) -> ParseResult<Template> {
     })
 }
 
-/// Returns true if url starts with http://, https:// or {{
+/// Returns true if url starts with http://, https://, ws://, wss:// or {{
 fn url_prefix_valid(reader: &mut Reader) -> bool {
-    let prefixes = ["https://", "http://", "{{"];
+    let prefixes = ["https://", "http://", "wss://", "ws://", "{{"];
     for expected_p in prefixes.iter() {
         let current_p = reader.peek_n(expected_p.len());
         if &current_p == expected_p {
@@ -0,0 +1,417 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Pure Rust detection of XML bodies, used when hurl_core is built without the `libxml` feature
+//! (for instance, to target WebAssembly).
+//!
+//! Contrary to the libxml SAX parser, the XML text is not fully validated: we only check that
+//! the markup is well-formed (matching tags, quoted attributes, comments, CDATA sections etc...)
+//! to find the end of the XML body.
+use crate::parser::error::*;
+use crate::parser::ParseResult;
+use crate::reader::Reader;
+
+/// Parses a text buffer until a valid XML has been found, ending at the closing tag of the root
+/// element.
+pub fn parse(reader: &mut Reader) -> ParseResult<String> {
+    // As with libxml, a text that doesn't start with `<` is a recoverable error, any subsequent
+    // error is non-recoverable.
+    let c = reader.peek();
+    match c {
+        Some('<') => {}
+        _ => {
+            return Err(ParseError::new(
+                reader.cursor().pos,
+                true,
+                ParseErrorKind::Xml,
+            ))
+        }
+    }
+
+    let mut buf = String::new();
+    let mut scanner = Scanner::new();
+    // We keep track of the previous char reader position, to accurately raise eventual error.
+    let mut prev_pos = reader.cursor().pos;
+    while let Some(c) = reader.read() {
+        buf.push(c);
+        let valid = scanner.feed(c);
+        if !valid || (reader.is_eof() && !scanner.is_done()) {
+            return Err(ParseError::new(prev_pos, false, ParseErrorKind::Xml));
+        }
+        if scanner.is_done() {
+            break;
+        }
+        prev_pos = reader.cursor().pos;
+    }
+    Ok(buf)
+}
+
+/// States of the XML scanner, fed char by char.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum State {
+    /// Text content, or whitespaces before the root element.
+    Text,
+    /// After a `<`.
+    TagOpen,
+    /// Name of a start tag.
+    StartTagName,
+    /// Inside a start tag, after its name or an attribute.
+    InTag,
+    /// Name of an attribute.
+    AttributeName,
+    /// After an attribute name, expecting `=`.
+    AfterAttributeName,
+    /// After `=`, expecting a quoted value.
+    BeforeAttributeValue,
+    /// Inside an attribute value, delimited by the `quote` char.
+    AttributeValue { quote: char },
+    /// After the `/` of an empty element tag, expecting `>`.
+    EmptyTagEnd,
+    /// After `</`.
+    EndTagName,
+    /// After the name of an end tag, expecting `>`.
+    AfterEndTagName,
+    /// After `<!`, until we know if it's a comment, a CDATA section or a declaration.
+    Bang,
+    /// Inside a comment, with the count of consecutive `-` read.
+    Comment { dashes: usize },
+    /// Inside a CDATA section, with the count of consecutive `]` read.
+    CData { brackets: usize },
+    /// Inside a declaration like `<!DOCTYPE ...>`, with the nesting of `[` `]` brackets.
+    Declaration { depth: usize, quote: Option<char> },
+    /// Inside a processing instruction like `<?xml version="1.0"?>`.
+    ProcessingInstruction { question: bool },
+}
+
+/// An XML scanner, tracking the open elements to detect the end of the root element.
+struct Scanner {
+    state: State,
+    /// Chars read in the current state (tag name or `<!` prefix).
+    token: String,
+    /// Names of the open elements.
+    elements: Vec<String>,
+    /// Has the root element been opened?
+    root: bool,
+    done: bool,
+}
+
+impl Scanner {
+    fn new() -> Self {
+        Scanner {
+            state: State::Text,
+            token: String::new(),
+            elements: vec![],
+            root: false,
+            done: false,
+        }
+    }
+
+    /// Returns true if the root element has been closed.
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Feeds the scanner with a char `c`, returns false if `c` is not valid at this position.
+    fn feed(&mut self, c: char) -> bool {
+        match self.state.clone() {
+            State::Text => {
+                if c == '<' {
+                    self.state = State::TagOpen;
+                } else if !self.root && !c.is_whitespace() {
+                    // Only whitespaces are allowed before the root element.
+                    return false;
+                }
+            }
+            State::TagOpen => match c {
+                '/' => {
+                    self.token.clear();
+                    self.state = State::EndTagName;
+                }
+                '!' => {
+                    self.token.clear();
+                    self.state = State::Bang;
+                }
+                '?' => self.state = State::ProcessingInstruction { question: false },
+                c if is_name_start_char(c) => {
+                    // A second root element is not allowed.
+                    if self.root && self.elements.is_empty() {
+                        return false;
+                    }
+                    self.root = true;
+                    self.token.clear();
+                    self.token.push(c);
+                    self.state = State::StartTagName;
+                }
+                _ => return false,
+            },
+            State::StartTagName => match c {
+                c if is_name_char(c) => self.token.push(c),
+                c if c.is_whitespace() => {
+                    self.elements.push(self.token.clone());
+                    self.state = State::InTag;
+                }
+                '/' => {
+                    self.elements.push(self.token.clone());
+                    self.state = State::EmptyTagEnd;
+                }
+                '>' => {
+                    self.elements.push(self.token.clone());
+                    self.state = State::Text;
+                }
+                _ => return false,
+            },
+            State::InTag => match c {
+                c if c.is_whitespace() => {}
+                '/' => self.state = State::EmptyTagEnd,
+                '>' => self.state = State::Text,
+                c if is_name_start_char(c) => self.state = State::AttributeName,
+                _ => return false,
+            },
+            State::AttributeName => match c {
+                c if is_name_char(c) => {}
+                c if c.is_whitespace() => self.state = State::AfterAttributeName,
+                '=' => self.state = State::BeforeAttributeValue,
+                _ => return false,
+            },
+            State::AfterAttributeName => match c {
+                c if c.is_whitespace() => {}
+                '=' => self.state = State::BeforeAttributeValue,
+                _ => return false,
+            },
+            State::BeforeAttributeValue => match c {
+                c if c.is_whitespace() => {}
+                '"' | '\'' => self.state = State::AttributeValue { quote: c },
+                _ => return false,
+            },
+            State::AttributeValue { quote } => {
+                if c == quote {
+                    self.state = State::InTag;
+                } else if c == '<' {
+                    return false;
+                }
+            }
+            State::EmptyTagEnd => {
+                if c != '>' {
+                    return false;
+                }
+                self.elements.pop();
+                self.close_element();
+            }
+            State::EndTagName => match c {
+                c if is_name_char(c) => self.token.push(c),
+                c if c.is_whitespace() && !self.token.is_empty() => {
+                    self.state = State::AfterEndTagName;
+                }
+                '>' => return self.end_element(),
+                _ => return false,
+            },
+            State::AfterEndTagName => match c {
+                c if c.is_whitespace() => {}
+                '>' => return self.end_element(),
+                _ => return false,
+            },
+            State::Bang => {
+                self.token.push(c);
+                if self.token == "--" {
+                    self.state = State::Comment { dashes: 0 };
+                } else if self.token == "[CDATA[" {
+                    // CDATA sections are only allowed inside an element.
+                    if self.elements.is_empty() {
+                        return false;
+                    }
+                    self.state = State::CData { brackets: 0 };
+                } else if self.token == "DOCTYPE" {
+                    if self.root {
+                        return false;
+                    }
+                    self.state = State::Declaration {
+                        depth: 0,
+                        quote: None,
+                    };
+                } else if !"--".starts_with(&self.token)
+                    && !"[CDATA[".starts_with(&self.token)
+                    && !"DOCTYPE".starts_with(&self.token)
+                {
+                    return false;
+                }
+            }
+            State::Comment { dashes } => match c {
+                '-' => self.state = State::Comment { dashes: dashes + 1 },
+                '>' if dashes >= 2 => self.state = State::Text,
+                _ => self.state = State::Comment { dashes: 0 },
+            },
+            State::CData { brackets } => match c {
+                ']' => {
+                    self.state = State::CData {
+                        brackets: brackets + 1,
+                    }
+                }
+                '>' if brackets >= 2 => self.state = State::Text,
+                _ => self.state = State::CData { brackets: 0 },
+            },
+            State::Declaration { depth, quote } => match (c, quote) {
+                (c, Some(q)) if c == q => {
+                    self.state = State::Declaration { depth, quote: None };
+                }
+                (_, Some(_)) => {}
+                ('"' | '\'', None) => {
+                    self.state = State::Declaration {
+                        depth,
+                        quote: Some(c),
+                    }
+                }
+                ('[', None) => {
+                    self.state = State::Declaration {
+                        depth: depth + 1,
+                        quote: None,
+                    }
+                }
+                (']', None) => {
+                    if depth == 0 {
+                        return false;
+                    }
+                    self.state = State::Declaration {
+                        depth: depth - 1,
+                        quote: None,
+                    }
+                }
+                ('>', None) if depth == 0 => self.state = State::Text,
+                _ => {}
+            },
+            State::ProcessingInstruction { question } => match c {
+                '>' if question => self.state = State::Text,
+                '?' => self.state = State::ProcessingInstruction { question: true },
+                _ => self.state = State::ProcessingInstruction { question: false },
+            },
+        }
+        true
+    }
+
+    /// Closes the current element with an end tag, returns false if the tag doesn't match it.
+    fn end_element(&mut self) -> bool {
+        match self.elements.pop() {
+            Some(name) if name == self.token => {
+                self.close_element();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Updates the scanner state after an element has been closed.
+    fn close_element(&mut self) {
+        self.state = State::Text;
+        if self.elements.is_empty() {
+            self.done = true;
+        }
+    }
+}
+
+fn is_name_start_char(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == ':'
+}
+
+fn is_name_char(c: char) -> bool {
+    is_name_start_char(c) || c.is_numeric() || c == '-' || c == '.'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::Pos;
+
+    #[test]
+    fn parse_xml_errors() {
+        let mut reader = Reader::new("");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 1 });
+        assert_eq!(error.kind, ParseErrorKind::Xml);
+        assert!(error.recoverable);
+
+        let mut reader = Reader::new("x");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 1 });
+        assert!(error.recoverable);
+
+        let mut reader = Reader::new("<<");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 2 });
+        assert!(!error.recoverable);
+
+        let mut reader = Reader::new("<users><user /></users");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 22
+            }
+        );
+
+        let mut reader = Reader::new("<users aa><user /></users");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 10
+            }
+        );
+
+        let mut reader = Reader::new("<users></user>");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 14
+            }
+        );
+        assert_eq!(error.kind, ParseErrorKind::Xml);
+    }
+
+    #[test]
+    fn parse_xml_ok() {
+        let mut reader = Reader::new("<users><user /></users>xx");
+        assert_eq!(
+            parse(&mut reader).unwrap(),
+            String::from("<users><user /></users>")
+        );
+        assert_eq!(reader.cursor().index, 23);
+        assert_eq!(reader.peek_n(2), String::from("xx"));
+
+        let mut reader = Reader::new("<?xml version=\"1.0\"?><users/>xxx");
+        assert_eq!(
+            parse(&mut reader).unwrap(),
+            String::from("<?xml version=\"1.0\"?><users/>")
+        );
+        assert_eq!(reader.cursor().index, 29);
+
+        let xml = r#"<?xml version='1.0' encoding='UTF-8'?>
+<!DOCTYPE note [<!ENTITY writer "Bob">]>
+<!-- A <note> -->
+<note lang="en" id='1'>
+    <to>Tove</to>
+    <body><![CDATA[</note> & <to>]]>&writer;</body>
+    <empty/>
+</note>"#;
+        let input = format!("{xml}\nHTTP 200");
+        let mut reader = Reader::new(&input);
+        assert_eq!(parse(&mut reader).unwrap(), xml);
+        assert_eq!(reader.peek_n(9), "\nHTTP 200");
+    }
+}
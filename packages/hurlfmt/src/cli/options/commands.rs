@@ -67,10 +67,29 @@ pub fn input_format() -> clap::Arg {
         .long("in")
         .value_name("FORMAT")
         .default_value("hurl")
-        .help("Specify input format: hurl or curl")
+        .help("Specify input format: hurl, curl, har, openapi or postman")
         .num_args(1)
 }
 
+pub fn lint() -> clap::Arg {
+    clap::Arg::new("lint")
+        .long("lint")
+        .help("Run in lint mode")
+        .conflicts_with("check")
+        .conflicts_with("in_place")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn lsp() -> clap::Arg {
+    clap::Arg::new("lsp")
+        .long("lsp")
+        .help("Run as a language server")
+        .conflicts_with("check")
+        .conflicts_with("lint")
+        .conflicts_with("in_place")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn no_color() -> clap::Arg {
     clap::Arg::new("no_color")
         .long("no-color")
@@ -43,10 +43,21 @@ pub fn input_format(arg_matches: &ArgMatches) -> Result<InputFormat, OptionsErro
     match get_string(arg_matches, "input_format").unwrap().as_str() {
         "hurl" => Ok(InputFormat::Hurl),
         "curl" => Ok(InputFormat::Curl),
+        "har" => Ok(InputFormat::Har),
+        "openapi" => Ok(InputFormat::OpenApi),
+        "postman" => Ok(InputFormat::Postman),
         v => Err(OptionsError::Error(format!("Invalid input format {v}"))),
     }
 }
 
+pub fn lint(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "lint")
+}
+
+pub fn lsp(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "lsp")
+}
+
 pub fn output_format(arg_matches: &ArgMatches) -> Result<OutputFormat, OptionsError> {
     // Deprecated --format option
     if arg_matches.value_source("format") == Some(ValueSource::CommandLine) {
@@ -31,6 +31,8 @@ pub struct Options {
     pub in_place: bool,
     pub input_files: Vec<String>,
     pub input_format: InputFormat,
+    pub lint: bool,
+    pub lsp: bool,
     pub output_file: Option<PathBuf>,
     pub output_format: OutputFormat,
     pub standalone: bool,
@@ -39,7 +41,10 @@ pub struct Options {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum InputFormat {
     Curl,
+    Har,
     Hurl,
+    OpenApi,
+    Postman,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -76,6 +81,8 @@ pub fn parse() -> Result<Options, OptionsError> {
         .arg(commands::in_place())
         .arg(commands::input_files())
         .arg(commands::input_format())
+        .arg(commands::lint())
+        .arg(commands::lsp())
         .arg(commands::no_color())
         .arg(commands::output())
         .arg(commands::output_format())
@@ -84,7 +91,7 @@ pub fn parse() -> Result<Options, OptionsError> {
     let arg_matches = command.try_get_matches_from_mut(env::args_os())?;
     let opts = parse_matches(&arg_matches)?;
 
-    if opts.input_files.is_empty() {
+    if opts.input_files.is_empty() && !opts.lsp {
         let help = command.render_help().to_string();
         return Err(OptionsError::Error(help));
     }
@@ -97,6 +104,8 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
     let in_place = matches::in_place(arg_matches)?;
     let input_files = matches::input_files(arg_matches)?;
     let input_format = matches::input_format(arg_matches)?;
+    let lint = matches::lint(arg_matches);
+    let lsp = matches::lsp(arg_matches);
     let output_file = matches::output_file(arg_matches);
     let output_format = matches::output_format(arg_matches)?;
     let standalone = matches::standalone(arg_matches)?;
@@ -106,6 +115,8 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
         in_place,
         input_files,
         input_format,
+        lint,
+        lsp,
         output_file,
         output_format,
         standalone,
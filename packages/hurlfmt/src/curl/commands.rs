@@ -29,6 +29,23 @@ pub fn data() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn data_urlencode() -> clap::Arg {
+    clap::Arg::new("data_urlencode")
+        .long("data-urlencode")
+        .value_name("data")
+        .action(ArgAction::Append)
+        .num_args(1)
+}
+
+pub fn form() -> clap::Arg {
+    clap::Arg::new("form")
+        .long("form")
+        .short('F')
+        .value_name("name=content")
+        .action(ArgAction::Append)
+        .num_args(1)
+}
+
 pub fn headers() -> clap::Arg {
     clap::Arg::new("headers")
         .long("header")
@@ -90,3 +107,11 @@ pub fn url_param() -> clap::Arg {
         .required(false)
         .num_args(1)
 }
+
+pub fn user() -> clap::Arg {
+    clap::Arg::new("user")
+        .long("user")
+        .short('u')
+        .value_name("user:password")
+        .num_args(1)
+}
@@ -18,6 +18,7 @@
 use clap::ArgMatches;
 
 use super::HurlOption;
+use crate::escape::{escape_filename, escape_key, escape_value};
 
 pub fn body(arg_matches: &ArgMatches) -> Option<String> {
     match get_string(arg_matches, "data") {
@@ -32,10 +33,81 @@ pub fn body(arg_matches: &ArgMatches) -> Option<String> {
     }
 }
 
+/// Returns the `[BasicAuth]` section values from the `--user` option.
+pub fn basic_auth(arg_matches: &ArgMatches) -> Vec<String> {
+    match get_string(arg_matches, "user") {
+        None => vec![],
+        Some(v) => match v.split_once(':') {
+            Some((user, password)) => {
+                vec![format!("{}: {}", escape_key(user), escape_value(password))]
+            }
+            None => vec![format!("{}:", escape_key(&v))],
+        },
+    }
+}
+
+/// Returns the `[FormParams]` section values from the `--data-urlencode` options.
+///
+/// Only the `name=content` form is supported, as the other forms of `--data-urlencode` don't
+/// produce a key/value form parameter.
+pub fn form_params(arg_matches: &ArgMatches) -> Result<Vec<String>, String> {
+    let values = get_strings(arg_matches, "data_urlencode").unwrap_or_default();
+    if !values.is_empty() && arg_matches.contains_id("data") {
+        return Err("--data and --data-urlencode can not be used together".to_string());
+    }
+    values
+        .iter()
+        .map(|v| match v.find(['=', '@']) {
+            Some(index) if index > 0 && v[index..].starts_with('=') => {
+                let (name, value) = v.split_at(index);
+                Ok(format!(
+                    "{}: {}",
+                    escape_key(name),
+                    escape_value(&value[1..])
+                ))
+            }
+            _ => Err(format!("--data-urlencode value <{v}> is not supported")),
+        })
+        .collect()
+}
+
+/// Returns the `[MultipartFormData]` section values from the `--form` options.
+pub fn multipart_form_data(arg_matches: &ArgMatches) -> Result<Vec<String>, String> {
+    let values = get_strings(arg_matches, "form").unwrap_or_default();
+    if !values.is_empty() && arg_matches.contains_id("data") {
+        return Err("--data and --form can not be used together".to_string());
+    }
+    values
+        .iter()
+        .map(|v| {
+            let Some((name, value)) = v.split_once('=') else {
+                return Err(format!("--form value <{v}> is not supported"));
+            };
+            let name = escape_key(name);
+            if let Some(file) = value.strip_prefix('@') {
+                match file.split_once(";type=") {
+                    Some((filename, content_type)) => Ok(format!(
+                        "{name}: file,{}; {content_type}",
+                        escape_filename(filename)
+                    )),
+                    None => Ok(format!("{name}: file,{};", escape_filename(file))),
+                }
+            } else if value.starts_with('<') {
+                Err(format!("--form value <{v}> is not supported"))
+            } else {
+                Ok(format!("{name}: {}", escape_value(value)))
+            }
+        })
+        .collect()
+}
+
 pub fn method(arg_matches: &ArgMatches) -> String {
     match get_string(arg_matches, "method") {
         None => {
-            if arg_matches.contains_id("data") {
+            if arg_matches.contains_id("data")
+                || arg_matches.contains_id("data_urlencode")
+                || arg_matches.contains_id("form")
+            {
                 "POST".to_string()
             } else {
                 "GET".to_string()
@@ -59,8 +131,10 @@ pub fn url(arg_matches: &ArgMatches) -> String {
 }
 
 pub fn headers(arg_matches: &ArgMatches) -> Vec<String> {
-    let mut headers = get_strings(arg_matches, "headers").unwrap_or_default();
-    if !has_content_type(&headers) {
+    let headers = get_strings(arg_matches, "headers").unwrap_or_default();
+    let content_type = has_content_type(&headers);
+    let mut headers = headers.iter().map(|h| header(h)).collect::<Vec<_>>();
+    if !content_type {
         if let Some(data) = get_string(arg_matches, "data") {
             if !data.starts_with('@') {
                 headers.push("Content-Type: application/x-www-form-urlencoded".to_string());
@@ -71,6 +145,18 @@ pub fn headers(arg_matches: &ArgMatches) -> Vec<String> {
     headers
 }
 
+/// Returns a Hurl header from a curl `--header` value, keeping the spaces that follow the colon.
+fn header(s: &str) -> String {
+    match s.split_once(':') {
+        Some((name, value)) => {
+            let spaces = value.len() - value.trim_start().len();
+            let (spaces, value) = value.split_at(spaces);
+            format!("{}:{spaces}{}", escape_key(name), escape_value(value))
+        }
+        None => s.to_string(),
+    }
+}
+
 pub fn options(arg_matches: &ArgMatches) -> Vec<HurlOption> {
     let mut options = vec![];
     if has_flag(arg_matches, "compressed") {
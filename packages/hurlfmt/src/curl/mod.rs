@@ -63,6 +63,8 @@ fn parse_line(s: &str) -> Result<String, String> {
     let mut command = clap::Command::new("curl")
         .arg(commands::compressed())
         .arg(commands::data())
+        .arg(commands::data_urlencode())
+        .arg(commands::form())
         .arg(commands::headers())
         .arg(commands::insecure())
         .arg(commands::location())
@@ -70,7 +72,8 @@ fn parse_line(s: &str) -> Result<String, String> {
         .arg(commands::method())
         .arg(commands::retry())
         .arg(commands::url())
-        .arg(commands::url_param());
+        .arg(commands::url_param())
+        .arg(commands::user());
 
     let params = args::split(s)?;
     let arg_matches = match command.try_get_matches_from_mut(params) {
@@ -81,9 +84,17 @@ fn parse_line(s: &str) -> Result<String, String> {
     let method = matches::method(&arg_matches);
     let url = matches::url(&arg_matches);
     let headers = matches::headers(&arg_matches);
+    let sections = [
+        ("BasicAuth", matches::basic_auth(&arg_matches)),
+        ("FormParams", matches::form_params(&arg_matches)?),
+        (
+            "MultipartFormData",
+            matches::multipart_form_data(&arg_matches)?,
+        ),
+    ];
     let options = matches::options(&arg_matches);
     let body = matches::body(&arg_matches);
-    let s = format(&method, &url, headers, &options, body);
+    let s = format(&method, &url, headers, &sections, &options, body);
     Ok(s)
 }
 
@@ -91,6 +102,7 @@ fn format(
     method: &str,
     url: &str,
     headers: Vec<String>,
+    sections: &[(&str, Vec<String>)],
     options: &[HurlOption],
     body: Option<String>,
 ) -> String {
@@ -98,6 +110,14 @@ fn format(
     for header in headers {
         s.push_str(format!("\n{header}").as_str());
     }
+    for (name, values) in sections {
+        if !values.is_empty() {
+            s.push_str(format!("\n[{name}]").as_str());
+            for value in values {
+                s.push_str(format!("\n{value}").as_str());
+            }
+        }
+    }
     if !options.is_empty() {
         s.push_str("\n[Options]");
         for option in options {
@@ -187,6 +207,32 @@ Test: '
         );
     }
 
+    #[test]
+    fn test_escape() {
+        let hurl_str = r#"POST http://localhost:8000/escape
+Token: \u{7b}{token}} \#1
+[BasicAuth]
+bob: pass\#word\\
+[MultipartFormData]
+key\#1: value \#1\\
+upload: file,my\ data.txt;
+"#;
+        assert_eq!(
+            parse_line(r#"curl -H 'Token: {{token}} #1' -u 'bob:pass#word\' -F 'key#1=value #1\' -F 'upload=@my data.txt' http://localhost:8000/escape"#).unwrap(),
+            hurl_str
+        );
+
+        let hurl_str = r#"POST http://localhost:8000/form-params
+[FormParams]
+query: \u{7b}{q}}
+"#;
+        assert_eq!(
+            parse_line(r#"curl --data-urlencode 'query={{q}}' http://localhost:8000/form-params"#)
+                .unwrap(),
+            hurl_str
+        );
+    }
+
     #[test]
     fn test_post_hello() {
         let hurl_str = r#"POST http://localhost:8000/hello
@@ -293,4 +339,54 @@ max-redirs: 10
             hurl_str
         );
     }
+
+    #[test]
+    fn test_basic_auth() {
+        let hurl_str = r#"GET http://localhost:8000/basic-authentication
+[BasicAuth]
+bob@email.com: secret
+"#;
+        assert_eq!(
+            parse_line(
+                r#"curl -u bob@email.com:secret http://localhost:8000/basic-authentication"#
+            )
+            .unwrap(),
+            hurl_str
+        );
+    }
+
+    #[test]
+    fn test_data_urlencode() {
+        let hurl_str = r#"POST http://localhost:8000/form-params
+[FormParams]
+param1: value1
+param3: a=b
+"#;
+        assert_eq!(
+            parse_line(r#"curl --data-urlencode param1=value1 --data-urlencode 'param3=a=b' http://localhost:8000/form-params"#).unwrap(),
+            hurl_str
+        );
+        assert!(
+            parse_line(r#"curl --data-urlencode @data.txt http://localhost:8000/form-params"#)
+                .is_err()
+        );
+        assert!(parse_line(
+            r#"curl -d foo=bar --data-urlencode a=b http://localhost:8000/form-params"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_form() {
+        let hurl_str = r#"POST http://localhost:8000/multipart-form-data
+[MultipartFormData]
+key1: value1
+upload1: file,data.txt;
+upload3: file,data.txt; text/html
+"#;
+        assert_eq!(
+            parse_line(r#"curl -F key1=value1 -F upload1=@data.txt --form 'upload3=@data.txt;type=text/html' http://localhost:8000/multipart-form-data"#).unwrap(),
+            hurl_str
+        );
+    }
 }
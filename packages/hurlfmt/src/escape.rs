@@ -0,0 +1,108 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Escaping of strings written in Hurl files generated by the importers (curl, HAR, OpenAPI and
+//! Postman).
+
+/// Escapes a string `s` to be used as the key of a Hurl key-value.
+pub fn escape_key(s: &str) -> String {
+    let mut key = String::new();
+    for c in s.chars() {
+        match c {
+            c if c.is_alphanumeric() || "_-.[]@$".contains(c) => key.push(c),
+            '#' | ':' | '\\' => {
+                key.push('\\');
+                key.push(c);
+            }
+            c => key.push_str(&format!("\\u{{{:x}}}", c as u32)),
+        }
+    }
+    key
+}
+
+/// Escapes a string `s` to be used as the value of a Hurl key-value.
+pub fn escape_value(s: &str) -> String {
+    escape_template_value(s).replace("{{", "\\u{7b}{")
+}
+
+/// Escapes a string `s` to be used as the value of a Hurl key-value, its `{{name}}` placeholders
+/// being kept as Hurl templates.
+pub fn escape_template_value(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('#', "\\#")
+        .replace('\n', "\\n")
+}
+
+/// Escapes a string `s` to be used as a Hurl filename.
+pub fn escape_filename(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(' ', "\\ ")
+}
+
+/// Escapes a string `s` to be used in a Hurl quoted string.
+pub fn escape_quoted(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Returns a valid Hurl variable name from a name `s` (like a parameter name `page[size]`), used
+/// in generated `{{name}}` placeholders. Invalid characters are replaced by `_`.
+pub fn variable_name(s: &str) -> String {
+    let name = s
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    let name = name.trim_matches('_');
+    // A variable name must start with a letter.
+    if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.to_string()
+    } else {
+        format!("var_{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape_key("x-foo"), "x-foo");
+        assert_eq!(escape_key("a b:c"), "a\\u{20}b\\:c");
+        assert_eq!(
+            escape_key("{{x}}#\\"),
+            "\\u{7b}\\u{7b}x\\u{7d}\\u{7d}\\#\\\\"
+        );
+        assert_eq!(escape_value("{{x}} #1"), "\\u{7b}{x}} \\#1");
+        assert_eq!(escape_value("a\\"), "a\\\\");
+        assert_eq!(escape_template_value("{{x}} #1"), "{{x}} \\#1");
+        assert_eq!(escape_filename("my file;1"), "my\\ file\\;1");
+        assert_eq!(escape_quoted("a\"b"), "a\\\"b");
+
+        assert_eq!(variable_name("id"), "id");
+        assert_eq!(variable_name("X-Api-Key"), "X-Api-Key");
+        assert_eq!(variable_name("page[size]"), "page_size");
+        assert_eq!(variable_name("$top"), "top");
+        assert_eq!(variable_name("1st"), "var_1st");
+    }
+}
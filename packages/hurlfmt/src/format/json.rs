@@ -88,6 +88,10 @@ impl ToJson for Request {
             let options = self.options().iter().map(|c| c.to_json()).collect();
             attributes.push(("options".to_string(), JValue::List(options)));
         }
+        if !self.frames().is_empty() {
+            let frames = self.frames().iter().map(|f| f.to_json()).collect();
+            attributes.push(("frames".to_string(), JValue::List(frames)));
+        }
         if let Some(body) = &self.body {
             attributes.push(("body".to_string(), body.to_json()));
         }
@@ -114,8 +118,22 @@ impl ToJson for Response {
         if let Some(v) = get_json_version(&self.version.value) {
             attributes.push(("version".to_string(), JValue::String(v)));
         }
-        if let StatusValue::Specific(n) = self.status.value {
-            attributes.push(("status".to_string(), JValue::Number(n.to_string())));
+        match &self.status.value {
+            StatusValue::Any => {}
+            StatusValue::Specific(n) => {
+                attributes.push(("status".to_string(), JValue::Number(n.to_string())));
+            }
+            StatusValue::Class(_) => {
+                let value = JValue::String(self.status.value.to_string());
+                attributes.push(("status".to_string(), value));
+            }
+            StatusValue::List(values) => {
+                let values = values
+                    .iter()
+                    .map(|n| JValue::Number(n.to_string()))
+                    .collect();
+                attributes.push(("status".to_string(), JValue::List(values)));
+            }
         }
         add_headers(&mut attributes, &self.headers);
         if !self.captures().is_empty() {
@@ -285,6 +303,19 @@ impl ToJson for FileParam {
     }
 }
 
+impl ToJson for Frame {
+    fn to_json(&self) -> JValue {
+        let attributes = vec![
+            (
+                "type".to_string(),
+                JValue::String(self.kind.value().to_string()),
+            ),
+            ("value".to_string(), JValue::String(self.value.to_string())),
+        ];
+        JValue::Object(attributes)
+    }
+}
+
 impl ToJson for Cookie {
     fn to_json(&self) -> JValue {
         let attributes = vec![
@@ -303,8 +334,10 @@ impl ToJson for EntryOption {
             OptionKind::ClientCert(filename) => JValue::String(filename.to_string()),
             OptionKind::ClientKey(filename) => JValue::String(filename.to_string()),
             OptionKind::Compressed(value) => value.to_json(),
+            OptionKind::ConnectTimeout(value) => value.to_json(),
             OptionKind::ConnectTo(value) => JValue::String(value.to_string()),
             OptionKind::Delay(value) => value.to_json(),
+            OptionKind::Digest(value) => value.to_json(),
             OptionKind::FollowLocation(value) => value.to_json(),
             OptionKind::FollowLocationTrusted(value) => value.to_json(),
             OptionKind::Http10(value) => value.to_json(),
@@ -315,17 +348,23 @@ impl ToJson for EntryOption {
             OptionKind::IpV4(value) => value.to_json(),
             OptionKind::IpV6(value) => value.to_json(),
             OptionKind::MaxRedirect(value) => value.to_json(),
+            OptionKind::MaxTime(value) => value.to_json(),
             OptionKind::NetRc(value) => value.to_json(),
             OptionKind::NetRcFile(filename) => JValue::String(filename.to_string()),
             OptionKind::NetRcOptional(value) => value.to_json(),
+            OptionKind::Ntlm(value) => value.to_json(),
+            OptionKind::OnlyIf(value) => JValue::String(value.to_string()),
             OptionKind::Output(filename) => JValue::String(filename.to_string()),
             OptionKind::PathAsIs(value) => value.to_json(),
+            OptionKind::Proto(filename) => JValue::String(filename.to_string()),
             OptionKind::Proxy(value) => JValue::String(value.to_string()),
             OptionKind::Repeat(value) => value.to_json(),
             OptionKind::Resolve(value) => JValue::String(value.to_string()),
             OptionKind::Retry(value) => value.to_json(),
             OptionKind::RetryInterval(value) => value.to_json(),
             OptionKind::Skip(value) => value.to_json(),
+            OptionKind::StreamCount(value) => value.to_json(),
+            OptionKind::StreamTimeout(value) => value.to_json(),
             OptionKind::UnixSocket(value) => JValue::String(value.to_string()),
             OptionKind::User(value) => JValue::String(value.to_string()),
             OptionKind::Variable(value) => {
@@ -333,6 +372,9 @@ impl ToJson for EntryOption {
             }
             OptionKind::Verbose(value) => value.to_json(),
             OptionKind::VeryVerbose(value) => value.to_json(),
+            OptionKind::XmlNamespace(value) => {
+                JValue::String(format!("{}={}", value.prefix, value.uri))
+            }
         };
 
         // If the value contains the unit such as `{ "value": 10, "unit": "second" }`
@@ -416,6 +458,9 @@ impl ToJson for Capture {
             let filters = JValue::List(self.filters.iter().map(|(_, f)| f.to_json()).collect());
             attributes.push(("filters".to_string(), filters));
         }
+        if self.redacted {
+            attributes.push(("redact".to_string(), JValue::Boolean(true)));
+        }
         JValue::Object(attributes)
     }
 }
@@ -448,6 +493,9 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Url => {
             attributes.push(("type".to_string(), JValue::String("url".to_string())));
         }
+        QueryValue::Redirects => {
+            attributes.push(("type".to_string(), JValue::String("redirects".to_string())));
+        }
         QueryValue::Body => {
             attributes.push(("type".to_string(), JValue::String("body".to_string())));
         }
@@ -478,15 +526,31 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Duration => {
             attributes.push(("type".to_string(), JValue::String("duration".to_string())));
         }
+        QueryValue::Timing { attribute_name, .. } => {
+            attributes.push(("type".to_string(), JValue::String("duration".to_string())));
+            attributes.push((
+                "expr".to_string(),
+                JValue::String(attribute_name.value().to_string()),
+            ));
+        }
         QueryValue::Bytes => {
             attributes.push(("type".to_string(), JValue::String("bytes".to_string())));
         }
         QueryValue::Sha256 => {
             attributes.push(("type".to_string(), JValue::String("sha256".to_string())));
         }
+        QueryValue::Sha1 => {
+            attributes.push(("type".to_string(), JValue::String("sha1".to_string())));
+        }
         QueryValue::Md5 => {
             attributes.push(("type".to_string(), JValue::String("md5".to_string())));
         }
+        QueryValue::Frames => {
+            attributes.push(("type".to_string(), JValue::String("frames".to_string())));
+        }
+        QueryValue::Events => {
+            attributes.push(("type".to_string(), JValue::String("events".to_string())));
+        }
         QueryValue::Certificate {
             attribute_name: field,
             ..
@@ -590,6 +654,13 @@ impl ToJson for Predicate {
                 attributes.push(("type".to_string(), JValue::String("match".to_string())));
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::MatchSchema { value, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("match-schema".to_string()),
+                ));
+                add_predicate_value(&mut attributes, value);
+            }
             PredicateFuncValue::IsInteger => {
                 attributes.push(("type".to_string(), JValue::String("isInteger".to_string())));
             }
@@ -696,6 +767,18 @@ impl ToJson for FilterValue {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![];
         match self {
+            FilterValue::Base64Decode => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("base64Decode".to_string()),
+                ));
+            }
+            FilterValue::Base64Encode => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("base64Encode".to_string()),
+                ));
+            }
             FilterValue::Count => {
                 attributes.push(("type".to_string(), JValue::String("count".to_string())));
             }
@@ -963,6 +1046,8 @@ pub mod tests {
             space2: whitespace(),
             query: header_query(),
             filters: vec![],
+            space3: whitespace(),
+            redacted: false,
             line_terminator0: line_terminator(),
         }
     }
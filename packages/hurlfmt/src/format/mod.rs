@@ -16,6 +16,7 @@
  *
  */
 pub use self::json::format as format_json;
+pub(crate) use self::serialize_json::JValue;
 pub use self::text::format as format_text;
 pub use self::token::{Token, Tokenizable};
 
@@ -135,6 +135,9 @@ impl Tokenizable for Status {
         match self.value.clone() {
             StatusValue::Any => tokens.push(Token::Status("*".to_string())),
             StatusValue::Specific(v) => tokens.push(Token::Status(v.to_string())),
+            StatusValue::Class(_) | StatusValue::List(_) => {
+                tokens.push(Token::Status(self.value.to_string()));
+            }
         }
         tokens
     }
@@ -227,6 +230,9 @@ impl Tokenizable for SectionValue {
             SectionValue::Options(items) => {
                 tokens.append(&mut items.iter().flat_map(|e| e.tokenize()).collect());
             }
+            SectionValue::WebSocket(items) => {
+                tokens.append(&mut items.iter().flat_map(|e| e.tokenize()).collect());
+            }
         }
         tokens
     }
@@ -324,6 +330,27 @@ impl Tokenizable for FileValue {
     }
 }
 
+impl Tokenizable for Frame {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        tokens.append(
+            &mut self
+                .line_terminators
+                .iter()
+                .flat_map(|e| e.tokenize())
+                .collect(),
+        );
+        tokens.append(&mut self.space0.tokenize());
+        tokens.push(Token::String(self.kind.value().to_string()));
+        tokens.append(&mut self.space1.tokenize());
+        tokens.push(Token::Colon(String::from(":")));
+        tokens.append(&mut self.space2.tokenize());
+        tokens.append(&mut self.value.tokenize());
+        tokens.append(&mut self.line_terminator0.tokenize());
+        tokens
+    }
+}
+
 impl Tokenizable for Cookie {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
@@ -365,6 +392,10 @@ impl Tokenizable for Capture {
             tokens.append(&mut space.tokenize());
             tokens.append(&mut filter.tokenize());
         }
+        if self.redacted {
+            tokens.append(&mut self.space3.tokenize());
+            tokens.push(Token::Keyword(String::from("redact")));
+        }
         tokens.append(&mut self.line_terminator0.tokenize());
         tokens
     }
@@ -407,6 +438,7 @@ impl Tokenizable for QueryValue {
         match self.clone() {
             QueryValue::Status => tokens.push(Token::QueryType(String::from("status"))),
             QueryValue::Url => tokens.push(Token::QueryType(String::from("url"))),
+            QueryValue::Redirects => tokens.push(Token::QueryType(String::from("redirects"))),
             QueryValue::Header { space0, name } => {
                 tokens.push(Token::QueryType(String::from("header")));
                 tokens.append(&mut space0.tokenize());
@@ -441,9 +473,22 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut name.tokenize());
             }
             QueryValue::Duration => tokens.push(Token::QueryType(String::from("duration"))),
+            QueryValue::Timing {
+                space0,
+                attribute_name,
+            } => {
+                tokens.push(Token::QueryType(String::from("duration")));
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::StringDelimiter("\"".to_string()));
+                tokens.push(Token::String(attribute_name.value().to_string()));
+                tokens.push(Token::StringDelimiter("\"".to_string()));
+            }
             QueryValue::Bytes => tokens.push(Token::QueryType(String::from("bytes"))),
             QueryValue::Sha256 => tokens.push(Token::QueryType(String::from("sha256"))),
+            QueryValue::Sha1 => tokens.push(Token::QueryType(String::from("sha1"))),
             QueryValue::Md5 => tokens.push(Token::QueryType(String::from("md5"))),
+            QueryValue::Frames => tokens.push(Token::QueryType(String::from("frames"))),
+            QueryValue::Events => tokens.push(Token::QueryType(String::from("events"))),
             QueryValue::Certificate {
                 space0,
                 attribute_name: field,
@@ -582,6 +627,11 @@ impl Tokenizable for PredicateFuncValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut value.tokenize());
             }
+            PredicateFuncValue::MatchSchema { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
 
             PredicateFuncValue::IsInteger => {
                 tokens.push(Token::PredicateType(self.name()));
@@ -907,8 +957,10 @@ impl Tokenizable for OptionKind {
             OptionKind::ClientCert(filename) => filename.tokenize(),
             OptionKind::ClientKey(filename) => filename.tokenize(),
             OptionKind::Compressed(value) => value.tokenize(),
+            OptionKind::ConnectTimeout(value) => value.tokenize(),
             OptionKind::ConnectTo(value) => value.tokenize(),
             OptionKind::Delay(value) => value.tokenize(),
+            OptionKind::Digest(value) => value.tokenize(),
             OptionKind::FollowLocation(value) => value.tokenize(),
             OptionKind::FollowLocationTrusted(value) => value.tokenize(),
             OptionKind::Http10(value) => value.tokenize(),
@@ -919,22 +971,29 @@ impl Tokenizable for OptionKind {
             OptionKind::IpV4(value) => value.tokenize(),
             OptionKind::IpV6(value) => value.tokenize(),
             OptionKind::MaxRedirect(value) => value.tokenize(),
+            OptionKind::MaxTime(value) => value.tokenize(),
             OptionKind::NetRc(value) => value.tokenize(),
             OptionKind::NetRcFile(filename) => filename.tokenize(),
             OptionKind::NetRcOptional(value) => value.tokenize(),
+            OptionKind::Ntlm(value) => value.tokenize(),
+            OptionKind::OnlyIf(value) => value.tokenize(),
             OptionKind::Output(filename) => filename.tokenize(),
             OptionKind::PathAsIs(value) => value.tokenize(),
+            OptionKind::Proto(filename) => filename.tokenize(),
             OptionKind::Proxy(value) => value.tokenize(),
             OptionKind::Repeat(value) => value.tokenize(),
             OptionKind::Resolve(value) => value.tokenize(),
             OptionKind::Retry(value) => value.tokenize(),
             OptionKind::RetryInterval(value) => value.tokenize(),
             OptionKind::Skip(value) => value.tokenize(),
+            OptionKind::StreamCount(value) => value.tokenize(),
+            OptionKind::StreamTimeout(value) => value.tokenize(),
             OptionKind::UnixSocket(value) => value.tokenize(),
             OptionKind::User(value) => value.tokenize(),
             OptionKind::Variable(value) => value.tokenize(),
             OptionKind::Verbose(value) => value.tokenize(),
             OptionKind::VeryVerbose(value) => value.tokenize(),
+            OptionKind::XmlNamespace(value) => value.tokenize(),
         }
     }
 }
@@ -994,6 +1053,16 @@ impl Tokenizable for Duration {
     }
 }
 
+impl Tokenizable for Condition {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        tokens.append(&mut self.expr.tokenize());
+        tokens.append(&mut self.space0.tokenize());
+        tokens.append(&mut self.predicate.tokenize());
+        tokens
+    }
+}
+
 impl Tokenizable for VariableDefinition {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![Token::String(self.name.clone())];
@@ -1005,6 +1074,17 @@ impl Tokenizable for VariableDefinition {
     }
 }
 
+impl Tokenizable for XmlNamespace {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![Token::String(self.prefix.clone())];
+        tokens.append(&mut self.space0.tokenize());
+        tokens.push(Token::Keyword("=".to_string()));
+        tokens.append(&mut self.space1.tokenize());
+        tokens.append(&mut self.uri.tokenize());
+        tokens
+    }
+}
+
 impl Tokenizable for VariableValue {
     fn tokenize(&self) -> Vec<Token> {
         match self {
@@ -1019,6 +1099,8 @@ impl Tokenizable for VariableValue {
 impl Tokenizable for Filter {
     fn tokenize(&self) -> Vec<Token> {
         match self.value.clone() {
+            FilterValue::Base64Decode => vec![Token::FilterType(String::from("base64Decode"))],
+            FilterValue::Base64Encode => vec![Token::FilterType(String::from("base64Encode"))],
             FilterValue::Count => vec![Token::FilterType(String::from("count"))],
             FilterValue::DaysAfterNow => vec![Token::FilterType(String::from("daysAfterNow"))],
             FilterValue::DaysBeforeNow => vec![Token::FilterType(String::from("daysBeforeNow"))],
@@ -0,0 +1,261 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use base64::engine::general_purpose;
+use base64::Engine;
+use serde_json::Value;
+
+use crate::escape::{escape_filename, escape_key, escape_value};
+
+/// Converts a HAR archive `s` (HTTP Archive 1.2, as recorded by browsers) to a Hurl file.
+///
+/// Entries are converted in the order of the archive, with their headers, cookies and bodies, and
+/// the status code of the recorded response. Entries which are not HTTP requests (`data:` URLs
+/// for instance) are ignored.
+pub fn parse(s: &str) -> Result<String, String> {
+    let har =
+        serde_json::from_str::<Value>(s).map_err(|e| format!("Can not parse HAR file: {e}"))?;
+    let Some(entries) = har["log"]["entries"].as_array() else {
+        return Err("Can not parse HAR file: missing log entries".to_string());
+    };
+    let mut s = vec![];
+    for (i, entry) in entries.iter().enumerate() {
+        if let Some(entry) = parse_entry(entry)
+            .map_err(|message| format!("Can not convert HAR entry {}: {message}", i + 1))?
+        {
+            s.push(entry);
+        }
+    }
+    Ok(s.join("\n"))
+}
+
+/// Converts a HAR `entry` to a Hurl entry, or returns `None` if it's not an HTTP request.
+fn parse_entry(entry: &Value) -> Result<Option<String>, String> {
+    let request = &entry["request"];
+    let method = request["method"].as_str().ok_or("missing request method")?;
+    let url = request["url"].as_str().ok_or("missing request URL")?;
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Ok(None);
+    }
+    let post_data = &request["postData"];
+    let params = post_data["params"].as_array().filter(|p| !p.is_empty());
+    let mime_type = post_data["mimeType"].as_str().unwrap_or_default();
+    let multipart = params.is_some() && mime_type.starts_with("multipart/form-data");
+    let cookies = values(&request["cookies"]);
+
+    let mut s = format!("{method} {url}\n");
+    for (name, value) in values(&request["headers"]) {
+        // HTTP/2 pseudo-headers, the content length and the multipart boundary are computed by
+        // Hurl, and cookies are written in their own section.
+        let name_lower = name.to_lowercase();
+        if name.starts_with(':')
+            || name_lower == "content-length"
+            || (name_lower == "content-type" && multipart)
+            || (name_lower == "cookie" && !cookies.is_empty())
+        {
+            continue;
+        }
+        s.push_str(&format!("{}: {}\n", escape_key(name), escape_value(value)));
+    }
+    if !cookies.is_empty() {
+        s.push_str("[Cookies]\n");
+        for (name, value) in cookies {
+            s.push_str(&format!("{}: {}\n", escape_key(name), escape_value(value)));
+        }
+    }
+    if multipart {
+        s.push_str("[MultipartFormData]\n");
+        for param in params.unwrap() {
+            let name = escape_key(param["name"].as_str().unwrap_or_default());
+            match param["fileName"].as_str() {
+                Some(filename) => {
+                    let filename = escape_filename(filename);
+                    let content_type = param["contentType"]
+                        .as_str()
+                        .map(|c| format!(" {c}"))
+                        .unwrap_or_default();
+                    s.push_str(&format!("{name}: file,{filename};{content_type}\n"));
+                }
+                None => {
+                    let value = escape_value(param["value"].as_str().unwrap_or_default());
+                    s.push_str(&format!("{name}: {value}\n"));
+                }
+            }
+        }
+    } else if let Some(text) = post_data["text"].as_str() {
+        if !text.is_empty() {
+            s.push_str(&body(text, post_data["encoding"].as_str()));
+        }
+    }
+
+    // A status of 0 is recorded for requests without response (blocked or aborted).
+    match entry["response"]["status"].as_u64() {
+        Some(status) if status > 0 => s.push_str(&format!("HTTP {status}\n")),
+        _ => {}
+    }
+    Ok(Some(s))
+}
+
+/// Returns the name and value pairs of a HAR list (headers, cookies...).
+fn values(values: &Value) -> Vec<(&str, &str)> {
+    values
+        .as_array()
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|v| Some((v["name"].as_str()?, v["value"].as_str()?)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Converts a HAR request body `text` to a Hurl body, preserving its exact bytes.
+///
+/// Valid JSON objects and arrays are written as JSON bodies, other text as oneline or multiline
+/// strings if possible, and as a Base64 body otherwise.
+fn body(text: &str, encoding: Option<&str>) -> String {
+    if encoding == Some("base64") {
+        return format!("base64,{text};\n");
+    }
+    match serde_json::from_str::<Value>(text) {
+        Ok(Value::Object(_)) | Ok(Value::Array(_)) if !text.contains("{{") => {
+            return format!("{}\n", text.trim());
+        }
+        _ => {}
+    }
+    if text.contains("{{") || text.contains("```") {
+        return format!("base64,{};\n", general_purpose::STANDARD.encode(text));
+    }
+    if !text.contains('\n') {
+        let text = text
+            .replace('\\', "\\\\")
+            .replace('`', "\\`")
+            .replace('#', "\\#");
+        return format!("`{text}`\n");
+    }
+    // A multiline string ends with a newline.
+    match text.strip_suffix('\n') {
+        Some(lines) => format!("```\n{}\n```\n", lines.replace('\\', "\\\\")),
+        None => format!("base64,{};\n", general_purpose::STANDARD.encode(text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let har = r#"{
+  "log": {
+    "version": "1.2",
+    "creator": {"name": "Firefox", "version": "130.0"},
+    "entries": [
+      {
+        "request": {
+          "method": "GET",
+          "url": "https://example.org/?q=hurl",
+          "httpVersion": "HTTP/2",
+          "headers": [
+            {"name": ":authority", "value": "example.org"},
+            {"name": "accept", "value": "text/html"},
+            {"name": "cookie", "value": "lang=en; theme=dark"}
+          ],
+          "cookies": [{"name": "lang", "value": "en"}, {"name": "theme", "value": "dark"}],
+          "queryString": [{"name": "q", "value": "hurl"}]
+        },
+        "response": {"status": 200, "statusText": "OK"}
+      },
+      {
+        "request": {"method": "GET", "url": "data:image/png;base64,AAAA", "headers": []},
+        "response": {"status": 200}
+      },
+      {
+        "request": {
+          "method": "POST",
+          "url": "https://example.org/api/users",
+          "headers": [
+            {"name": "Content-Type", "value": "application/json"},
+            {"name": "Content-Length", "value": "15"}
+          ],
+          "postData": {"mimeType": "application/json", "text": "{\"name\":\"Bob\"}"}
+        },
+        "response": {"status": 201}
+      },
+      {
+        "request": {
+          "method": "POST",
+          "url": "https://example.org/upload",
+          "headers": [{"name": "Content-Type", "value": "multipart/form-data; boundary=----1234"}],
+          "postData": {
+            "mimeType": "multipart/form-data; boundary=----1234",
+            "params": [
+              {"name": "title", "value": "Hello #1"},
+              {"name": "file", "fileName": "my file.txt", "contentType": "text/plain"}
+            ]
+          }
+        },
+        "response": {"status": 0}
+      }
+    ]
+  }
+}"#;
+        assert_eq!(
+            parse(har).unwrap(),
+            r#"GET https://example.org/?q=hurl
+accept: text/html
+[Cookies]
+lang: en
+theme: dark
+HTTP 200
+
+POST https://example.org/api/users
+Content-Type: application/json
+{"name":"Bob"}
+HTTP 201
+
+POST https://example.org/upload
+[MultipartFormData]
+title: Hello \#1
+file: file,my\ file.txt; text/plain
+"#
+        );
+    }
+
+    #[test]
+    fn test_body() {
+        assert_eq!(body("a=1&b=2", None), "`a=1&b=2`\n");
+        assert_eq!(body("a=`#`", None), "`a=\\`\\#\\``\n");
+        assert_eq!(body("line1\nline2\n", None), "```\nline1\nline2\n```\n");
+        assert_eq!(body("line1\nline2", None), "base64,bGluZTEKbGluZTI=;\n");
+        assert_eq!(body("{{name}}", None), "base64,e3tuYW1lfX0=;\n");
+        assert_eq!(body("AAEC", Some("base64")), "base64,AAEC;\n");
+        assert_eq!(body("[1, 2]", None), "[1, 2]\n");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("{}").unwrap_err(),
+            "Can not parse HAR file: missing log entries"
+        );
+        assert_eq!(
+            parse(r#"{"log": {"entries": [{"request": {"method": "GET"}}]}}"#).unwrap_err(),
+            "Can not convert HAR entry 1: missing request URL"
+        );
+    }
+}
@@ -17,5 +17,11 @@
  */
 pub mod cli;
 pub mod curl;
+mod escape;
 pub mod format;
+pub mod har;
 pub mod linter;
+pub mod lsp;
+pub mod openapi;
+pub mod postman;
+pub mod wasm;
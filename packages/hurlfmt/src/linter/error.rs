@@ -20,6 +20,8 @@ use hurl_core::error;
 use hurl_core::error::DisplaySourceError;
 use hurl_core::text::{Style, StyledString};
 
+use crate::format::JValue;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct LinterError {
     pub source_info: SourceInfo,
@@ -31,6 +33,26 @@ pub enum LinterErrorKind {
     UnnecessarySpace,
     UnnecessaryJsonEncoding,
     OneSpace,
+    DuplicatedHeader,
+    UnusedCapture,
+    DeprecatedVersion,
+}
+
+/// Returns a JSON list of `lints`, each lint being an error with the name of its file.
+pub fn format_json(lints: &[(String, LinterError)]) -> String {
+    let lints = lints
+        .iter()
+        .map(|(filename, error)| {
+            let pos = error.source_info.start;
+            JValue::Object(vec![
+                ("filename".to_string(), JValue::String(filename.clone())),
+                ("line".to_string(), JValue::Number(pos.line.to_string())),
+                ("column".to_string(), JValue::Number(pos.column.to_string())),
+                ("message".to_string(), JValue::String(error.description())),
+            ])
+        })
+        .collect();
+    JValue::List(lints).format()
 }
 
 ///
@@ -46,6 +68,9 @@ impl DisplaySourceError for LinterError {
             LinterErrorKind::UnnecessarySpace => "Unnecessary space".to_string(),
             LinterErrorKind::UnnecessaryJsonEncoding => "Unnecessary json encoding".to_string(),
             LinterErrorKind::OneSpace => "One space".to_string(),
+            LinterErrorKind::DuplicatedHeader => "Duplicated header".to_string(),
+            LinterErrorKind::UnusedCapture => "Unused capture".to_string(),
+            LinterErrorKind::DeprecatedVersion => "Deprecated syntax".to_string(),
         }
     }
 
@@ -54,6 +79,9 @@ impl DisplaySourceError for LinterError {
             LinterErrorKind::UnnecessarySpace => "Remove space".to_string(),
             LinterErrorKind::UnnecessaryJsonEncoding => "Use Simple String".to_string(),
             LinterErrorKind::OneSpace => "Use only one space".to_string(),
+            LinterErrorKind::DuplicatedHeader => "Remove duplicated header".to_string(),
+            LinterErrorKind::UnusedCapture => "Capture is never used".to_string(),
+            LinterErrorKind::DeprecatedVersion => "Use HTTP instead of HTTP/*".to_string(),
         };
         let mut s = StyledString::new();
         let message = error::add_carets(&message, self.source_info(), content);
@@ -16,7 +16,9 @@
  *
  */
 pub use rules::{check_hurl_file, lint_hurl_file};
+pub use warnings::check_warnings;
 
-pub use self::error::{LinterError, LinterErrorKind};
+pub use self::error::{format_json, LinterError, LinterErrorKind};
 mod error;
 mod rules;
+mod warnings;
@@ -189,6 +189,9 @@ fn lint_section_value(section_value: &SectionValue) -> SectionValue {
         SectionValue::Options(options) => {
             SectionValue::Options(options.iter().map(lint_entry_option).collect())
         }
+        SectionValue::WebSocket(frames) => {
+            SectionValue::WebSocket(frames.iter().map(lint_frame).collect())
+        }
     }
 }
 
@@ -201,6 +204,7 @@ fn section_value_index(section_value: SectionValue) -> u32 {
         SectionValue::FormParams(_) => 3,
         SectionValue::MultipartFormData(_) => 4,
         SectionValue::Cookies(_) => 5,
+        SectionValue::WebSocket(_) => 6,
         // Response sections
         SectionValue::Captures(_) => 0,
         SectionValue::Asserts(_) => 1,
@@ -238,6 +242,12 @@ fn lint_capture(capture: &Capture) -> Capture {
         space2: one_whitespace(),
         query: lint_query(&capture.query),
         filters,
+        space3: if capture.redacted {
+            one_whitespace()
+        } else {
+            empty_whitespace()
+        },
+        redacted: capture.redacted,
         line_terminator0: lint_line_terminator(&capture.line_terminator0),
     }
 }
@@ -253,6 +263,7 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
     match query_value {
         QueryValue::Status => QueryValue::Status,
         QueryValue::Url => QueryValue::Url,
+        QueryValue::Redirects => QueryValue::Redirects,
         QueryValue::Header { name, .. } => QueryValue::Header {
             name: name.clone(),
             space0: one_whitespace(),
@@ -288,9 +299,16 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
             space0: one_whitespace(),
         },
         QueryValue::Duration => QueryValue::Duration,
+        QueryValue::Timing { attribute_name, .. } => QueryValue::Timing {
+            attribute_name: *attribute_name,
+            space0: one_whitespace(),
+        },
         QueryValue::Bytes => QueryValue::Bytes,
         QueryValue::Sha256 => QueryValue::Sha256,
+        QueryValue::Sha1 => QueryValue::Sha1,
         QueryValue::Md5 => QueryValue::Md5,
+        QueryValue::Frames => QueryValue::Frames,
+        QueryValue::Events => QueryValue::Events,
         QueryValue::Certificate {
             attribute_name: field,
             ..
@@ -399,6 +417,10 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::MatchSchema { value, .. } => PredicateFuncValue::MatchSchema {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+        },
         PredicateFuncValue::StartWith { value, .. } => PredicateFuncValue::StartWith {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
@@ -504,6 +526,10 @@ fn lint_cookie(cookie: &Cookie) -> Cookie {
     cookie.clone()
 }
 
+fn lint_frame(frame: &Frame) -> Frame {
+    frame.clone()
+}
+
 fn lint_body(body: &Body) -> Body {
     let line_terminators = body.line_terminators.clone();
     let space0 = empty_whitespace();
@@ -681,12 +707,26 @@ fn lint_entry_option(entry_option: &EntryOption) -> EntryOption {
 
 fn lint_option_kind(option_kind: &OptionKind) -> OptionKind {
     match option_kind {
+        OptionKind::ConnectTimeout(duration) => {
+            OptionKind::ConnectTimeout(lint_duration_option(duration, DurationUnit::Second))
+        }
+        OptionKind::MaxTime(duration) => {
+            OptionKind::MaxTime(lint_duration_option(duration, DurationUnit::Second))
+        }
         OptionKind::Delay(duration) => {
             OptionKind::Delay(lint_duration_option(duration, DurationUnit::MilliSecond))
         }
         OptionKind::RetryInterval(duration) => {
             OptionKind::RetryInterval(lint_duration_option(duration, DurationUnit::MilliSecond))
         }
+        OptionKind::StreamTimeout(duration) => {
+            OptionKind::StreamTimeout(lint_duration_option(duration, DurationUnit::MilliSecond))
+        }
+        OptionKind::OnlyIf(condition) => OptionKind::OnlyIf(Condition {
+            expr: condition.expr.clone(),
+            space0: one_whitespace(),
+            predicate: lint_predicate(&condition.predicate),
+        }),
         _ => option_kind.clone(),
     }
 }
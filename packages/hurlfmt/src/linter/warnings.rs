@@ -0,0 +1,169 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashSet;
+
+use hurl_core::ast::visit::Visitor;
+use hurl_core::ast::*;
+
+use crate::linter::{LinterError, LinterErrorKind};
+
+/// Returns lint warnings for the `hurl_file`.
+///
+/// Contrary to [`crate::linter::check_hurl_file`], warnings are not formatting issues but possible
+/// mistakes: duplicated request headers, unused captures and deprecated syntax.
+pub fn check_warnings(hurl_file: &HurlFile) -> Vec<LinterError> {
+    let mut errors = vec![];
+    for (index, entry) in hurl_file.entries.iter().enumerate() {
+        errors.extend(check_duplicated_headers(&entry.request));
+        if let Some(response) = &entry.response {
+            if response.version.value == VersionValue::VersionAnyLegacy {
+                errors.push(LinterError {
+                    source_info: response.version.source_info,
+                    kind: LinterErrorKind::DeprecatedVersion,
+                });
+            }
+            // A capture can be used in the response sections of its own entry, or in any
+            // following entry.
+            let mut variables = VariableNames::default();
+            for section in &response.sections {
+                variables.visit_section(section);
+            }
+            for entry in &hurl_file.entries[index + 1..] {
+                variables.visit_entry(entry);
+            }
+            for capture in response.captures() {
+                let name = capture.name.to_string();
+                if !variables.0.contains(&name) {
+                    errors.push(LinterError {
+                        source_info: capture.name.source_info,
+                        kind: LinterErrorKind::UnusedCapture,
+                    });
+                }
+            }
+        }
+    }
+    errors
+}
+
+/// Collects the name of the variables of every visited expression.
+#[derive(Default)]
+struct VariableNames(HashSet<String>);
+
+impl Visitor for VariableNames {
+    fn visit_expr(&mut self, expr: &Expr) {
+        self.0.insert(expr.variable.name.clone());
+    }
+}
+
+/// Returns errors for request headers that are declared more than once with the same value.
+fn check_duplicated_headers(request: &Request) -> Vec<LinterError> {
+    let mut errors = vec![];
+    let mut headers = HashSet::new();
+    for header in request.headers.iter() {
+        let name = header.key.to_string().to_lowercase();
+        let value = header.value.to_string();
+        if !headers.insert((name, value)) {
+            errors.push(LinterError {
+                source_info: header.key.source_info,
+                kind: LinterErrorKind::DuplicatedHeader,
+            });
+        }
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser;
+
+    use super::*;
+
+    fn kinds(content: &str) -> Vec<(usize, LinterErrorKind)> {
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        check_warnings(&hurl_file)
+            .iter()
+            .map(|e| (e.source_info.start.line, e.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_duplicated_headers() {
+        let content = r#"GET http://localhost:8000/hello
+Accept: */*
+Foo: bar
+accept: */*
+Foo: baz
+"#;
+        assert_eq!(kinds(content), vec![(4, LinterErrorKind::DuplicatedHeader)]);
+    }
+
+    #[test]
+    fn test_unused_captures() {
+        let content = r#"GET http://localhost:8000/a
+HTTP 200
+[Captures]
+id: jsonpath "$.id"
+token: header "token"
+unused: header "foo"
+[Asserts]
+header "bar" == "{{token}}"
+
+GET http://localhost:8000/b/{{id}}
+"#;
+        assert_eq!(kinds(content), vec![(6, LinterErrorKind::UnusedCapture)]);
+    }
+
+    #[test]
+    fn test_unused_captures_in_templates() {
+        let content = r#"GET http://localhost:8000/a
+HTTP 200
+[Captures]
+key: jsonpath "$.key"
+name: jsonpath "$.name"
+part: jsonpath "$.part"
+suffix: jsonpath "$.suffix"
+
+POST http://localhost:8000/b
+{"{{key}}": 1}
+
+POST http://localhost:8000/c
+```
+Hello {{name}}
+```
+
+POST http://localhost:8000/d
+[MultipartFormData]
+file: file,{{part}}.txt;
+HTTP 200
+[Asserts]
+jsonpath "$.name" endsWith "{{suffix}}"
+"#;
+        assert_eq!(kinds(content), vec![]);
+    }
+
+    #[test]
+    fn test_deprecated_version() {
+        let content = r#"GET http://localhost:8000/hello
+HTTP/* 200
+"#;
+        assert_eq!(
+            kinds(content),
+            vec![(2, LinterErrorKind::DeprecatedVersion)]
+        );
+    }
+}
@@ -0,0 +1,399 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+use hurl_core::error::DisplaySourceError;
+use hurl_core::parser::{self, ParseError};
+use hurl_core::reader::Pos;
+use hurl_core::text::Format;
+use serde_json::{json, Value};
+
+use crate::lsp::keywords::{FILTERS, HEADERS, PREDICATES, QUERIES, SECTIONS};
+use crate::{format, linter};
+
+// Diagnostic severities, as defined by the LSP specification.
+const SEVERITY_ERROR: u8 = 1;
+const SEVERITY_WARNING: u8 = 2;
+const SEVERITY_INFORMATION: u8 = 3;
+
+// Completion item kinds, as defined by the LSP specification.
+const KIND_FUNCTION: u8 = 3;
+const KIND_FIELD: u8 = 5;
+const KIND_MODULE: u8 = 9;
+const KIND_OPERATOR: u8 = 24;
+
+/// Where the cursor is in a Hurl document, used to select completions and hovers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Context {
+    /// The headers of a request or a response.
+    Headers,
+    /// A section, like `[Asserts]`.
+    Section(String),
+    /// Before the first request.
+    Start,
+}
+
+/// Returns the diagnostics of the Hurl document `text`: the parsing errors, the formatting issues
+/// and, for a valid document, the lint warnings.
+pub fn diagnostics(text: &str) -> Vec<Value> {
+    let lines = text.split('\n').collect::<Vec<_>>();
+    let (hurl_file, errors) = parser::parse_hurl_file_with_recovery(text);
+    let is_valid = errors.is_empty();
+    let errors = errors.into_iter().map(|e| error_diagnostic(&lines, &e));
+    let lints = linter::check_hurl_file(&hurl_file)
+        .into_iter()
+        .map(|e| (e, SEVERITY_INFORMATION));
+    // Warnings like unused captures are not reliable when some entries can not be parsed.
+    let warnings = if is_valid {
+        linter::check_warnings(&hurl_file)
+    } else {
+        vec![]
+    };
+    let warnings = warnings.into_iter().map(|e| (e, SEVERITY_WARNING));
+    let lints = lints.chain(warnings).map(|(e, severity)| {
+        let message = error_message(&e, &lines);
+        diagnostic(&lines, e.source_info, severity, &message)
+    });
+    errors.chain(lints).collect()
+}
+
+/// Returns the diagnostic of the parsing error `error` of the document whose lines are `lines`.
+pub fn error_diagnostic(lines: &[&str], error: &ParseError) -> Value {
+    let message = error_message(error, lines);
+    diagnostic(lines, error.source_info(), SEVERITY_ERROR, &message)
+}
+
+/// Returns a one-line message for `error`, its description followed by its fix hint.
+fn error_message(error: &dyn DisplaySourceError, lines: &[&str]) -> String {
+    let fixme = error.fixme(lines).to_string(Format::Plain);
+    // The fix hint is prefixed with carets under the error column.
+    let fixme = fixme.trim_start().trim_start_matches('^').trim_start();
+    format!("{}: {fixme}", error.description())
+}
+
+fn diagnostic(lines: &[&str], source_info: SourceInfo, severity: u8, message: &str) -> Value {
+    let start = source_info.start;
+    let end = if source_info.end == start {
+        Pos::new(start.line, start.column + 1)
+    } else {
+        source_info.end
+    };
+    json!({
+        "range": {"start": position(lines, start), "end": position(lines, end)},
+        "severity": severity,
+        "source": "hurlfmt",
+        "message": message,
+    })
+}
+
+/// Converts a Hurl position (1-based line and column in chars) to a LSP position (0-based line
+/// and column in UTF-16 code units).
+fn position(lines: &[&str], pos: Pos) -> Value {
+    let line = pos.line.saturating_sub(1);
+    let text = lines.get(line).copied().unwrap_or_default();
+    let character = text
+        .chars()
+        .take(pos.column.saturating_sub(1))
+        .map(char::len_utf16)
+        .sum::<usize>();
+    json!({"line": line, "character": character})
+}
+
+/// Returns the byte index in `line` of the LSP column `character`, in UTF-16 code units.
+fn byte_index(line: &str, character: usize) -> usize {
+    let mut utf16 = 0;
+    for (index, c) in line.char_indices() {
+        if utf16 >= character {
+            return index;
+        }
+        utf16 += c.len_utf16();
+    }
+    line.len()
+}
+
+/// Returns the edits formatting the Hurl document `text`, or `None` if `text` can not be parsed.
+pub fn formatting(text: &str) -> Option<Vec<Value>> {
+    let hurl_file = parser::parse_hurl_file(text).ok()?;
+    let hurl_file = linter::lint_hurl_file(&hurl_file);
+    let mut formatted = format::format_text(&hurl_file, false);
+    if !formatted.ends_with('\n') {
+        formatted.push('\n');
+    }
+    if formatted == text {
+        return Some(vec![]);
+    }
+    let lines = text.split('\n').collect::<Vec<_>>();
+    let last = lines.len() - 1;
+    let end = json!({"line": last, "character": lines[last].encode_utf16().count()});
+    Some(vec![json!({
+        "range": {"start": {"line": 0, "character": 0}, "end": end},
+        "newText": formatted,
+    })])
+}
+
+/// Returns the Markdown documentation of the section, query, predicate or filter at the LSP
+/// position (`line`, `character`) of the Hurl document `text`.
+pub fn hover(text: &str, line: usize, character: usize) -> Option<String> {
+    let lines = text.split('\n').collect::<Vec<_>>();
+    let line_text = lines.get(line)?;
+    let index = byte_index(line_text, character);
+
+    let trimmed = line_text.trim();
+    if let Some(name) = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        let (name, doc) = SECTIONS.iter().find(|(n, _)| *n == name)?;
+        return Some(format!("`[{name}]` section\n\n{doc}"));
+    }
+
+    match context(&lines, line) {
+        Context::Section(name) if name == "Asserts" || name == "Captures" => {}
+        _ => return None,
+    }
+    let is_word_char = |c: char| c.is_ascii_alphanumeric() || "=!<>".contains(c);
+    let start = line_text[..index]
+        .rfind(|c| !is_word_char(c))
+        .map_or(0, |i| i + 1);
+    let end = line_text[index..]
+        .find(|c| !is_word_char(c))
+        .map_or(line_text.len(), |i| index + i);
+    let word = &line_text[start..end];
+    if word.is_empty() {
+        return None;
+    }
+
+    // A query is the first token of an assert, or the first token after the name of a capture.
+    let before = line_text[..start].trim_end();
+    let (keywords, kind) = if before.is_empty() || before.ends_with(':') {
+        (QUERIES, "query")
+    } else if PREDICATES.iter().any(|(n, _)| *n == word) {
+        (PREDICATES, "predicate")
+    } else {
+        (FILTERS, "filter")
+    };
+    let (name, doc) = keywords.iter().find(|(n, _)| *n == word)?;
+    Some(format!("`{name}` {kind}\n\n{doc}"))
+}
+
+/// Returns the completion items at the LSP position (`line`, `character`) of the Hurl document
+/// `text`: section names, header names, queries, predicates and filters.
+pub fn completion(text: &str, line: usize, character: usize) -> Vec<Value> {
+    let lines = text.split('\n').collect::<Vec<_>>();
+    let Some(line_text) = lines.get(line) else {
+        return vec![];
+    };
+    let prefix = &line_text[..byte_index(line_text, character)];
+    let trimmed = prefix.trim_start();
+
+    // Section names replace the whole `[...` prefix typed so far.
+    if trimmed.starts_with('[') {
+        let start = json!({"line": line, "character": (prefix.len() - trimmed.len())});
+        let end = json!({"line": line, "character": prefix.encode_utf16().count()});
+        return SECTIONS
+            .iter()
+            .map(|(name, doc)| {
+                let label = format!("[{name}]");
+                json!({
+                    "label": label,
+                    "kind": KIND_MODULE,
+                    "documentation": doc,
+                    "filterText": label,
+                    "textEdit": {"range": {"start": start, "end": end}, "newText": label},
+                })
+            })
+            .collect();
+    }
+
+    match context(&lines, line) {
+        Context::Headers if !trimmed.contains([' ', ':']) => HEADERS
+            .iter()
+            .map(|name| {
+                json!({
+                    "label": name,
+                    "kind": KIND_FIELD,
+                    "insertText": format!("{name}: "),
+                })
+            })
+            .collect(),
+        Context::Section(name) if name == "Asserts" => {
+            if trimmed.contains(' ') {
+                items(PREDICATES, KIND_OPERATOR)
+                    .chain(items(FILTERS, KIND_FUNCTION))
+                    .collect()
+            } else {
+                items(QUERIES, KIND_FUNCTION).collect()
+            }
+        }
+        Context::Section(name) if name == "Captures" => match trimmed.split_once(':') {
+            Some((_, query)) if query.trim_start().contains(' ') => {
+                items(FILTERS, KIND_FUNCTION).collect()
+            }
+            Some(_) => items(QUERIES, KIND_FUNCTION).collect(),
+            None => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+fn items(
+    keywords: &'static [(&'static str, &'static str)],
+    kind: u8,
+) -> impl Iterator<Item = Value> {
+    keywords.iter().map(move |(name, doc)| {
+        json!({
+            "label": name,
+            "kind": kind,
+            "documentation": doc,
+        })
+    })
+}
+
+/// Returns the context of the line at index `line` of a Hurl document `lines`, looking at the
+/// previous lines.
+fn context(lines: &[&str], line: usize) -> Context {
+    for text in lines[..line].iter().rev() {
+        let text = text.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = text.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            return Context::Section(name.to_string());
+        }
+        // A request line (`GET http://...`) or a response line (`HTTP 200`).
+        if let Some((first, _)) = text.split_once(' ') {
+            if !first.is_empty() && first.chars().all(|c| c.is_ascii_uppercase()) {
+                return Context::Headers;
+            }
+        }
+    }
+    Context::Start
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostics() {
+        assert_eq!(
+            diagnostics("GET http://localhost:8000/hello\nHTTP 200\n"),
+            Vec::<Value>::new()
+        );
+        assert_eq!(
+            diagnostics("GET http://localhost:8000/hello\nHTTP 200\n[Assert]\n"),
+            vec![json!({
+                "range": {
+                    "start": {"line": 2, "character": 1},
+                    "end": {"line": 2, "character": 2},
+                },
+                "severity": 1,
+                "source": "hurlfmt",
+                "message": "Parsing response section name: the section is not valid. Did you mean Asserts?",
+            })]
+        );
+        assert_eq!(
+            diagnostics("GET http://localhost:8000/hello\nAccept: */*\nAccept: */*\n"),
+            vec![json!({
+                "range": {
+                    "start": {"line": 2, "character": 0},
+                    "end": {"line": 2, "character": 6},
+                },
+                "severity": 2,
+                "source": "hurlfmt",
+                "message": "Duplicated header: Remove duplicated header",
+            })]
+        );
+
+        // All the parsing errors are reported.
+        let text = "GET\n\nGET http://localhost:8000/hello\nHTTP 200\n[Assert]\n";
+        let diagnostics = diagnostics(text);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            diagnostics[0]["range"]["start"],
+            json!({"line": 0, "character": 3})
+        );
+        assert_eq!(
+            diagnostics[1]["range"]["start"],
+            json!({"line": 4, "character": 1})
+        );
+    }
+
+    #[test]
+    fn test_formatting() {
+        let text = "GET   http://localhost:8000/hello\nHTTP 200\n";
+        assert_eq!(
+            formatting(text).unwrap(),
+            vec![json!({
+                "range": {
+                    "start": {"line": 0, "character": 0},
+                    "end": {"line": 2, "character": 0},
+                },
+                "newText": "GET http://localhost:8000/hello\nHTTP 200\n",
+            })]
+        );
+        assert!(formatting("GET http://localhost:8000/hello\n")
+            .unwrap()
+            .is_empty());
+        assert!(formatting("GET\n").is_none());
+    }
+
+    #[test]
+    fn test_hover() {
+        let text = r#"GET http://localhost:8000/hello
+HTTP 200
+[Captures]
+id: jsonpath "$.id"
+[Asserts]
+jsonpath "$.tags" count >= 2
+"#;
+        assert_eq!(
+            hover(text, 4, 3).unwrap(),
+            "`[Asserts]` section\n\nResponse section: asserts on the HTTP response, one per line (`query predicate value`)."
+        );
+        assert!(hover(text, 3, 6).unwrap().starts_with("`jsonpath` query"));
+        assert!(hover(text, 5, 2).unwrap().starts_with("`jsonpath` query"));
+        assert!(hover(text, 5, 19).unwrap().starts_with("`count` filter"));
+        assert!(hover(text, 5, 25).unwrap().starts_with("`>=` predicate"));
+        assert_eq!(hover(text, 0, 1), None);
+    }
+
+    fn labels(items: Vec<Value>) -> Vec<String> {
+        items
+            .iter()
+            .map(|item| item["label"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_completion() {
+        let text =
+            "GET http://localhost:8000/hello\nAcc\nHTTP 200\n[As\n[Asserts]\njsonpath \"$.id\" \n";
+        assert!(labels(completion(text, 1, 3)).contains(&"Accept".to_string()));
+        let sections = completion(text, 3, 3);
+        assert_eq!(sections[0]["label"], "[Asserts]");
+        assert_eq!(
+            sections[0]["textEdit"]["range"],
+            json!({"start": {"line": 3, "character": 0}, "end": {"line": 3, "character": 3}})
+        );
+        assert!(labels(completion(text, 5, 0)).contains(&"jsonpath".to_string()));
+        let predicates = labels(completion(text, 5, 16));
+        assert!(predicates.contains(&"==".to_string()));
+        assert!(predicates.contains(&"count".to_string()));
+
+        let text = "GET http://localhost:8000/hello\nHTTP 200\n[Captures]\nid: \n";
+        assert!(labels(completion(text, 3, 4)).contains(&"jsonpath".to_string()));
+        assert!(completion(text, 3, 1).is_empty());
+    }
+}
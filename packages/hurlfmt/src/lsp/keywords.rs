@@ -0,0 +1,260 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+/// Request and response sections, with their description.
+pub const SECTIONS: &[(&str, &str)] = &[
+    (
+        "Asserts",
+        "Response section: asserts on the HTTP response, one per line (`query predicate value`).",
+    ),
+    (
+        "BasicAuth",
+        "Request section: basic authentication, with a single `user: password` line.",
+    ),
+    (
+        "Captures",
+        "Response section: captures of values from the HTTP response, as `name: query`.",
+    ),
+    (
+        "Cookies",
+        "Request section: cookies sent with the request, as `name: value`.",
+    ),
+    (
+        "FormParams",
+        "Request section: URL encoded form parameters, as `name: value`.",
+    ),
+    (
+        "MultipartFormData",
+        "Request section: multipart form data, as `name: value` or `name: file,path;`.",
+    ),
+    (
+        "Options",
+        "Request section: options for this request only, like `location: true` or `retry: 3`.",
+    ),
+    (
+        "QueryStringParams",
+        "Request section: query string parameters added to the URL, as `name: value`.",
+    ),
+    (
+        "WebSocket",
+        "Request section: frames sent over a WebSocket, as `text: value` or `binary: hex`.",
+    ),
+];
+
+/// Queries, with their description.
+pub const QUERIES: &[(&str, &str)] = &[
+    ("body", "Query the HTTP response body, decoded as text."),
+    ("bytes", "Query the HTTP response body as bytes."),
+    (
+        "certificate",
+        "Query a field of the server certificate, like `Subject` or `Expire-Date`.",
+    ),
+    (
+        "cookie",
+        "Query a response cookie, or one of its attributes.",
+    ),
+    (
+        "duration",
+        "Query the duration of the HTTP transaction, in milliseconds.",
+    ),
+    (
+        "events",
+        "Query the list of server-sent events received from a `text/event-stream` response.",
+    ),
+    (
+        "frames",
+        "Query the list of frames received over a WebSocket.",
+    ),
+    (
+        "header",
+        "Query the value of a response header, case insensitive.",
+    ),
+    (
+        "jsonpath",
+        "Query the HTTP response body with a JSONPath expression.",
+    ),
+    ("md5", "Query the MD5 hash of the HTTP response body bytes."),
+    (
+        "redirects",
+        "Query the list of redirections followed to get the final response.",
+    ),
+    (
+        "regex",
+        "Query the HTTP response body with a regex, returning the first captured group.",
+    ),
+    (
+        "sha1",
+        "Query the SHA-1 hash of the HTTP response body bytes.",
+    ),
+    (
+        "sha256",
+        "Query the SHA-256 hash of the HTTP response body bytes.",
+    ),
+    ("status", "Query the HTTP response status code."),
+    (
+        "url",
+        "Query the final URL of the HTTP response, after any redirection.",
+    ),
+    ("variable", "Query the value of a variable."),
+    (
+        "xpath",
+        "Query the HTML or XML response body with an XPath expression.",
+    ),
+];
+
+/// Predicates, with their description.
+pub const PREDICATES: &[(&str, &str)] = &[
+    ("==", "Query and predicate value are equal."),
+    ("!=", "Query and predicate value are different."),
+    (">", "Query number is greater than predicate value."),
+    (
+        ">=",
+        "Query number is greater than or equal to the predicate value.",
+    ),
+    ("<", "Query number is less than that predicate value."),
+    (
+        "<=",
+        "Query number is less than or equal to the predicate value.",
+    ),
+    (
+        "contains",
+        "Query contains the predicate value (string or binary content).",
+    ),
+    (
+        "endsWith",
+        "Query ends with the predicate value (string or binary content).",
+    ),
+    ("exists", "Query returns a value."),
+    (
+        "includes",
+        "Query collections includes the predicate value.",
+    ),
+    ("isBoolean", "Query returns a boolean."),
+    ("isCollection", "Query returns a collection."),
+    ("isDate", "Query returns a date."),
+    ("isEmpty", "Query returns an empty collection."),
+    ("isFloat", "Query returns a float."),
+    ("isInteger", "Query returns an integer."),
+    (
+        "isIsoDate",
+        "Query string returns a RFC 3339 date (`YYYY-MM-DDTHH:mm:ss.sssZ`).",
+    ),
+    ("isNumber", "Query returns an integer or a float."),
+    ("isString", "Query returns a string."),
+    (
+        "matches",
+        "Part of the query string matches the regex pattern described by the predicate value.",
+    ),
+    (
+        "matchesSchema",
+        "Query is valid against the JSON Schema predicate value.",
+    ),
+    (
+        "not",
+        "Negates the following predicate, for instance `not contains` or `not exists`.",
+    ),
+    (
+        "startsWith",
+        "Query starts with the predicate value (string or binary content).",
+    ),
+];
+
+/// Filters, with their description.
+pub const FILTERS: &[(&str, &str)] = &[
+    (
+        "base64Decode",
+        "Decodes a Base64 encoded string into bytes.",
+    ),
+    (
+        "base64Encode",
+        "Encodes bytes or a string into a Base64 encoded string.",
+    ),
+    ("count", "Counts the number of items in a collection."),
+    (
+        "daysAfterNow",
+        "Returns the number of days between now and a date in the future.",
+    ),
+    (
+        "daysBeforeNow",
+        "Returns the number of days between now and a date in the past.",
+    ),
+    ("decode", "Decodes bytes to string using an encoding."),
+    (
+        "format",
+        "Formats a date to a string given a specification format.",
+    ),
+    (
+        "htmlEscape",
+        "Converts the characters `&`, `<` and `>` to HTML-safe sequence.",
+    ),
+    (
+        "htmlUnescape",
+        "Converts HTML character references to the corresponding characters.",
+    ),
+    ("jsonpath", "Evaluates a JSONPath expression."),
+    (
+        "nth",
+        "Returns the element from a collection at a zero-based index.",
+    ),
+    (
+        "regex",
+        "Extracts regex capture group. Pattern must have at least one capture group.",
+    ),
+    (
+        "replace",
+        "Replaces all occurrences of old string with new string.",
+    ),
+    (
+        "split",
+        "Splits to a list of strings around occurrences of the specified delimiter.",
+    ),
+    (
+        "toDate",
+        "Converts a string to a date given a specification format.",
+    ),
+    ("toFloat", "Converts a string to a float."),
+    ("toInt", "Converts a string to an integer."),
+    (
+        "urlDecode",
+        "Replaces `%xx` escapes with their single-character equivalent.",
+    ),
+    (
+        "urlEncode",
+        "Percent-encodes all the characters which are not unreserved.",
+    ),
+    ("xpath", "Evaluates a XPath expression."),
+];
+
+/// Common request header names.
+pub const HEADERS: &[&str] = &[
+    "Accept",
+    "Accept-Encoding",
+    "Accept-Language",
+    "Authorization",
+    "Cache-Control",
+    "Connection",
+    "Content-Type",
+    "Cookie",
+    "Host",
+    "If-Match",
+    "If-Modified-Since",
+    "If-None-Match",
+    "Origin",
+    "Referer",
+    "User-Agent",
+];
@@ -0,0 +1,127 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! A [Language Server Protocol] server for Hurl files, communicating over standard input and
+//! output.
+//!
+//! The server publishes parsing errors and lint warnings as diagnostics, and provides hover on
+//! sections and queries, completion and document formatting.
+//!
+//! [Language Server Protocol]: https://microsoft.github.io/language-server-protocol/
+use std::io::{self, BufRead, Write};
+
+use serde_json::Value;
+
+pub use self::server::Server;
+use self::server::{error_response, PARSE_ERROR};
+
+pub(crate) mod document;
+mod keywords;
+mod server;
+
+/// Runs a language server reading client messages from `reader` and writing to `writer`, until
+/// the client sends the `exit` notification or closes `reader`.
+///
+/// Returns the exit code of the server.
+pub fn run(reader: &mut impl BufRead, writer: &mut impl Write) -> io::Result<i32> {
+    let mut server = Server::new();
+    while let Some(content) = read_message(reader)? {
+        let messages = match serde_json::from_str::<Value>(&content) {
+            Ok(message) => server.handle(&message),
+            Err(e) => vec![error_response(&Value::Null, PARSE_ERROR, &e.to_string())],
+        };
+        for message in messages {
+            write_message(writer, &message.to_string())?;
+        }
+        if let Some(exit_code) = server.exit_code() {
+            return Ok(exit_code);
+        }
+    }
+    Ok(if server.is_shutdown() { 0 } else { 1 })
+}
+
+/// Reads the content of a message from `reader`, prefixed with a `Content-Length` header.
+/// Returns `None` at the end of the input.
+fn read_message(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            if length.is_some() {
+                break;
+            }
+            continue;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+    let mut content = vec![0; length.unwrap_or_default()];
+    reader.read_exact(&mut content)?;
+    String::from_utf8(content)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Writes a message `content` to `writer`, prefixed with its `Content-Length` header.
+fn write_message(writer: &mut impl Write, content: &str) -> io::Result<()> {
+    write!(writer, "Content-Length: {}\r\n\r\n{content}", content.len())?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(content: &str) -> String {
+        format!("Content-Length: {}\r\n\r\n{content}", content.len())
+    }
+
+    #[test]
+    fn test_run() {
+        let input = [
+            message(r#"{"jsonrpc":"2.0","id":1,"method":"shutdown"}"#),
+            message("{"),
+            message(r#"{"jsonrpc":"2.0","method":"exit"}"#),
+        ]
+        .join("");
+        let mut output = vec![];
+        let exit_code = run(&mut input.as_bytes(), &mut output).unwrap();
+        assert_eq!(exit_code, 0);
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output,
+            [
+                message(r#"{"id":1,"jsonrpc":"2.0","result":null}"#),
+                message(
+                    r#"{"error":{"code":-32700,"message":"EOF while parsing an object at line 1 column 1"},"id":null,"jsonrpc":"2.0"}"#
+                ),
+            ]
+            .join("")
+        );
+
+        // The input is closed without shutdown.
+        let mut output = vec![];
+        assert_eq!(run(&mut "".as_bytes(), &mut output).unwrap(), 1);
+    }
+}
@@ -0,0 +1,271 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::lsp::document;
+
+// JSON-RPC error codes (see <https://www.jsonrpc.org/specification#error_object>).
+pub const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// A language server, holding the Hurl documents opened by the client.
+///
+/// Documents are synchronized in full: each change notification sends the whole text of the
+/// document.
+#[derive(Clone, Debug, Default)]
+pub struct Server {
+    documents: HashMap<String, String>,
+    shutdown: bool,
+    exit_code: Option<i32>,
+}
+
+impl Server {
+    /// Creates a new server, with no opened document.
+    pub fn new() -> Self {
+        Server::default()
+    }
+
+    /// Returns the exit code of the server once the client has sent the `exit` notification,
+    /// `None` otherwise.
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
+    /// Returns true if the client has sent the `shutdown` request.
+    pub fn is_shutdown(&self) -> bool {
+        self.shutdown
+    }
+
+    /// Handles a JSON-RPC `message` sent by the client, and returns the messages to send back:
+    /// the response to a request, or the notifications triggered by a notification.
+    pub fn handle(&mut self, message: &Value) -> Vec<Value> {
+        // Responses from the client are ignored, as the server never sends any request.
+        let Some(method) = message["method"].as_str() else {
+            return vec![];
+        };
+        let params = &message["params"];
+        match message.get("id") {
+            Some(id) => vec![self.handle_request(id, method, params)],
+            None => self.handle_notification(method, params),
+        }
+    }
+
+    fn handle_request(&mut self, id: &Value, method: &str, params: &Value) -> Value {
+        if self.shutdown {
+            return error_response(id, INVALID_REQUEST, "server is shut down");
+        }
+        let result = match method {
+            "initialize" => json!({
+                "capabilities": {
+                    "textDocumentSync": 1,
+                    "hoverProvider": true,
+                    "completionProvider": {"triggerCharacters": ["["]},
+                    "documentFormattingProvider": true,
+                },
+                "serverInfo": {"name": "hurlfmt", "version": env!("CARGO_PKG_VERSION")},
+            }),
+            "shutdown" => {
+                self.shutdown = true;
+                Value::Null
+            }
+            "textDocument/formatting" => match self.document(params) {
+                Some(text) => json!(document::formatting(text)),
+                None => Value::Null,
+            },
+            "textDocument/hover" => match (self.document(params), position(params)) {
+                (Some(text), Some((line, character))) => {
+                    match document::hover(text, line, character) {
+                        Some(value) => json!({
+                            "contents": {"kind": "markdown", "value": value},
+                        }),
+                        None => Value::Null,
+                    }
+                }
+                _ => Value::Null,
+            },
+            "textDocument/completion" => match (self.document(params), position(params)) {
+                (Some(text), Some((line, character))) => {
+                    json!(document::completion(text, line, character))
+                }
+                _ => Value::Null,
+            },
+            _ => {
+                let message = format!("method <{method}> is not supported");
+                return error_response(id, METHOD_NOT_FOUND, &message);
+            }
+        };
+        json!({"jsonrpc": "2.0", "id": id, "result": result})
+    }
+
+    fn handle_notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        match method {
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default();
+                self.documents.insert(uri.to_string(), text.to_string());
+                vec![publish_diagnostics(uri, document::diagnostics(text))]
+            }
+            "textDocument/didChange" => {
+                let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                else {
+                    return vec![];
+                };
+                self.documents.insert(uri.to_string(), text.to_string());
+                vec![publish_diagnostics(uri, document::diagnostics(text))]
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(uri);
+                vec![publish_diagnostics(uri, vec![])]
+            }
+            "exit" => {
+                self.exit_code = Some(if self.shutdown { 0 } else { 1 });
+                vec![]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Returns the text of the document referenced by the request `params`.
+    fn document(&self, params: &Value) -> Option<&str> {
+        let uri = params["textDocument"]["uri"].as_str()?;
+        self.documents.get(uri).map(|s| s.as_str())
+    }
+}
+
+/// Returns the LSP position (line and character) of the request `params`.
+fn position(params: &Value) -> Option<(usize, usize)> {
+    let line = params["position"]["line"].as_u64()?;
+    let character = params["position"]["character"].as_u64()?;
+    Some((line as usize, character as usize))
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": {"uri": uri, "diagnostics": diagnostics},
+    })
+}
+
+/// Returns a JSON-RPC error response to the request `id`.
+pub fn error_response(id: &Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {"code": code, "message": message},
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(id: u64, method: &str, params: Value) -> Value {
+        json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params})
+    }
+
+    fn notification(method: &str, params: Value) -> Value {
+        json!({"jsonrpc": "2.0", "method": method, "params": params})
+    }
+
+    #[test]
+    fn test_lifecycle() {
+        let mut server = Server::new();
+        let responses = server.handle(&request(1, "initialize", json!({})));
+        assert_eq!(responses[0]["id"], 1);
+        assert_eq!(
+            responses[0]["result"]["capabilities"]["documentFormattingProvider"],
+            true
+        );
+        assert!(server
+            .handle(&notification("initialized", json!({})))
+            .is_empty());
+
+        let responses = server.handle(&request(2, "foo", json!({})));
+        assert_eq!(responses[0]["error"]["code"], METHOD_NOT_FOUND);
+
+        let responses = server.handle(&request(3, "shutdown", Value::Null));
+        assert_eq!(
+            responses,
+            vec![json!({"jsonrpc": "2.0", "id": 3, "result": null})]
+        );
+        let responses = server.handle(&request(4, "initialize", json!({})));
+        assert_eq!(responses[0]["error"]["code"], INVALID_REQUEST);
+        assert_eq!(server.exit_code(), None);
+
+        server.handle(&notification("exit", Value::Null));
+        assert_eq!(server.exit_code(), Some(0));
+    }
+
+    #[test]
+    fn test_documents() {
+        let mut server = Server::new();
+        let uri = "file:///tmp/test.hurl";
+        let messages = server.handle(&notification(
+            "textDocument/didOpen",
+            json!({"textDocument": {"uri": uri, "languageId": "hurl", "version": 1, "text": "GET\n"}}),
+        ));
+        assert_eq!(messages[0]["method"], "textDocument/publishDiagnostics");
+        assert_eq!(messages[0]["params"]["uri"], uri);
+        assert_eq!(messages[0]["params"]["diagnostics"][0]["severity"], 1);
+
+        let messages = server.handle(&notification(
+            "textDocument/didChange",
+            json!({
+                "textDocument": {"uri": uri, "version": 2},
+                "contentChanges": [{"text": "GET   http://localhost:8000/hello\n"}],
+            }),
+        ));
+        assert_eq!(messages[0]["params"]["diagnostics"][0]["severity"], 3);
+
+        let responses = server.handle(&request(
+            1,
+            "textDocument/formatting",
+            json!({"textDocument": {"uri": uri}, "options": {"tabSize": 4, "insertSpaces": true}}),
+        ));
+        assert_eq!(
+            responses[0]["result"][0]["newText"],
+            "GET http://localhost:8000/hello\n"
+        );
+
+        let responses = server.handle(&request(
+            2,
+            "textDocument/hover",
+            json!({"textDocument": {"uri": uri}, "position": {"line": 0, "character": 1}}),
+        ));
+        assert_eq!(responses[0]["result"], Value::Null);
+
+        let messages = server.handle(&notification(
+            "textDocument/didClose",
+            json!({"textDocument": {"uri": uri}}),
+        ));
+        assert_eq!(messages[0]["params"]["diagnostics"], json!([]));
+        let responses = server.handle(&request(
+            3,
+            "textDocument/formatting",
+            json!({"textDocument": {"uri": uri}}),
+        ));
+        assert_eq!(responses[0]["result"], Value::Null);
+    }
+}
@@ -23,7 +23,7 @@ use hurl_core::input::Input;
 use hurl_core::{parser, text};
 use hurlfmt::cli::options::{InputFormat, OptionsError, OutputFormat};
 use hurlfmt::cli::Logger;
-use hurlfmt::{cli, curl, format, linter};
+use hurlfmt::{cli, curl, format, har, linter, lsp, openapi, postman};
 
 const EXIT_OK: i32 = 0;
 const EXIT_ERROR: i32 = 1;
@@ -49,7 +49,22 @@ fn main() {
     };
 
     let logger = Logger::new(opts.color);
+
+    // Language server mode, the client messages are read on standard input
+    if opts.lsp {
+        let stdin = io::stdin();
+        let stdout = io::stdout();
+        match lsp::run(&mut stdin.lock(), &mut stdout.lock()) {
+            Ok(exit_code) => process::exit(exit_code),
+            Err(e) => {
+                logger.error(&format!("Language server error - {e}"));
+                process::exit(EXIT_ERROR);
+            }
+        }
+    }
+
     let mut output_all = String::new();
+    let mut lints_all = vec![];
 
     for input_file in &opts.input_files {
         let input_file = Input::new(input_file);
@@ -66,7 +81,7 @@ fn main() {
             }
         };
 
-        // Parse input curl or Hurl file
+        // Parse input curl, HAR, OpenAPI, Postman or Hurl file
         let input = match opts.input_format {
             InputFormat::Hurl => content.to_string(),
             InputFormat::Curl => match curl::parse(&content) {
@@ -76,13 +91,34 @@ fn main() {
                     process::exit(EXIT_INVALID_INPUT);
                 }
             },
+            InputFormat::Har => match har::parse(&content) {
+                Ok(s) => s,
+                Err(e) => {
+                    logger.error(&e);
+                    process::exit(EXIT_INVALID_INPUT);
+                }
+            },
+            InputFormat::OpenApi => match openapi::parse(&content) {
+                Ok(s) => s,
+                Err(e) => {
+                    logger.error(&e);
+                    process::exit(EXIT_INVALID_INPUT);
+                }
+            },
+            InputFormat::Postman => match postman::parse(&content) {
+                Ok(s) => s,
+                Err(e) => {
+                    logger.error(&e);
+                    process::exit(EXIT_INVALID_INPUT);
+                }
+            },
         };
 
         // Parse Hurl content
         let hurl_file = match parser::parse_hurl_file(&input) {
             Ok(h) => h,
             Err(e) => {
-                logger.error_parsing(&content, &input_file, &e);
+                logger.error_parsing(&input, &input_file, &e);
                 process::exit(EXIT_INVALID_INPUT);
             }
         };
@@ -91,7 +127,7 @@ fn main() {
         if opts.check {
             let lints = linter::check_hurl_file(&hurl_file);
             for e in lints.iter() {
-                logger.warn_lint(&content, &input_file, e);
+                logger.warn_lint(&input, &input_file, e);
             }
             if lints.is_empty() {
                 process::exit(EXIT_OK);
@@ -100,6 +136,18 @@ fn main() {
             }
         }
 
+        // Only lint warnings, reported once all files have been processed
+        if opts.lint {
+            let lints = linter::check_warnings(&hurl_file);
+            for e in lints.into_iter() {
+                if opts.output_format != OutputFormat::Json {
+                    logger.warn_lint(&input, &input_file, &e);
+                }
+                lints_all.push((input_file.to_string(), e));
+            }
+            continue;
+        }
+
         // Output files
         let output = match opts.output_format {
             OutputFormat::Hurl => {
@@ -118,6 +166,16 @@ fn main() {
             output_all.push_str(&output);
         }
     }
+    if opts.lint {
+        if opts.output_format == OutputFormat::Json {
+            write_output(&linter::format_json(&lints_all), opts.output_file);
+        }
+        if lints_all.is_empty() {
+            process::exit(EXIT_OK);
+        } else {
+            process::exit(EXIT_LINT_ISSUE);
+        }
+    }
     if !opts.in_place {
         write_output(&output_all, opts.output_file);
     }
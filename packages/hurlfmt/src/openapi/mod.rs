@@ -0,0 +1,514 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use serde_json::{json, Map, Value};
+
+use crate::escape::{escape_filename, escape_key, escape_value, variable_name};
+
+/// HTTP methods of an OpenAPI path item, in the order operations are generated.
+const METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Maximum depth of nested schemas when generating example values (recursive schemas).
+const MAX_DEPTH: usize = 8;
+
+/// Generates a skeleton Hurl file from an OpenAPI 3 document `s` (JSON), with one entry per
+/// operation.
+///
+/// Paths are generated in lexicographic order and path parameters are converted to templates
+/// (`/users/{id}` becomes `/users/{{id}}`). Request bodies use the example of the operation if
+/// any, or a value generated from their schema. Each entry asserts the first documented success
+/// status code.
+pub fn parse(s: &str) -> Result<String, String> {
+    let document = serde_json::from_str::<Value>(s)
+        .map_err(|e| format!("Can not parse OpenAPI document: {e}"))?;
+    match document["openapi"].as_str() {
+        Some(version) if version.starts_with("3.") => {}
+        _ => return Err("Can not parse OpenAPI document: only OpenAPI 3 is supported".to_string()),
+    }
+    let Some(paths) = document["paths"].as_object() else {
+        return Err("Can not parse OpenAPI document: missing paths".to_string());
+    };
+    let base_url = document["servers"][0]["url"]
+        .as_str()
+        .unwrap_or("{{base_url}}")
+        .trim_end_matches('/');
+
+    let mut entries = vec![];
+    for (path, item) in paths {
+        for method in METHODS {
+            let operation = &item[method];
+            if !operation.is_object() {
+                continue;
+            }
+            let parameters = parameters(&document, item, operation);
+            entries.push(entry(
+                &document,
+                base_url,
+                path,
+                method,
+                operation,
+                &parameters,
+            ));
+        }
+    }
+    Ok(entries.join("\n"))
+}
+
+/// Returns the parameters of an `operation`, including the parameters shared by its path `item`.
+/// An operation parameter overrides a path parameter with the same name and location.
+fn parameters<'a>(document: &'a Value, item: &'a Value, operation: &'a Value) -> Vec<&'a Value> {
+    let mut parameters: Vec<&Value> = vec![];
+    for parameter in [&item["parameters"], &operation["parameters"]]
+        .into_iter()
+        .filter_map(|p| p.as_array())
+        .flatten()
+    {
+        let parameter = resolve(document, parameter);
+        parameters.retain(|p| p["name"] != parameter["name"] || p["in"] != parameter["in"]);
+        parameters.push(parameter);
+    }
+    parameters
+}
+
+/// Generates the Hurl entry of an `operation`.
+fn entry(
+    document: &Value,
+    base_url: &str,
+    path: &str,
+    method: &str,
+    operation: &Value,
+    parameters: &[&Value],
+) -> String {
+    let method = method.to_uppercase();
+    let comment = match (
+        operation["operationId"].as_str(),
+        operation["summary"].as_str(),
+    ) {
+        (Some(id), Some(summary)) => format!("{id}: {summary}"),
+        (Some(id), None) => id.to_string(),
+        (None, Some(summary)) => summary.to_string(),
+        (None, None) => format!("{method} {path}"),
+    };
+    let path = path_template(path);
+    let mut s = format!(
+        "# {}\n{method} {base_url}{path}\n",
+        comment.replace('\n', " ")
+    );
+
+    for header in required(parameters, "header") {
+        let name = escape_key(header["name"].as_str().unwrap_or_default());
+        s.push_str(&format!("{name}: {}\n", parameter_value(document, header)));
+    }
+    let params = required(parameters, "query");
+    if !params.is_empty() {
+        s.push_str("[QueryStringParams]\n");
+        for param in params {
+            let name = escape_key(param["name"].as_str().unwrap_or_default());
+            s.push_str(&format!("{name}: {}\n", parameter_value(document, param)));
+        }
+    }
+    let body = resolve(document, &operation["requestBody"]);
+    if let Some(content) = body["content"].as_object() {
+        s.push_str(&request_body(document, content));
+    }
+
+    let status = operation["responses"]
+        .as_object()
+        .and_then(|responses| {
+            responses
+                .keys()
+                .filter(|code| code.starts_with('2'))
+                .min()
+                .cloned()
+        })
+        .filter(|code| code.len() == 3 && code.chars().all(|c| c.is_ascii_digit()))
+        .unwrap_or_else(|| "*".to_string());
+    s.push_str(&format!("HTTP {status}\n"));
+    s
+}
+
+/// Returns the Hurl template of an OpenAPI `path`, path parameters being converted to variables
+/// (`/users/{id}` becomes `/users/{{id}}`).
+fn path_template(path: &str) -> String {
+    let mut s = String::new();
+    let mut rest = path;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        s.push_str(&rest[..start]);
+        s.push_str(&format!(
+            "{{{{{}}}}}",
+            variable_name(&rest[start + 1..start + end])
+        ));
+        rest = &rest[start + end + 1..];
+    }
+    s.push_str(rest);
+    s
+}
+
+/// Returns the required parameters of a `location` (`header`, `query`...).
+fn required<'a>(parameters: &[&'a Value], location: &str) -> Vec<&'a Value> {
+    parameters
+        .iter()
+        .filter(|p| p["in"] == location && p["required"].as_bool().unwrap_or(false))
+        .copied()
+        .collect()
+}
+
+/// Returns the value of a `parameter`: its example if any, or a template with its name.
+fn parameter_value(document: &Value, parameter: &Value) -> String {
+    let example = example(document, parameter)
+        .or_else(|| example(document, resolve(document, &parameter["schema"])));
+    match example {
+        Some(Value::String(s)) => escape_value(&s),
+        Some(value) => value.to_string(),
+        None => {
+            let name = variable_name(parameter["name"].as_str().unwrap_or_default());
+            format!("{{{{{name}}}}}")
+        }
+    }
+}
+
+/// Generates the Hurl request body of an OpenAPI request body `content`, given by media type.
+fn request_body(document: &Value, content: &Map<String, Value>) -> String {
+    let mut s = String::new();
+    if let Some((_, media)) = content.iter().find(|(media_type, _)| is_json(media_type)) {
+        let schema = resolve(document, &media["schema"]);
+        let value = example(document, media).unwrap_or_else(|| generate(document, schema, 0));
+        // `{{` can only be found in JSON strings, and is escaped so it's not parsed as a template.
+        let json = serde_json::to_string_pretty(&value).unwrap();
+        s.push_str(&json.replace("{{", "\\u007b{"));
+        s.push('\n');
+    } else if let Some(media) = content.get("application/x-www-form-urlencoded") {
+        let schema = resolve(document, &media["schema"]);
+        if let Some(properties) = schema["properties"].as_object() {
+            s.push_str("[FormParams]\n");
+            for (name, property) in properties {
+                let value = generate(document, resolve(document, property), 0);
+                let name = escape_key(name);
+                s.push_str(&format!("{name}: {}\n", form_value(&value)));
+            }
+        }
+    } else if let Some(media) = content.get("multipart/form-data") {
+        let schema = resolve(document, &media["schema"]);
+        if let Some(properties) = schema["properties"].as_object() {
+            s.push_str("[MultipartFormData]\n");
+            for (name, property) in properties {
+                let property = resolve(document, property);
+                let filename = escape_filename(&format!("{name}.bin"));
+                let name = escape_key(name);
+                if property["format"] == "binary" {
+                    s.push_str(&format!("{name}: file,{filename};\n"));
+                } else {
+                    let value = generate(document, property, 0);
+                    s.push_str(&format!("{name}: {}\n", form_value(&value)));
+                }
+            }
+        }
+    }
+    s
+}
+
+/// Returns true if `media_type` is a JSON media type, like `application/json` or
+/// `application/problem+json`.
+fn is_json(media_type: &str) -> bool {
+    let media_type = media_type.split(';').next().unwrap_or_default().trim();
+    media_type == "application/json" || media_type.ends_with("+json")
+}
+
+/// Returns the example of an OpenAPI object (media type, parameter or schema), from its `example`
+/// field or its first `examples`.
+fn example(document: &Value, value: &Value) -> Option<Value> {
+    if let Some(example) = value.get("example") {
+        return Some(example.clone());
+    }
+    match &value["examples"] {
+        // Media type and parameter examples are a map of example objects.
+        Value::Object(examples) => examples
+            .values()
+            .next()
+            .and_then(|e| resolve(document, e).get("value").cloned()),
+        // Schema examples (OpenAPI 3.1) are a list of values.
+        Value::Array(examples) => examples.first().cloned(),
+        _ => None,
+    }
+}
+
+/// Generates an example value for a JSON `schema`.
+fn generate(document: &Value, schema: &Value, depth: usize) -> Value {
+    if let Some(example) = example(document, schema) {
+        return example;
+    }
+    if let Some(default) = schema.get("default") {
+        return default.clone();
+    }
+    if let Some(value) = schema["enum"].as_array().and_then(|values| values.first()) {
+        return value.clone();
+    }
+    if depth > MAX_DEPTH {
+        return Value::Null;
+    }
+    if let Some(schemas) = schema["allOf"].as_array() {
+        let mut object = Map::new();
+        for schema in schemas {
+            if let Value::Object(properties) =
+                generate(document, resolve(document, schema), depth + 1)
+            {
+                object.extend(properties);
+            }
+        }
+        return Value::Object(object);
+    }
+    for key in ["oneOf", "anyOf"] {
+        if let Some(schema) = schema[key].as_array().and_then(|schemas| schemas.first()) {
+            return generate(document, resolve(document, schema), depth + 1);
+        }
+    }
+    // In OpenAPI 3.1, the type can be a list of types, like `["string", "null"]`.
+    let schema_type = match &schema["type"] {
+        Value::Array(types) => types
+            .iter()
+            .find_map(|t| t.as_str().filter(|t| *t != "null")),
+        value => value.as_str(),
+    };
+    match schema_type {
+        Some("object") | None if schema["properties"].is_object() => {
+            let mut object = Map::new();
+            for (name, property) in schema["properties"].as_object().unwrap() {
+                let value = generate(document, resolve(document, property), depth + 1);
+                object.insert(name.clone(), value);
+            }
+            Value::Object(object)
+        }
+        Some("object") => json!({}),
+        Some("array") => {
+            let items = resolve(document, &schema["items"]);
+            json!([generate(document, items, depth + 1)])
+        }
+        Some("string") => match schema["format"].as_str() {
+            Some("date") => json!("2024-01-01"),
+            Some("date-time") => json!("2024-01-01T00:00:00Z"),
+            Some("email") => json!("user@example.com"),
+            Some("uuid") => json!("00000000-0000-0000-0000-000000000000"),
+            Some("uri") | Some("url") => json!("https://example.com"),
+            _ => json!("string"),
+        },
+        Some("integer") => json!(0),
+        Some("number") => json!(0.0),
+        Some("boolean") => json!(true),
+        _ => Value::Null,
+    }
+}
+
+/// Resolves a local reference object (`{"$ref": "#/components/schemas/User"}`) of `document`.
+/// Returns `value` if it's not a reference, and `null` for a reference that can not be resolved.
+fn resolve<'a>(document: &'a Value, value: &'a Value) -> &'a Value {
+    let mut value = value;
+    // References can be chained, the number of indirections is bounded for cyclic references.
+    for _ in 0..MAX_DEPTH {
+        let Some(reference) = value["$ref"].as_str() else {
+            return value;
+        };
+        let Some(pointer) = reference.strip_prefix('#') else {
+            return &Value::Null;
+        };
+        value = document.pointer(pointer).unwrap_or(&Value::Null);
+    }
+    value
+}
+
+/// Returns a generated `value` as the value of a form param.
+fn form_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => escape_value(s),
+        value => escape_value(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let document = r##"{
+  "openapi": "3.0.3",
+  "info": {"title": "Users", "version": "1.0.0"},
+  "servers": [{"url": "https://example.org/api/"}],
+  "paths": {
+    "/users": {
+      "get": {
+        "operationId": "listUsers",
+        "parameters": [
+          {"name": "limit", "in": "query", "required": true, "schema": {"type": "integer", "example": 10}},
+          {"name": "offset", "in": "query", "schema": {"type": "integer"}},
+          {"$ref": "#/components/parameters/ApiKey"}
+        ],
+        "responses": {"200": {"description": "OK"}}
+      },
+      "post": {
+        "summary": "Create a user",
+        "requestBody": {
+          "content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}
+        },
+        "responses": {"default": {"description": "Error"}, "201": {"description": "Created"}}
+      }
+    },
+    "/users/{id}": {
+      "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+      "delete": {
+        "responses": {"204": {"description": "Deleted"}}
+      }
+    }
+  },
+  "components": {
+    "parameters": {
+      "ApiKey": {"name": "X-Api-Key", "in": "header", "required": true, "schema": {"type": "string"}}
+    },
+    "schemas": {
+      "User": {
+        "type": "object",
+        "properties": {
+          "name": {"type": "string", "example": "Bob"},
+          "email": {"type": "string", "format": "email"},
+          "age": {"type": "integer"},
+          "roles": {"type": "array", "items": {"type": "string", "enum": ["admin", "user"]}}
+        }
+      }
+    }
+  }
+}"##;
+        assert_eq!(
+            parse(document).unwrap(),
+            r#"# listUsers
+GET https://example.org/api/users
+X-Api-Key: {{X-Api-Key}}
+[QueryStringParams]
+limit: 10
+HTTP 200
+
+# Create a user
+POST https://example.org/api/users
+{
+  "age": 0,
+  "email": "user@example.com",
+  "name": "Bob",
+  "roles": [
+    "admin"
+  ]
+}
+HTTP 201
+
+# DELETE /users/{id}
+DELETE https://example.org/api/users/{{id}}
+HTTP 204
+"#
+        );
+    }
+
+    #[test]
+    fn test_request_body() {
+        let document = json!({});
+        let content = json!({
+            "application/x-www-form-urlencoded": {
+                "schema": {"properties": {"user": {"type": "string"}, "count": {"type": "integer"}}}
+            }
+        });
+        assert_eq!(
+            request_body(&document, content.as_object().unwrap()),
+            "[FormParams]\ncount: 0\nuser: string\n"
+        );
+        let content = json!({
+            "multipart/form-data": {
+                "schema": {"properties": {"file": {"type": "string", "format": "binary"}}}
+            }
+        });
+        assert_eq!(
+            request_body(&document, content.as_object().unwrap()),
+            "[MultipartFormData]\nfile: file,file.bin;\n"
+        );
+        let content = json!({
+            "application/json": {
+                "schema": {"type": "object"},
+                "examples": {"first": {"value": {"id": 1}}}
+            }
+        });
+        assert_eq!(
+            request_body(&document, content.as_object().unwrap()),
+            "{\n  \"id\": 1\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_parameter_names() {
+        let document = r#"{
+  "openapi": "3.0.3",
+  "paths": {
+    "/items/{item.id}": {
+      "get": {
+        "parameters": [
+          {"name": "page[size]", "in": "query", "required": true},
+          {"name": "X Token", "in": "header", "required": true, "example": "{{a}}"}
+        ]
+      }
+    }
+  }
+}"#;
+        assert_eq!(
+            parse(document).unwrap(),
+            r#"# GET /items/{item.id}
+GET {{base_url}}/items/{{item_id}}
+X\u{20}Token: \u{7b}{a}}
+[QueryStringParams]
+page[size]: {{page_size}}
+HTTP *
+"#
+        );
+    }
+
+    #[test]
+    fn test_generate_recursive() {
+        let document = json!({
+            "components": {"schemas": {"Node": {
+                "type": "object",
+                "properties": {"child": {"$ref": "#/components/schemas/Node"}}
+            }}}
+        });
+        let schema = resolve(&document, &document["components"]["schemas"]["Node"]);
+        let value = generate(&document, schema, 0);
+        assert!(value["child"]["child"].is_object());
+        assert_eq!(
+            value.pointer("/child/child/child/child/child/child/child/child/child"),
+            Some(&Value::Null)
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse(r#"{"swagger": "2.0", "paths": {}}"#).unwrap_err(),
+            "Can not parse OpenAPI document: only OpenAPI 3 is supported"
+        );
+        assert_eq!(
+            parse(r#"{"openapi": "3.1.0"}"#).unwrap_err(),
+            "Can not parse OpenAPI document: missing paths"
+        );
+    }
+}
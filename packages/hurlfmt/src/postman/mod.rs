@@ -0,0 +1,433 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use regex::Regex;
+use serde_json::Value;
+
+use crate::escape::{escape_filename, escape_key, escape_quoted, escape_template_value};
+
+/// Converts a Postman collection `s` (Collection v2.1 JSON export) to a Hurl file.
+///
+/// Requests are converted in the order of the collection, folders included. Basic test scripts
+/// (status code, header existence and body content) are converted to asserts, other scripts are
+/// ignored.
+pub fn parse(s: &str) -> Result<String, String> {
+    let collection = serde_json::from_str::<Value>(s)
+        .map_err(|e| format!("Can not parse Postman collection: {e}"))?;
+    let Some(items) = collection["item"].as_array() else {
+        return Err("Can not parse Postman collection: missing item list".to_string());
+    };
+    let mut entries = vec![];
+    parse_items(items, &[], &collection["auth"], &mut entries)?;
+    Ok(entries.join("\n"))
+}
+
+/// Converts the Postman `items` to Hurl entries, appended to `entries`.
+///
+/// `folders` is the path of folder names of the items, and `auth` the authentication inherited
+/// from the parent folder or collection.
+fn parse_items(
+    items: &[Value],
+    folders: &[&str],
+    auth: &Value,
+    entries: &mut Vec<String>,
+) -> Result<(), String> {
+    for item in items {
+        let name = item["name"].as_str().unwrap_or_default();
+        let auth = if item["auth"].is_object() {
+            &item["auth"]
+        } else {
+            auth
+        };
+        let mut path = folders.to_vec();
+        path.push(name);
+        if let Some(children) = item["item"].as_array() {
+            parse_items(children, &path, auth, entries)?;
+        } else if item["request"].is_object() || item["request"].is_string() {
+            let entry = parse_item(item, &path.join(" / "), auth).map_err(|message| {
+                format!("Can not convert Postman request <{name}>: {message}")
+            })?;
+            entries.push(entry);
+        }
+    }
+    Ok(())
+}
+
+/// Converts a Postman request `item` to a Hurl entry, with its `title` as comment.
+fn parse_item(item: &Value, title: &str, auth: &Value) -> Result<String, String> {
+    let request = &item["request"];
+    // A request can be a simple URL string.
+    if let Some(url) = request.as_str() {
+        return Ok(format!("# {title}\nGET {url}\n"));
+    }
+    let method = request["method"].as_str().unwrap_or("GET").to_uppercase();
+    let url = url(&request["url"]).ok_or("missing URL")?;
+
+    let mut s = format!("# {title}\n{method} {url}\n");
+    for header in enabled(&request["header"]) {
+        let name = escape_key(header["key"].as_str().unwrap_or_default());
+        let value = escape_template_value(header["value"].as_str().unwrap_or_default());
+        s.push_str(&format!("{name}: {value}\n"));
+    }
+    let auth = if request["auth"].is_object() {
+        &request["auth"]
+    } else {
+        auth
+    };
+    match auth["type"].as_str() {
+        Some("basic") => {
+            let user = auth_param(&auth["basic"], "username");
+            let password = auth_param(&auth["basic"], "password");
+            s.push_str(&format!(
+                "[BasicAuth]\n{}: {}\n",
+                escape_key(user),
+                escape_template_value(password)
+            ));
+        }
+        Some("bearer") => {
+            let token = auth_param(&auth["bearer"], "token");
+            s.push_str(&format!(
+                "Authorization: Bearer {}\n",
+                escape_template_value(token)
+            ));
+        }
+        _ => {}
+    }
+    s.push_str(&body(&request["body"]));
+    s.push_str(&response(&item["event"]));
+    Ok(s)
+}
+
+/// Returns the URL of a Postman request, either a string or an object with a `raw` field.
+fn url(url: &Value) -> Option<String> {
+    if let Some(url) = url.as_str() {
+        return Some(url.to_string());
+    }
+    if let Some(raw) = url["raw"].as_str() {
+        return Some(raw.to_string());
+    }
+    let host = match &url["host"] {
+        Value::Array(parts) => parts
+            .iter()
+            .filter_map(|p| p.as_str())
+            .collect::<Vec<_>>()
+            .join("."),
+        Value::String(host) => host.clone(),
+        _ => return None,
+    };
+    let protocol = url["protocol"].as_str().unwrap_or("https");
+    let path = match &url["path"] {
+        Value::Array(parts) => parts
+            .iter()
+            .filter_map(|p| p.as_str())
+            .collect::<Vec<_>>()
+            .join("/"),
+        Value::String(path) => path.trim_start_matches('/').to_string(),
+        _ => String::new(),
+    };
+    Some(format!("{protocol}://{host}/{path}"))
+}
+
+/// Returns the elements of a Postman list of key-values (headers, form params...), without the
+/// disabled ones.
+fn enabled(values: &Value) -> Vec<&Value> {
+    values
+        .as_array()
+        .map(|values| {
+            values
+                .iter()
+                .filter(|v| !v["disabled"].as_bool().unwrap_or(false))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the value of the parameter `key` of a Postman authentication.
+fn auth_param<'a>(params: &'a Value, key: &str) -> &'a str {
+    params
+        .as_array()
+        .and_then(|params| params.iter().find(|p| p["key"] == key))
+        .and_then(|p| p["value"].as_str())
+        .unwrap_or_default()
+}
+
+/// Converts a Postman request body to a Hurl body or request section.
+fn body(body: &Value) -> String {
+    let mut s = String::new();
+    match body["mode"].as_str() {
+        Some("raw") => {
+            let raw = body["raw"].as_str().unwrap_or_default();
+            if raw.is_empty() {
+                return s;
+            }
+            if serde_json::from_str::<Value>(raw).is_ok_and(|v| v.is_object() || v.is_array()) {
+                s.push_str(raw.trim());
+                s.push('\n');
+            } else {
+                s.push_str(&format!("```\n{raw}\n```\n"));
+            }
+        }
+        Some("urlencoded") => {
+            let params = enabled(&body["urlencoded"]);
+            if !params.is_empty() {
+                s.push_str("[FormParams]\n");
+            }
+            for param in params {
+                let name = escape_key(param["key"].as_str().unwrap_or_default());
+                let value = escape_template_value(param["value"].as_str().unwrap_or_default());
+                s.push_str(&format!("{name}: {value}\n"));
+            }
+        }
+        Some("formdata") => {
+            let params = enabled(&body["formdata"]);
+            if !params.is_empty() {
+                s.push_str("[MultipartFormData]\n");
+            }
+            for param in params {
+                let name = escape_key(param["key"].as_str().unwrap_or_default());
+                if param["type"] == "file" {
+                    let src = param["src"].as_str().unwrap_or_default();
+                    s.push_str(&format!("{name}: file,{};\n", escape_filename(src)));
+                } else {
+                    let value = escape_template_value(param["value"].as_str().unwrap_or_default());
+                    s.push_str(&format!("{name}: {value}\n"));
+                }
+            }
+        }
+        Some("file") => {
+            if let Some(src) = body["file"]["src"].as_str() {
+                s.push_str(&format!("file,{};\n", escape_filename(src)));
+            }
+        }
+        Some("graphql") => {
+            let query = body["graphql"]["query"].as_str().unwrap_or_default();
+            s.push_str(&format!("```graphql\n{}\n", query.trim_end()));
+            let variables = body["graphql"]["variables"].as_str().unwrap_or_default();
+            if !variables.trim().is_empty() {
+                s.push_str(&format!("\nvariables {}\n", variables.trim()));
+            }
+            s.push_str("```\n");
+        }
+        _ => {}
+    }
+    s
+}
+
+/// Converts the test scripts of Postman `events` to a Hurl response, if any.
+fn response(events: &Value) -> String {
+    let scripts = events
+        .as_array()
+        .map(|events| {
+            events
+                .iter()
+                .filter(|e| e["listen"] == "test")
+                .flat_map(|e| match &e["script"]["exec"] {
+                    Value::Array(lines) => lines.iter().filter_map(|l| l.as_str()).collect(),
+                    Value::String(line) => vec![line.as_str()],
+                    _ => vec![],
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .unwrap_or_default();
+
+    let status_regexes = [
+        r"pm\.response\.to\.have\.status\((\d{3})\)",
+        r"pm\.expect\(pm\.response\.code\)\.to\.(?:eql|equal|be\.eql|be\.equal)\((\d{3})\)",
+    ];
+    let status = status_regexes.iter().find_map(|r| {
+        let caps = Regex::new(r).unwrap().captures(&scripts)?;
+        Some(caps[1].to_string())
+    });
+    let status = status.or_else(|| {
+        scripts
+            .contains("pm.response.to.be.ok")
+            .then(|| "200".to_string())
+    });
+
+    let mut asserts = vec![];
+    let header_regex = Regex::new(r#"pm\.response\.to\.have\.header\(["']([^"']+)["']\)"#).unwrap();
+    for caps in header_regex.captures_iter(&scripts) {
+        asserts.push(format!("header \"{}\" exists", escape_quoted(&caps[1])));
+    }
+    let body_regex =
+        Regex::new(r#"pm\.expect\(pm\.response\.text\(\)\)\.to\.include\("([^"]*)"\)"#).unwrap();
+    for caps in body_regex.captures_iter(&scripts) {
+        asserts.push(format!("body contains \"{}\"", escape_quoted(&caps[1])));
+    }
+
+    let mut s = String::new();
+    if status.is_none() && asserts.is_empty() {
+        return s;
+    }
+    s.push_str(&format!("HTTP {}\n", status.as_deref().unwrap_or("*")));
+    if !asserts.is_empty() {
+        s.push_str("[Asserts]\n");
+        for assert in asserts {
+            s.push_str(&format!("{assert}\n"));
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let collection = r#"{
+  "info": {"name": "Sample", "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"},
+  "auth": {"type": "bearer", "bearer": [{"key": "token", "value": "{{token}}", "type": "string"}]},
+  "item": [
+    {
+      "name": "Users",
+      "item": [
+        {
+          "name": "Create user",
+          "event": [
+            {
+              "listen": "test",
+              "script": {
+                "exec": [
+                  "pm.test(\"Status code is 201\", function () {",
+                  "    pm.response.to.have.status(201);",
+                  "});",
+                  "pm.test(\"Location\", function () {",
+                  "    pm.response.to.have.header(\"Location\");",
+                  "});"
+                ],
+                "type": "text/javascript"
+              }
+            }
+          ],
+          "request": {
+            "method": "POST",
+            "header": [
+              {"key": "Content-Type", "value": "application/json"},
+              {"key": "X-Debug", "value": "1", "disabled": true}
+            ],
+            "body": {"mode": "raw", "raw": "{\n  \"name\": \"Bob\"\n}", "options": {"raw": {"language": "json"}}},
+            "url": {"raw": "{{host}}/users", "host": ["{{host}}"], "path": ["users"]}
+          }
+        }
+      ]
+    },
+    {
+      "name": "Login",
+      "request": {
+        "auth": {"type": "basic", "basic": [{"key": "password", "value": "secret#1"}, {"key": "username", "value": "bob"}]},
+        "method": "POST",
+        "body": {"mode": "urlencoded", "urlencoded": [{"key": "remember", "value": "true"}]},
+        "url": "https://example.org/login"
+      }
+    },
+    {
+      "name": "Upload",
+      "request": {
+        "auth": {"type": "noauth"},
+        "method": "PUT",
+        "body": {"mode": "formdata", "formdata": [{"key": "note", "value": "hello", "type": "text"}, {"key": "file", "src": "data.bin", "type": "file"}]},
+        "url": {"protocol": "https", "host": ["example", "org"], "path": ["upload"]}
+      }
+    },
+    {
+      "name": "Health",
+      "request": "https://example.org/health"
+    }
+  ]
+}"#;
+        assert_eq!(
+            parse(collection).unwrap(),
+            r#"# Users / Create user
+POST {{host}}/users
+Content-Type: application/json
+Authorization: Bearer {{token}}
+{
+  "name": "Bob"
+}
+HTTP 201
+[Asserts]
+header "Location" exists
+
+# Login
+POST https://example.org/login
+[BasicAuth]
+bob: secret\#1
+[FormParams]
+remember: true
+
+# Upload
+PUT https://example.org/upload
+[MultipartFormData]
+note: hello
+file: file,data.bin;
+
+# Health
+GET https://example.org/health
+"#
+        );
+    }
+
+    #[test]
+    fn test_body() {
+        let value = serde_json::json!({"mode": "raw", "raw": "Hello World"});
+        assert_eq!(body(&value), "```\nHello World\n```\n");
+        let value = serde_json::json!({
+            "mode": "graphql",
+            "graphql": {"query": "{ hero { name } }", "variables": "{\"id\": 1}"}
+        });
+        assert_eq!(
+            body(&value),
+            "```graphql\n{ hero { name } }\n\nvariables {\"id\": 1}\n```\n"
+        );
+        let value = serde_json::json!({"mode": "file", "file": {"src": "my data.bin"}});
+        assert_eq!(body(&value), "file,my\\ data.bin;\n");
+    }
+
+    #[test]
+    fn test_response() {
+        let events = serde_json::json!([{
+            "listen": "test",
+            "script": {"exec": [
+                "pm.test(\"ok\", () => pm.response.to.be.ok);",
+                "pm.expect(pm.response.text()).to.include(\"Hello\");"
+            ]}
+        }]);
+        assert_eq!(
+            response(&events),
+            "HTTP 200\n[Asserts]\nbody contains \"Hello\"\n"
+        );
+        let events = serde_json::json!([{
+            "listen": "prerequest",
+            "script": {"exec": ["pm.response.to.have.status(404);"]}
+        }]);
+        assert_eq!(response(&events), "");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("{}").unwrap_err(),
+            "Can not parse Postman collection: missing item list"
+        );
+        assert_eq!(
+            parse(r#"{"item": [{"name": "foo", "request": {"method": "GET"}}]}"#).unwrap_err(),
+            "Can not convert Postman request <foo>: missing URL"
+        );
+    }
+}
@@ -0,0 +1,208 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Parse, format and lint entry points for WebAssembly, used by the playground and editors to
+//! validate Hurl files client-side.
+//!
+//! With the `wasm` feature, these entry points are exported as C functions taking a UTF-8 buffer
+//! and returning a JSON envelope, `{"ok": ...}` or `{"error": ...}`. The library is built for
+//! WebAssembly without libxml, which can not target `wasm32-unknown-unknown`:
+//!
+//! ```shell
+//! $ cargo rustc -p hurlfmt --lib --target wasm32-unknown-unknown --no-default-features \
+//!     --features wasm --crate-type cdylib --release
+//! ```
+//!
+//! Errors are [LSP diagnostics], with a 0-based range.
+//!
+//! [LSP diagnostics]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#diagnostic
+use hurl_core::parser;
+use serde_json::Value;
+
+use crate::lsp::document;
+use crate::{format, linter};
+
+/// Parses the Hurl file `text` and returns its JSON representation, as `hurlfmt --out json`,
+/// or the diagnostic of the parsing error.
+pub fn parse(text: &str) -> Result<Value, Value> {
+    let hurl_file = parse_hurl_file(text)?;
+    let json = format::format_json(&hurl_file);
+    Ok(serde_json::from_str(&json).expect("valid JSON"))
+}
+
+/// Formats the Hurl file `text`, as `hurlfmt`, or returns the diagnostic of the parsing error.
+pub fn format(text: &str) -> Result<String, Value> {
+    let hurl_file = parse_hurl_file(text)?;
+    let hurl_file = linter::lint_hurl_file(&hurl_file);
+    Ok(format::format_text(&hurl_file, false))
+}
+
+/// Returns the diagnostics of the Hurl file `text`: the parsing errors, the formatting issues
+/// and the lint warnings.
+pub fn lint(text: &str) -> Vec<Value> {
+    document::diagnostics(text)
+}
+
+fn parse_hurl_file(text: &str) -> Result<hurl_core::ast::HurlFile, Value> {
+    parser::parse_hurl_file(text).map_err(|e| {
+        let lines = text.split('\n').collect::<Vec<_>>();
+        document::error_diagnostic(&lines, &e)
+    })
+}
+
+#[cfg(feature = "wasm")]
+mod exports {
+    use std::ffi::{c_char, CString};
+
+    use serde_json::{json, Value};
+
+    /// Allocates a buffer of `len` bytes, where the host writes the input of an entry point.
+    #[no_mangle]
+    pub extern "C" fn hurlfmt_alloc(len: usize) -> *mut u8 {
+        let mut buffer = Vec::<u8>::with_capacity(len);
+        let ptr = buffer.as_mut_ptr();
+        std::mem::forget(buffer);
+        ptr
+    }
+
+    /// Frees a buffer allocated by [`hurlfmt_alloc`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [`hurlfmt_alloc`] with the same `len`.
+    #[no_mangle]
+    pub unsafe extern "C" fn hurlfmt_dealloc(ptr: *mut u8, len: usize) {
+        drop(Vec::from_raw_parts(ptr, 0, len));
+    }
+
+    /// Frees a JSON envelope returned by an entry point.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by an entry point, and not already freed.
+    #[no_mangle]
+    pub unsafe extern "C" fn hurlfmt_free_string(ptr: *mut c_char) {
+        drop(CString::from_raw(ptr));
+    }
+
+    /// Parses the Hurl file of `len` bytes at `ptr`, see [`super::parse`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to `len` initialized bytes.
+    #[no_mangle]
+    pub unsafe extern "C" fn hurlfmt_parse(ptr: *const u8, len: usize) -> *mut c_char {
+        envelope(ptr, len, super::parse)
+    }
+
+    /// Formats the Hurl file of `len` bytes at `ptr`, see [`super::format`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to `len` initialized bytes.
+    #[no_mangle]
+    pub unsafe extern "C" fn hurlfmt_format(ptr: *const u8, len: usize) -> *mut c_char {
+        envelope(ptr, len, |text| super::format(text).map(Value::String))
+    }
+
+    /// Lints the Hurl file of `len` bytes at `ptr`, see [`super::lint`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to `len` initialized bytes.
+    #[no_mangle]
+    pub unsafe extern "C" fn hurlfmt_lint(ptr: *const u8, len: usize) -> *mut c_char {
+        envelope(ptr, len, |text| Ok(Value::Array(super::lint(text))))
+    }
+
+    /// Runs `f` on the UTF-8 text of `len` bytes at `ptr`, and returns its result as a JSON
+    /// envelope to be freed with [`hurlfmt_free_string`].
+    unsafe fn envelope(
+        ptr: *const u8,
+        len: usize,
+        f: impl Fn(&str) -> Result<Value, Value>,
+    ) -> *mut c_char {
+        let bytes = std::slice::from_raw_parts(ptr, len);
+        let value = match std::str::from_utf8(bytes) {
+            Ok(text) => match f(text) {
+                Ok(value) => json!({ "ok": value }),
+                Err(error) => json!({ "error": error }),
+            },
+            Err(e) => json!({ "error": { "message": e.to_string() } }),
+        };
+        // JSON strings escape NUL characters.
+        CString::new(value.to_string()).unwrap().into_raw()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let value = parse("GET http://localhost:8000/hello\nHTTP 200\n").unwrap();
+        assert_eq!(
+            value["entries"][0]["request"],
+            json!({"method": "GET", "url": "http://localhost:8000/hello"})
+        );
+        assert_eq!(value["entries"][0]["response"]["status"], json!(200));
+
+        let error = parse("GET http://localhost:8000/hello\nHTTP 20x\n").unwrap_err();
+        assert_eq!(error["range"]["start"], json!({"line": 1, "character": 7}));
+        assert_eq!(error["severity"], json!(1));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            format("GET   http://localhost:8000/hello\nHTTP 200\n").unwrap(),
+            "GET http://localhost:8000/hello\nHTTP 200\n"
+        );
+        assert!(format("GET http://localhost:8000/hello\nHTTP 20x\n").is_err());
+    }
+
+    #[test]
+    fn test_lint() {
+        assert!(lint("GET http://localhost:8000/hello\nHTTP 200\n").is_empty());
+        let diagnostics = lint("GET   http://localhost:8000/hello\nHTTP 200\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0]["severity"], json!(3));
+    }
+
+    #[cfg(feature = "wasm")]
+    #[test]
+    fn test_exports() {
+        use std::ffi::CStr;
+
+        use exports::*;
+
+        let text = "GET http://localhost:8000/hello\nHTTP 20x\n";
+        unsafe {
+            let ptr = hurlfmt_alloc(text.len());
+            std::ptr::copy_nonoverlapping(text.as_ptr(), ptr, text.len());
+            let result = hurlfmt_format(ptr, text.len());
+            let value: serde_json::Value =
+                serde_json::from_str(CStr::from_ptr(result).to_str().unwrap()).unwrap();
+            assert_eq!(value["error"]["severity"], json!(1));
+            hurlfmt_free_string(result);
+            hurlfmt_dealloc(ptr, text.len());
+        }
+    }
+}